@@ -0,0 +1,66 @@
+//! Compares [`SpanTable::overlapping`] to a linear scan of the table.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use solar_bench::{get_srcs, Source};
+use solar_parse::{
+    ast::{Arena, SpanTable},
+    interface::{BytePos, Session, Span},
+    Parser,
+};
+use std::{hint::black_box, path::PathBuf, time::Duration};
+
+/// The length of the queried ranges, in bytes; roughly a few lines of code.
+const WINDOW: u32 = 256;
+
+fn span_table_benches(c: &mut Criterion) {
+    let mut g = c.benchmark_group("span_table");
+    g.warm_up_time(Duration::from_secs(1));
+    g.measurement_time(Duration::from_secs(5));
+    g.sample_size(20);
+
+    let sess = Session::builder().with_stderr_emitter().build();
+    sess.enter(|| {
+        for &Source { name, path: _, src } in get_srcs() {
+            if src.is_empty() {
+                continue;
+            }
+            let arena = Arena::new();
+            let filename = PathBuf::from(format!("{name}.sol"));
+            let file = sess.source_map().new_dummy_source_file(filename, src.into()).unwrap();
+            let mut parser = Parser::from_source_file(&sess, &arena, &file);
+            let unit = parser.parse_file().map_err(|e| e.emit()).unwrap();
+            let table = SpanTable::new(&unit);
+
+            let start = file.start_pos;
+            let queries = (0..src.len() as u32)
+                .step_by(WINDOW as usize)
+                .map(|lo| Span::new(start + BytePos(lo), start + BytePos(lo + WINDOW)))
+                .collect::<Vec<_>>();
+
+            g.bench_function(format!("{name}/index"), |b| {
+                b.iter(|| {
+                    for &span in &queries {
+                        black_box(table.overlapping(span));
+                    }
+                })
+            });
+            g.bench_function(format!("{name}/linear"), |b| {
+                b.iter(|| {
+                    for &span in &queries {
+                        let ids = table
+                            .iter()
+                            .filter(|(_, entry)| entry.span.overlaps(span))
+                            .map(|(id, _)| id)
+                            .collect::<Vec<_>>();
+                        black_box(ids);
+                    }
+                })
+            });
+        }
+    });
+
+    g.finish();
+}
+
+criterion_group!(benches, span_table_benches);
+criterion_main!(benches);
@@ -0,0 +1,272 @@
+//! Programmatic construction of AST nodes.
+
+use super::*;
+use solar_interface::kw;
+
+/// Builds AST nodes programmatically, for example to generate code.
+///
+/// Nodes are allocated on the given arena, and all their spans are [`Span::DUMMY`]. Identifiers
+/// are interned, so the builder must be used inside of a session.
+///
+/// Node kinds without a dedicated helper can be built with their struct literal and the generic
+/// constructors, like [`expr`](Self::expr) and [`stmt`](Self::stmt).
+///
+/// # Examples
+///
+/// Build `function inc(uint256 x) public pure returns (uint256) { return x + 1; }`:
+///
+/// ```
+/// use solar_ast::ast::{self, AstBuilder, BinOpKind, ElementaryType, TypeSize};
+///
+/// solar_interface::enter(|| {
+///     let arena = ast::Arena::new();
+///     let b = AstBuilder::new(&arena);
+///     let uint256 = || b.elementary_ty(ElementaryType::UInt(TypeSize::new_int_bits(256)));
+///     let header = ast::FunctionHeader {
+///         name: Some(b.ident("inc")),
+///         parameters: b.alloc_vec(vec![b.var(uint256(), Some("x"))]),
+///         visibility: Some(ast::Visibility::Public),
+///         state_mutability: ast::StateMutability::Pure,
+///         returns: b.alloc_vec(vec![b.var(uint256(), None)]),
+///         ..Default::default()
+///     };
+///     let sum = b.expr_binary(b.expr_ident("x"), BinOpKind::Add, b.expr_number(1));
+///     let body = vec![b.stmt_return(Some(sum))];
+///     let item = b.item_function(ast::FunctionKind::Function, header, Some(body));
+///     assert_eq!(item.name().unwrap().as_str(), "inc");
+/// });
+/// ```
+#[derive(Clone, Copy)]
+pub struct AstBuilder<'ast> {
+    arena: &'ast Arena,
+}
+
+impl<'ast> AstBuilder<'ast> {
+    /// Creates a new builder allocating on the given arena.
+    pub fn new(arena: &'ast Arena) -> Self {
+        Self { arena }
+    }
+
+    /// Allocates an object on the arena.
+    pub fn alloc<T>(self, value: T) -> Box<'ast, T> {
+        self.arena.alloc(value)
+    }
+
+    /// Allocates a list of objects on the arena.
+    pub fn alloc_vec<T>(self, values: Vec<T>) -> Box<'ast, [T]> {
+        self.arena.alloc_vec(values)
+    }
+
+    /// Creates an identifier.
+    pub fn ident(self, name: &str) -> Ident {
+        Ident::new(Symbol::intern(name), Span::DUMMY)
+    }
+
+    /// Allocates a path, like `A.B`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `segments` is empty.
+    pub fn path(self, segments: &[&str]) -> AstPath<'ast> {
+        let idents = segments.iter().map(|&s| self.ident(s)).collect::<Vec<_>>();
+        PathSlice::from_mut_slice(self.alloc_vec(idents))
+    }
+
+    // Types.
+
+    /// Creates a type.
+    pub fn ty(self, kind: TypeKind<'ast>) -> Type<'ast> {
+        Type { span: Span::DUMMY, kind }
+    }
+
+    /// Creates an elementary type, like `uint256`.
+    pub fn elementary_ty(self, ty: ElementaryType) -> Type<'ast> {
+        self.ty(TypeKind::Elementary(ty))
+    }
+
+    /// Creates a custom type from its path, like `IERC20` or `Lib.S`.
+    pub fn custom_ty(self, path: &[&str]) -> Type<'ast> {
+        self.ty(TypeKind::Custom(self.path(path)))
+    }
+
+    // Expressions.
+
+    /// Allocates an expression.
+    pub fn expr(self, kind: ExprKind<'ast>) -> Box<'ast, Expr<'ast>> {
+        self.alloc(Expr { span: Span::DUMMY, kind })
+    }
+
+    /// Allocates an identifier expression.
+    pub fn expr_ident(self, name: &str) -> Box<'ast, Expr<'ast>> {
+        self.expr(ExprKind::Ident(self.ident(name)))
+    }
+
+    /// Allocates a literal expression.
+    ///
+    /// `symbol` is the literal as written in the source code.
+    pub fn expr_lit(self, symbol: Symbol, kind: LitKind) -> Box<'ast, Expr<'ast>> {
+        let lit = self.arena.literals.alloc(Lit { span: Span::DUMMY, symbol, kind });
+        self.expr(ExprKind::Lit(lit, None))
+    }
+
+    /// Allocates a boolean literal expression.
+    pub fn expr_bool(self, value: bool) -> Box<'ast, Expr<'ast>> {
+        let symbol = if value { kw::True } else { kw::False };
+        self.expr_lit(symbol, LitKind::Bool(value))
+    }
+
+    /// Allocates a decimal number literal expression.
+    pub fn expr_number(self, value: impl Into<num_bigint::BigInt>) -> Box<'ast, Expr<'ast>> {
+        let value = value.into();
+        self.expr_lit(Symbol::intern(&value.to_string()), LitKind::Number(value))
+    }
+
+    /// Allocates a string literal expression.
+    ///
+    /// `value` is written in the source code as is, so it must not contain characters that need
+    /// to be escaped, such as quotes or newlines.
+    pub fn expr_str(self, value: &str) -> Box<'ast, Expr<'ast>> {
+        let kind = LitKind::Str(StrKind::Str, value.as_bytes().into());
+        self.expr_lit(Symbol::intern(value), kind)
+    }
+
+    /// Allocates a unary operation expression: `!x`, `-x`, `x++`.
+    pub fn expr_unary(self, op: UnOpKind, expr: Box<'ast, Expr<'ast>>) -> Box<'ast, Expr<'ast>> {
+        self.expr(ExprKind::Unary(UnOp { span: Span::DUMMY, kind: op }, expr))
+    }
+
+    /// Allocates a binary operation expression: `a + b`.
+    pub fn expr_binary(
+        self,
+        lhs: Box<'ast, Expr<'ast>>,
+        op: BinOpKind,
+        rhs: Box<'ast, Expr<'ast>>,
+    ) -> Box<'ast, Expr<'ast>> {
+        self.expr(ExprKind::Binary(lhs, BinOp { span: Span::DUMMY, kind: op }, rhs))
+    }
+
+    /// Allocates an assignment expression: `a = b`, or `a += b` if `op` is `Some`.
+    pub fn expr_assign(
+        self,
+        lhs: Box<'ast, Expr<'ast>>,
+        op: Option<BinOpKind>,
+        rhs: Box<'ast, Expr<'ast>>,
+    ) -> Box<'ast, Expr<'ast>> {
+        let op = op.map(|kind| BinOp { span: Span::DUMMY, kind });
+        self.expr(ExprKind::Assign(lhs, op, rhs))
+    }
+
+    /// Allocates a call expression with unnamed arguments: `f(a, b)`.
+    pub fn expr_call(
+        self,
+        callee: Box<'ast, Expr<'ast>>,
+        args: Vec<Box<'ast, Expr<'ast>>>,
+    ) -> Box<'ast, Expr<'ast>> {
+        self.expr(ExprKind::Call(callee, CallArgs::Unnamed(self.alloc_vec(args))))
+    }
+
+    /// Allocates a member access expression: `a.b`.
+    pub fn expr_member(self, base: Box<'ast, Expr<'ast>>, member: &str) -> Box<'ast, Expr<'ast>> {
+        self.expr(ExprKind::Member(base, self.ident(member)))
+    }
+
+    /// Allocates an index expression: `a[i]`.
+    pub fn expr_index(
+        self,
+        base: Box<'ast, Expr<'ast>>,
+        index: Box<'ast, Expr<'ast>>,
+    ) -> Box<'ast, Expr<'ast>> {
+        self.expr(ExprKind::Index(base, IndexKind::Index(Some(index))))
+    }
+
+    // Statements.
+
+    /// Creates a statement without doc-comments.
+    pub fn stmt(self, kind: StmtKind<'ast>) -> Stmt<'ast> {
+        Stmt { docs: Default::default(), span: Span::DUMMY, kind }
+    }
+
+    /// Allocates a block of statements.
+    pub fn block(self, stmts: Vec<Stmt<'ast>>) -> Block<'ast> {
+        self.alloc_vec(stmts)
+    }
+
+    /// Creates an expression statement: `f();`.
+    pub fn stmt_expr(self, expr: Box<'ast, Expr<'ast>>) -> Stmt<'ast> {
+        self.stmt(StmtKind::Expr(expr))
+    }
+
+    /// Creates a single-variable declaration statement: `uint256 x = 1;`.
+    pub fn stmt_decl(self, var: VariableDefinition<'ast>) -> Stmt<'ast> {
+        self.stmt(StmtKind::DeclSingle(self.alloc(var)))
+    }
+
+    /// Creates a return statement: `return x;`, or `return;` if `expr` is `None`.
+    pub fn stmt_return(self, expr: Option<Box<'ast, Expr<'ast>>>) -> Stmt<'ast> {
+        self.stmt(StmtKind::Return(expr))
+    }
+
+    /// Creates an `if` statement, with an `else` block if `else_` is `Some`.
+    pub fn stmt_if(
+        self,
+        cond: Box<'ast, Expr<'ast>>,
+        then: Vec<Stmt<'ast>>,
+        else_: Option<Vec<Stmt<'ast>>>,
+    ) -> Stmt<'ast> {
+        let then = self.alloc(self.stmt(StmtKind::Block(self.block(then))));
+        let else_ = else_.map(|stmts| self.alloc(self.stmt(StmtKind::Block(self.block(stmts)))));
+        self.stmt(StmtKind::If(cond, then, else_))
+    }
+
+    // Items.
+
+    /// Creates a variable definition without specifiers or initializer: `uint256 x`.
+    ///
+    /// The remaining fields can be set on the returned value.
+    pub fn var(self, ty: Type<'ast>, name: Option<&str>) -> VariableDefinition<'ast> {
+        VariableDefinition {
+            span: Span::DUMMY,
+            ty,
+            visibility: None,
+            mutability: None,
+            data_location: None,
+            override_: None,
+            indexed: false,
+            name: name.map(|name| self.ident(name)),
+            initializer: None,
+        }
+    }
+
+    /// Creates an item without doc-comments.
+    pub fn item(self, kind: ItemKind<'ast>) -> Item<'ast> {
+        Item { docs: Default::default(), span: Span::DUMMY, kind }
+    }
+
+    /// Creates a function item. The function has no implementation if `body` is `None`.
+    pub fn item_function(
+        self,
+        kind: FunctionKind,
+        header: FunctionHeader<'ast>,
+        body: Option<Vec<Stmt<'ast>>>,
+    ) -> Item<'ast> {
+        let body = body.map(|stmts| self.block(stmts));
+        self.item(ItemKind::Function(ItemFunction { kind, header, body }))
+    }
+
+    /// Creates a contract item without base contracts.
+    pub fn item_contract(
+        self,
+        kind: ContractKind,
+        name: &str,
+        body: Vec<Item<'ast>>,
+    ) -> Item<'ast> {
+        let name = self.ident(name);
+        let body = self.alloc_vec(body);
+        self.item(ItemKind::Contract(ItemContract { kind, name, bases: Default::default(), body }))
+    }
+
+    /// Creates a source unit.
+    pub fn source_unit(self, items: Vec<Item<'ast>>) -> SourceUnit<'ast> {
+        SourceUnit::new(self.alloc_vec(items))
+    }
+}
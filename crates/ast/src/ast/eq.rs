@@ -0,0 +1,531 @@
+use super::*;
+use crate::token::{Token, TokenKind};
+use solar_interface::{Ident, Symbol};
+use std::cell::RefCell;
+
+thread_local! {
+    /// The pairs of identifiers that are considered equal. See [`ast_eq_renamed`].
+    static RENAMES: RefCell<Vec<(Symbol, Symbol)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Returns `true` if the two AST nodes are structurally equal.
+///
+/// See [`AstEq`] for more details.
+#[inline]
+pub fn ast_eq<T: AstEq + ?Sized>(a: &T, b: &T) -> bool {
+    a.ast_eq(b)
+}
+
+/// Returns `true` if the two AST nodes are structurally equal, up to the given renaming of
+/// identifiers.
+///
+/// For each `(from, to)` pair in `renames`, the identifier `from` in `a` is only equal to the
+/// identifier `to` in `b`. This can be used to compare function bodies regardless of the names
+/// of their parameters. Note that all the identifiers are renamed, including the names of members.
+pub fn ast_eq_renamed<T: AstEq + ?Sized>(a: &T, b: &T, renames: &[(Symbol, Symbol)]) -> bool {
+    let prev = RENAMES.replace(renames.to_vec());
+    let eq = a.ast_eq(b);
+    RENAMES.set(prev);
+    eq
+}
+
+/// Structural equality of AST nodes.
+///
+/// Two nodes are equal if they have the same structure and the same interned symbols, regardless
+/// of where they are located in the source: spans are ignored, and so are trivia such as
+/// whitespace, comments, and doc-comments. This is useful for checking that a transformation of
+/// the AST did not change anything, or for comparing parses of different sources.
+pub trait AstEq {
+    /// Returns `true` if `self` and `other` are structurally equal.
+    fn ast_eq(&self, other: &Self) -> bool;
+}
+
+impl<T: AstEq + ?Sized> AstEq for &T {
+    #[inline]
+    fn ast_eq(&self, other: &Self) -> bool {
+        (**self).ast_eq(&**other)
+    }
+}
+
+impl<T: AstEq + ?Sized> AstEq for &mut T {
+    #[inline]
+    fn ast_eq(&self, other: &Self) -> bool {
+        (**self).ast_eq(&**other)
+    }
+}
+
+impl<T: AstEq> AstEq for [T] {
+    fn ast_eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a.ast_eq(b))
+    }
+}
+
+impl<T: AstEq> AstEq for Option<T> {
+    fn ast_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Some(a), Some(b)) => a.ast_eq(b),
+            (None, None) => true,
+            (Some(_) | None, _) => false,
+        }
+    }
+}
+
+impl<A: AstEq, B: AstEq> AstEq for (A, B) {
+    fn ast_eq(&self, other: &Self) -> bool {
+        self.0.ast_eq(&other.0) && self.1.ast_eq(&other.1)
+    }
+}
+
+impl AstEq for Ident {
+    #[inline]
+    fn ast_eq(&self, other: &Self) -> bool {
+        RENAMES.with_borrow(|renames| {
+            match renames.iter().find(|&&(from, to)| from == self.name || to == other.name) {
+                Some(&(from, to)) => from == self.name && to == other.name,
+                None => self.name == other.name,
+            }
+        })
+    }
+}
+
+impl AstEq for PathSlice {
+    fn ast_eq(&self, other: &Self) -> bool {
+        self.segments().ast_eq(other.segments())
+    }
+}
+
+impl AstEq for SourceUnit<'_> {
+    fn ast_eq(&self, other: &Self) -> bool {
+        self.items.raw.ast_eq(&other.items.raw)
+    }
+}
+
+/// Implements [`AstEq`] for types without spans using [`PartialEq`].
+macro_rules! impl_partial_eq {
+    ($($ty:ty),* $(,)?) => {$(
+        impl AstEq for $ty {
+            #[inline]
+            fn ast_eq(&self, other: &Self) -> bool {
+                self == other
+            }
+        }
+    )*};
+}
+
+impl_partial_eq!(
+    bool,
+    Symbol,
+    TokenKind,
+    BinOpKind,
+    UnOpKind,
+    UserDefinableOperator,
+    ContractKind,
+    FunctionKind,
+    DataLocation,
+    StateMutability,
+    Visibility,
+    VarMut,
+    StrKind,
+    SubDenomination,
+    ElementaryType,
+    SemverOp,
+);
+
+/// Implements [`AstEq`] for structs by comparing all of their fields, except for the ones listed
+/// after `;`.
+///
+/// All the fields must be listed so that new fields are not forgotten.
+macro_rules! impl_struct {
+    ($($ty:ident $(<$lt:lifetime>)? { $($field:ident),* $(; $($ignored:ident),*)? })*) => {$(
+        impl AstEq for $ty $(<$lt>)? {
+            fn ast_eq(&self, other: &Self) -> bool {
+                let Self { $($field,)* $($($ignored: _,)*)? } = self;
+                true $(&& $field.ast_eq(&other.$field))*
+            }
+        }
+    )*};
+}
+
+impl_struct! {
+    Token { kind; span }
+    Expr<'ast> { kind; span }
+    BinOp { kind; span }
+    UnOp { kind; span }
+    NamedArg<'ast> { name, value }
+    Item<'ast> { kind; docs, span }
+    PragmaDirective<'ast> { tokens }
+    ImportDirective<'ast> { path, items }
+    UsingDirective<'ast> { list, ty, global }
+    ItemContract<'ast> { kind, name, bases, body }
+    ItemFunction<'ast> { kind, header, body }
+    FunctionHeader<'ast> {
+        name,
+        parameters,
+        visibility,
+        state_mutability,
+        modifiers,
+        virtual_,
+        override_,
+        returns
+    }
+    Modifier<'ast> { name, arguments }
+    Override<'ast> { paths; span }
+    VariableDefinition<'ast> {
+        ty,
+        visibility,
+        mutability,
+        data_location,
+        override_,
+        indexed,
+        name,
+        initializer;
+        span
+    }
+    ItemStruct<'ast> { name, fields }
+    ItemEnum<'ast> { name, variants }
+    ItemUdvt<'ast> { name, ty }
+    ItemError<'ast> { name, parameters }
+    ItemEvent<'ast> { name, parameters, anonymous }
+    Lit { symbol, kind; span }
+    StrLit { value; span }
+    SemverVersion { major, minor, patch; span }
+    SemverReq<'ast> { dis }
+    SemverReqCon<'ast> { components; span }
+    SemverReqComponent { kind; span }
+    Stmt<'ast> { kind; docs, span }
+    StmtAssembly<'ast> { dialect, flags, block }
+    StmtTry<'ast> { expr, returns, block, catch }
+    CatchClause<'ast> { name, args, block; span }
+    Type<'ast> { kind; span }
+    TypeArray<'ast> { element, size }
+    TypeFunction<'ast> { parameters, visibility, state_mutability, returns }
+    TypeMapping<'ast> { key, key_name, value, value_name }
+}
+
+mod yul_structs {
+    use super::*;
+    use crate::ast::yul::*;
+
+    impl_struct! {
+        Object<'ast> { name, code, children, data; docs, span }
+        CodeBlock<'ast> { code; span }
+        Data<'ast> { name, data; span }
+        Stmt<'ast> { kind; docs, span }
+        StmtSwitch<'ast> { selector, branches, default_case }
+        StmtSwitchCase<'ast> { constant, body }
+        Function<'ast> { name, parameters, returns, body }
+        Expr<'ast> { kind; span }
+        ExprCall<'ast> { name, arguments }
+    }
+}
+
+// The implementations for enums list all the variants in their last arm instead of using a
+// wildcard, so that new variants are not forgotten.
+impl AstEq for ExprKind<'_> {
+    fn ast_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Array(a), Self::Array(b)) => a.ast_eq(b),
+            (Self::Assign(a1, a2, a3), Self::Assign(b1, b2, b3)) => {
+                a1.ast_eq(b1) && a2.ast_eq(b2) && a3.ast_eq(b3)
+            }
+            (Self::Binary(a1, a2, a3), Self::Binary(b1, b2, b3)) => {
+                a1.ast_eq(b1) && a2.ast_eq(b2) && a3.ast_eq(b3)
+            }
+            (Self::Call(a1, a2), Self::Call(b1, b2)) => a1.ast_eq(b1) && a2.ast_eq(b2),
+            (Self::CallOptions(a1, a2), Self::CallOptions(b1, b2)) => {
+                a1.ast_eq(b1) && a2.ast_eq(b2)
+            }
+            (Self::Delete(a), Self::Delete(b)) => a.ast_eq(b),
+            (Self::Ident(a), Self::Ident(b)) => a.ast_eq(b),
+            (Self::Index(a1, a2), Self::Index(b1, b2)) => a1.ast_eq(b1) && a2.ast_eq(b2),
+            (Self::Lit(a1, a2), Self::Lit(b1, b2)) => a1.ast_eq(b1) && a2.ast_eq(b2),
+            (Self::Member(a1, a2), Self::Member(b1, b2)) => a1.ast_eq(b1) && a2.ast_eq(b2),
+            (Self::New(a), Self::New(b)) => a.ast_eq(b),
+            (Self::Payable(a), Self::Payable(b)) => a.ast_eq(b),
+            (Self::Ternary(a1, a2, a3), Self::Ternary(b1, b2, b3)) => {
+                a1.ast_eq(b1) && a2.ast_eq(b2) && a3.ast_eq(b3)
+            }
+            (Self::Tuple(a), Self::Tuple(b)) => a.ast_eq(b),
+            (Self::TypeCall(a), Self::TypeCall(b)) => a.ast_eq(b),
+            (Self::Type(a), Self::Type(b)) => a.ast_eq(b),
+            (Self::Unary(a1, a2), Self::Unary(b1, b2)) => a1.ast_eq(b1) && a2.ast_eq(b2),
+            (
+                Self::Array(..)
+                | Self::Assign(..)
+                | Self::Binary(..)
+                | Self::Call(..)
+                | Self::CallOptions(..)
+                | Self::Delete(..)
+                | Self::Ident(..)
+                | Self::Index(..)
+                | Self::Lit(..)
+                | Self::Member(..)
+                | Self::New(..)
+                | Self::Payable(..)
+                | Self::Ternary(..)
+                | Self::Tuple(..)
+                | Self::TypeCall(..)
+                | Self::Type(..)
+                | Self::Unary(..),
+                _,
+            ) => false,
+        }
+    }
+}
+
+impl AstEq for CallArgs<'_> {
+    fn ast_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Unnamed(a), Self::Unnamed(b)) => a.ast_eq(b),
+            (Self::Named(a), Self::Named(b)) => a.ast_eq(b),
+            (Self::Unnamed(..) | Self::Named(..), _) => false,
+        }
+    }
+}
+
+impl AstEq for IndexKind<'_> {
+    fn ast_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Index(a), Self::Index(b)) => a.ast_eq(b),
+            (Self::Range(a1, a2), Self::Range(b1, b2)) => a1.ast_eq(b1) && a2.ast_eq(b2),
+            (Self::Index(..) | Self::Range(..), _) => false,
+        }
+    }
+}
+
+impl AstEq for ItemKind<'_> {
+    fn ast_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Pragma(a), Self::Pragma(b)) => a.ast_eq(b),
+            (Self::Import(a), Self::Import(b)) => a.ast_eq(b),
+            (Self::Using(a), Self::Using(b)) => a.ast_eq(b),
+            (Self::Contract(a), Self::Contract(b)) => a.ast_eq(b),
+            (Self::Function(a), Self::Function(b)) => a.ast_eq(b),
+            (Self::Variable(a), Self::Variable(b)) => a.ast_eq(b),
+            (Self::Struct(a), Self::Struct(b)) => a.ast_eq(b),
+            (Self::Enum(a), Self::Enum(b)) => a.ast_eq(b),
+            (Self::Udvt(a), Self::Udvt(b)) => a.ast_eq(b),
+            (Self::Error(a), Self::Error(b)) => a.ast_eq(b),
+            (Self::Event(a), Self::Event(b)) => a.ast_eq(b),
+            (
+                Self::Pragma(..)
+                | Self::Import(..)
+                | Self::Using(..)
+                | Self::Contract(..)
+                | Self::Function(..)
+                | Self::Variable(..)
+                | Self::Struct(..)
+                | Self::Enum(..)
+                | Self::Udvt(..)
+                | Self::Error(..)
+                | Self::Event(..),
+                _,
+            ) => false,
+        }
+    }
+}
+
+impl AstEq for PragmaTokens<'_> {
+    fn ast_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Version(a1, a2), Self::Version(b1, b2)) => a1.ast_eq(b1) && a2.ast_eq(b2),
+            (Self::Custom(a1, a2), Self::Custom(b1, b2)) => a1.ast_eq(b1) && a2.ast_eq(b2),
+            (Self::Verbatim(a), Self::Verbatim(b)) => a.ast_eq(b),
+            (Self::Version(..) | Self::Custom(..) | Self::Verbatim(..), _) => false,
+        }
+    }
+}
+
+impl AstEq for IdentOrStrLit {
+    fn ast_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Ident(a), Self::Ident(b)) => a.ast_eq(b),
+            (Self::StrLit(a), Self::StrLit(b)) => a.ast_eq(b),
+            (Self::Ident(..) | Self::StrLit(..), _) => false,
+        }
+    }
+}
+
+impl AstEq for ImportItems<'_> {
+    fn ast_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Plain(a), Self::Plain(b)) => a.ast_eq(b),
+            (Self::Aliases(a), Self::Aliases(b)) => a.ast_eq(b),
+            (Self::Glob(a), Self::Glob(b)) => a.ast_eq(b),
+            (Self::Plain(..) | Self::Aliases(..) | Self::Glob(..), _) => false,
+        }
+    }
+}
+
+impl AstEq for UsingList<'_> {
+    fn ast_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Single(a), Self::Single(b)) => a.ast_eq(b),
+            (Self::Multiple(a), Self::Multiple(b)) => a.ast_eq(b),
+            (Self::Single(..) | Self::Multiple(..), _) => false,
+        }
+    }
+}
+
+impl AstEq for LitKind {
+    fn ast_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Str(a1, a2), Self::Str(b1, b2)) => a1 == b1 && a2 == b2,
+            (Self::Number(a), Self::Number(b)) => a == b,
+            (Self::Rational(a), Self::Rational(b)) => a == b,
+            (Self::Address(a), Self::Address(b)) => a == b,
+            (Self::Bool(a), Self::Bool(b)) => a == b,
+            (Self::Err(_), Self::Err(_)) => true,
+            (
+                Self::Str(..)
+                | Self::Number(..)
+                | Self::Rational(..)
+                | Self::Address(..)
+                | Self::Bool(..)
+                | Self::Err(..),
+                _,
+            ) => false,
+        }
+    }
+}
+
+impl AstEq for SemverVersionNumber {
+    fn ast_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => a == b,
+            (Self::Wildcard, Self::Wildcard) => true,
+            (Self::Number(..) | Self::Wildcard, _) => false,
+        }
+    }
+}
+
+impl AstEq for SemverReqComponentKind {
+    fn ast_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Op(a1, a2), Self::Op(b1, b2)) => a1.ast_eq(b1) && a2.ast_eq(b2),
+            (Self::Range(a1, a2), Self::Range(b1, b2)) => a1.ast_eq(b1) && a2.ast_eq(b2),
+            (Self::Op(..) | Self::Range(..), _) => false,
+        }
+    }
+}
+
+impl AstEq for StmtKind<'_> {
+    fn ast_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Assembly(a), Self::Assembly(b)) => a.ast_eq(b),
+            (Self::DeclSingle(a), Self::DeclSingle(b)) => a.ast_eq(b),
+            (Self::DeclMulti(a1, a2), Self::DeclMulti(b1, b2)) => a1.ast_eq(b1) && a2.ast_eq(b2),
+            (Self::Block(a), Self::Block(b)) => a.ast_eq(b),
+            (Self::Break, Self::Break) => true,
+            (Self::Continue, Self::Continue) => true,
+            (Self::DoWhile(a1, a2), Self::DoWhile(b1, b2)) => a1.ast_eq(b1) && a2.ast_eq(b2),
+            (Self::Emit(a1, a2), Self::Emit(b1, b2)) => a1.ast_eq(b1) && a2.ast_eq(b2),
+            (Self::Expr(a), Self::Expr(b)) => a.ast_eq(b),
+            (
+                Self::For { init: a1, cond: a2, next: a3, body: a4 },
+                Self::For { init: b1, cond: b2, next: b3, body: b4 },
+            ) => a1.ast_eq(b1) && a2.ast_eq(b2) && a3.ast_eq(b3) && a4.ast_eq(b4),
+            (Self::If(a1, a2, a3), Self::If(b1, b2, b3)) => {
+                a1.ast_eq(b1) && a2.ast_eq(b2) && a3.ast_eq(b3)
+            }
+            (Self::Return(a), Self::Return(b)) => a.ast_eq(b),
+            (Self::Revert(a1, a2), Self::Revert(b1, b2)) => a1.ast_eq(b1) && a2.ast_eq(b2),
+            (Self::Try(a), Self::Try(b)) => a.ast_eq(b),
+            (Self::UncheckedBlock(a), Self::UncheckedBlock(b)) => a.ast_eq(b),
+            (Self::While(a1, a2), Self::While(b1, b2)) => a1.ast_eq(b1) && a2.ast_eq(b2),
+            (Self::Placeholder, Self::Placeholder) => true,
+            (
+                Self::Assembly(..)
+                | Self::DeclSingle(..)
+                | Self::DeclMulti(..)
+                | Self::Block(..)
+                | Self::Break
+                | Self::Continue
+                | Self::DoWhile(..)
+                | Self::Emit(..)
+                | Self::Expr(..)
+                | Self::For { .. }
+                | Self::If(..)
+                | Self::Return(..)
+                | Self::Revert(..)
+                | Self::Try(..)
+                | Self::UncheckedBlock(..)
+                | Self::While(..)
+                | Self::Placeholder,
+                _,
+            ) => false,
+        }
+    }
+}
+
+impl AstEq for TypeKind<'_> {
+    fn ast_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Elementary(a), Self::Elementary(b)) => a.ast_eq(b),
+            (Self::Array(a), Self::Array(b)) => a.ast_eq(b),
+            (Self::Function(a), Self::Function(b)) => a.ast_eq(b),
+            (Self::Mapping(a), Self::Mapping(b)) => a.ast_eq(b),
+            (Self::Custom(a), Self::Custom(b)) => a.ast_eq(b),
+            (
+                Self::Elementary(..)
+                | Self::Array(..)
+                | Self::Function(..)
+                | Self::Mapping(..)
+                | Self::Custom(..),
+                _,
+            ) => false,
+        }
+    }
+}
+
+impl AstEq for yul::StmtKind<'_> {
+    fn ast_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Block(a), Self::Block(b)) => a.ast_eq(b),
+            (Self::AssignSingle(a1, a2), Self::AssignSingle(b1, b2)) => {
+                a1.ast_eq(b1) && a2.ast_eq(b2)
+            }
+            (Self::AssignMulti(a1, a2), Self::AssignMulti(b1, b2)) => {
+                a1.ast_eq(b1) && a2.ast_eq(b2)
+            }
+            (Self::Expr(a), Self::Expr(b)) => a.ast_eq(b),
+            (Self::If(a1, a2), Self::If(b1, b2)) => a1.ast_eq(b1) && a2.ast_eq(b2),
+            (
+                Self::For { init: a1, cond: a2, step: a3, body: a4 },
+                Self::For { init: b1, cond: b2, step: b3, body: b4 },
+            ) => a1.ast_eq(b1) && a2.ast_eq(b2) && a3.ast_eq(b3) && a4.ast_eq(b4),
+            (Self::Switch(a), Self::Switch(b)) => a.ast_eq(b),
+            (Self::Leave, Self::Leave) => true,
+            (Self::Break, Self::Break) => true,
+            (Self::Continue, Self::Continue) => true,
+            (Self::FunctionDef(a), Self::FunctionDef(b)) => a.ast_eq(b),
+            (Self::VarDecl(a1, a2), Self::VarDecl(b1, b2)) => a1.ast_eq(b1) && a2.ast_eq(b2),
+            (
+                Self::Block(..)
+                | Self::AssignSingle(..)
+                | Self::AssignMulti(..)
+                | Self::Expr(..)
+                | Self::If(..)
+                | Self::For { .. }
+                | Self::Switch(..)
+                | Self::Leave
+                | Self::Break
+                | Self::Continue
+                | Self::FunctionDef(..)
+                | Self::VarDecl(..),
+                _,
+            ) => false,
+        }
+    }
+}
+
+impl AstEq for yul::ExprKind<'_> {
+    fn ast_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Path(a), Self::Path(b)) => a.ast_eq(b),
+            (Self::Call(a), Self::Call(b)) => a.ast_eq(b),
+            (Self::Lit(a), Self::Lit(b)) => a.ast_eq(b),
+            (Self::Path(..) | Self::Call(..) | Self::Lit(..), _) => false,
+        }
+    }
+}
@@ -6,6 +6,12 @@ use std::fmt;
 pub use crate::token::CommentKind;
 pub use solar_interface::{Ident, Span, Symbol};
 
+mod build;
+pub use build::*;
+
+mod eq;
+pub use eq::*;
+
 mod expr;
 pub use expr::*;
 
@@ -21,6 +27,9 @@ pub use path::*;
 mod semver;
 pub use semver::*;
 
+mod span_table;
+pub use span_table::*;
+
 mod stmt;
 pub use stmt::*;
 
@@ -207,6 +207,40 @@ impl SemverReq<'_> {
         self.dis.iter().any(|c| c.matches(version))
     }
 
+    /// Returns `true` if a version satisfies both this requirement and `other`.
+    pub fn intersects(&self, other: &SemverReq<'_>) -> bool {
+        let mut versions = Vec::new();
+        for con in self.dis.iter().chain(other.dis.iter()) {
+            for component in con.components.iter() {
+                match &component.kind {
+                    SemverReqComponentKind::Op(_, version) => versions.push(version),
+                    SemverReqComponentKind::Range(start, end) => versions.extend([start, end]),
+                }
+            }
+        }
+
+        // The smallest version satisfying both requirements, if any, is `0.0.0`, one of the
+        // versions in the requirements, or the next patch, minor or major version after one.
+        let version =
+            |major, minor, patch| SemverVersion::from(semver::Version::new(major, minor, patch));
+        let number = |n: Option<SemverVersionNumber>| match n {
+            Some(SemverVersionNumber::Number(n)) => u64::from(n),
+            _ => 0,
+        };
+        let candidates = versions.into_iter().flat_map(|v| {
+            let (major, minor, patch) = (number(Some(v.major)), number(v.minor), number(v.patch));
+            [
+                version(major, minor, patch),
+                version(major, minor, patch + 1),
+                version(major, minor + 1, 0),
+                version(major + 1, 0, 0),
+            ]
+        });
+        std::iter::once(version(0, 0, 0))
+            .chain(candidates)
+            .any(|v| self.matches(&v) && other.matches(&v))
+    }
+
     /// Converts this requirement to a [::semver] version requirement.
     pub fn to_semver(&self) -> SemverVersionReqCompat {
         SemverVersionReqCompat { reqs: self.dis.iter().map(SemverReqCon::to_semver).collect() }
@@ -0,0 +1,182 @@
+use super::{Expr, SourceUnit, Stmt};
+use crate::visit::Visit;
+use solar_data_structures::{
+    index::{Idx, IndexVec},
+    newtype_index,
+};
+use solar_interface::{BytePos, Span};
+
+newtype_index! {
+    /// A statement or expression ID in a [`SpanTable`].
+    pub struct NodeId;
+}
+
+/// A flat table of the spans of all the statements and expressions in a source unit.
+///
+/// Nodes are numbered in the order in which they are visited: a statement or expression comes
+/// before its children, which come in source order. This is intended for tools that map back to
+/// the source, such as coverage reporters, which would otherwise have to walk the AST themselves.
+///
+/// The table also indexes the spans of its nodes, so that the nodes overlapping a range can be
+/// found with [`overlapping`](Self::overlapping) without scanning the whole table.
+#[derive(Clone, Debug, Default)]
+pub struct SpanTable {
+    nodes: IndexVec<NodeId, SpanTableEntry>,
+    index: SpanIndex,
+}
+
+/// An entry in a [`SpanTable`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SpanTableEntry {
+    /// The kind of node.
+    pub kind: NodeKind,
+    /// The span of the node.
+    pub span: Span,
+}
+
+/// The kind of a node in a [`SpanTable`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NodeKind {
+    /// A statement.
+    Stmt,
+    /// An expression.
+    Expr,
+}
+
+impl SpanTable {
+    /// Collects the spans of all the statements and expressions in the given source unit.
+    pub fn new(source: &SourceUnit<'_>) -> Self {
+        let mut collector = SpanCollector { table: Self::default() };
+        collector.visit_source_unit(source);
+        let mut table = collector.table;
+        table.index = SpanIndex::new(&table.nodes);
+        table
+    }
+
+    /// Returns the number of nodes in the table.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if the table is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the entry of the given node.
+    #[inline]
+    pub fn get(&self, id: NodeId) -> Option<&SpanTableEntry> {
+        self.nodes.get(id)
+    }
+
+    /// Returns an iterator over all the nodes in the table.
+    #[inline]
+    pub fn iter(
+        &self,
+    ) -> impl ExactSizeIterator<Item = (NodeId, &SpanTableEntry)> + DoubleEndedIterator + Clone
+    {
+        self.nodes.iter().enumerate().map(|(i, entry)| (NodeId::from_usize(i), entry))
+    }
+
+    /// Returns an iterator over the statement nodes in the table.
+    pub fn stmts(&self) -> impl Iterator<Item = (NodeId, Span)> + Clone + '_ {
+        self.of_kind(NodeKind::Stmt)
+    }
+
+    /// Returns an iterator over the expression nodes in the table.
+    pub fn exprs(&self) -> impl Iterator<Item = (NodeId, Span)> + Clone + '_ {
+        self.of_kind(NodeKind::Expr)
+    }
+
+    /// Returns the nodes whose span overlaps `span`, ordered by the start of their span.
+    ///
+    /// Nodes that start at the same position are returned in the order in which they were
+    /// visited, parents before their children. Empty spans never overlap anything, as in
+    /// [`Span::overlaps`].
+    pub fn overlapping(&self, span: Span) -> Vec<NodeId> {
+        let mut ids = Vec::new();
+        self.index.query(span, 0, self.index.sorted.len(), &mut ids);
+        ids
+    }
+
+    fn of_kind(&self, kind: NodeKind) -> impl Iterator<Item = (NodeId, Span)> + Clone + '_ {
+        self.iter().filter(move |(_, entry)| entry.kind == kind).map(|(id, entry)| (id, entry.span))
+    }
+}
+
+/// An augmented interval tree over the spans of a [`SpanTable`].
+///
+/// The spans are sorted by their start, and form an implicit balanced binary search tree in which
+/// the root of the subtree over `sorted[l..r]` is `sorted[(l + r) / 2]`. Each root also stores the
+/// maximum end of the spans in its subtree, so that queries can skip the subtrees which end before
+/// the queried range starts, or start after it ends. A query with `k` results runs in
+/// `O((k + 1) log n)` time, instead of the `O(n)` of a linear scan.
+#[derive(Clone, Debug, Default)]
+struct SpanIndex {
+    /// The spans of the nodes, sorted by their start.
+    sorted: Vec<(Span, NodeId)>,
+    /// The maximum end of the spans in the subtree rooted at each entry of `sorted`.
+    max_hi: Vec<BytePos>,
+}
+
+impl SpanIndex {
+    fn new(nodes: &IndexVec<NodeId, SpanTableEntry>) -> Self {
+        let mut sorted: Vec<_> =
+            nodes.iter_enumerated().map(|(id, entry)| (entry.span, id)).collect();
+        // Stable sort to keep parents before their children.
+        sorted.sort_by_key(|(span, _)| span.lo());
+        let mut max_hi = vec![BytePos(0); sorted.len()];
+        Self::build(&sorted, &mut max_hi, 0, sorted.len());
+        Self { sorted, max_hi }
+    }
+
+    fn build(sorted: &[(Span, NodeId)], max_hi: &mut [BytePos], l: usize, r: usize) -> BytePos {
+        if l >= r {
+            return BytePos(0);
+        }
+        let mid = l + (r - l) / 2;
+        let left = Self::build(sorted, max_hi, l, mid);
+        let right = Self::build(sorted, max_hi, mid + 1, r);
+        max_hi[mid] = sorted[mid].0.hi().max(left).max(right);
+        max_hi[mid]
+    }
+
+    fn query(&self, span: Span, l: usize, r: usize, out: &mut Vec<NodeId>) {
+        if l >= r {
+            return;
+        }
+        let mid = l + (r - l) / 2;
+        // All the spans in this subtree end before the range starts.
+        if self.max_hi[mid] <= span.lo() {
+            return;
+        }
+        self.query(span, l, mid, out);
+        let (node_span, id) = self.sorted[mid];
+        // This span and all the spans in the right subtree start after the range ends.
+        if node_span.lo() >= span.hi() {
+            return;
+        }
+        if node_span.overlaps(span) {
+            out.push(id);
+        }
+        self.query(span, mid + 1, r, out);
+    }
+}
+
+struct SpanCollector {
+    table: SpanTable,
+}
+
+impl<'ast> Visit<'ast> for SpanCollector {
+    fn visit_stmt(&mut self, stmt: &'ast Stmt<'ast>) {
+        self.table.nodes.push(SpanTableEntry { kind: NodeKind::Stmt, span: stmt.span });
+        self.walk_stmt(stmt);
+    }
+
+    fn visit_expr(&mut self, expr: &'ast Expr<'ast>) {
+        self.table.nodes.push(SpanTableEntry { kind: NodeKind::Expr, span: expr.span });
+        self.walk_expr(expr);
+    }
+}
@@ -108,6 +108,8 @@ pub struct StmtTry<'ast> {
 /// Reference: <https://docs.soliditylang.org/en/latest/grammar.html#a4.SolidityParser.catchClause>
 #[derive(Debug)]
 pub struct CatchClause<'ast> {
+    /// The span of the clause, from the `catch` keyword to the end of the block.
+    pub span: Span,
     pub name: Option<Ident>,
     pub args: ParameterList<'ast>,
     pub block: Block<'ast>,
@@ -1,7 +1,7 @@
 //! Yul AST.
 
 use super::{AstPath, Box, DocComments, Lit, StrLit};
-use solar_interface::{Ident, Span};
+use solar_interface::{sym, Ident, Span, Symbol};
 
 /// A block of Yul statements: `{ ... }`.
 ///
@@ -189,3 +189,62 @@ pub struct ExprCall<'ast> {
     pub name: Ident,
     pub arguments: Box<'ast, [Expr<'ast>]>,
 }
+
+impl ExprCall<'_> {
+    /// Returns the object builtin that this expression calls, if any.
+    pub fn object_builtin(&self) -> Option<ObjectBuiltin> {
+        ObjectBuiltin::from_name(self.name.name)
+    }
+}
+
+/// A builtin function that refers to Yul objects, immutables or linked libraries.
+///
+/// Unlike the EVM builtins, these are not reserved keywords.
+///
+/// Reference: <https://docs.soliditylang.org/en/latest/yul.html#specification-of-yul-object>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ObjectBuiltin {
+    /// `dataoffset("name")`
+    DataOffset,
+    /// `datasize("name")`
+    DataSize,
+    /// `linkersymbol("library_id")`
+    LinkerSymbol,
+    /// `setimmutable(offset, "name", value)`
+    SetImmutable,
+    /// `loadimmutable("name")`
+    LoadImmutable,
+}
+
+impl ObjectBuiltin {
+    /// Returns the builtin with the given name, if any.
+    pub fn from_name(name: Symbol) -> Option<Self> {
+        Some(match name {
+            sym::dataoffset => Self::DataOffset,
+            sym::datasize => Self::DataSize,
+            sym::linkersymbol => Self::LinkerSymbol,
+            sym::setimmutable => Self::SetImmutable,
+            sym::loadimmutable => Self::LoadImmutable,
+            _ => return None,
+        })
+    }
+
+    /// Returns the name of the builtin.
+    pub fn name(self) -> Symbol {
+        match self {
+            Self::DataOffset => sym::dataoffset,
+            Self::DataSize => sym::datasize,
+            Self::LinkerSymbol => sym::linkersymbol,
+            Self::SetImmutable => sym::setimmutable,
+            Self::LoadImmutable => sym::loadimmutable,
+        }
+    }
+
+    /// Returns the number of arguments that the builtin takes.
+    pub fn arity(self) -> usize {
+        match self {
+            Self::DataOffset | Self::DataSize | Self::LinkerSymbol | Self::LoadImmutable => 1,
+            Self::SetImmutable => 3,
+        }
+    }
+}
@@ -1,7 +1,7 @@
 //! Solidity source code token.
 
 use crate::ast::{BinOp, BinOpKind, UnOp, UnOpKind};
-use solar_interface::{diagnostics::ErrorGuaranteed, Ident, Span, Symbol};
+use solar_interface::{diagnostics::ErrorGuaranteed, kw, Ident, Span, Symbol};
 use std::{borrow::Cow, fmt};
 
 /// The type of a comment.
@@ -580,12 +580,13 @@ impl Token {
         self.is_ident_where(|i| i.is_non_reserved(yul))
     }
 
-    /// Returns `true` if the token is an elementary type name.
+    /// Returns `true` if the token is an elementary type name, or `byte`, the former alias of
+    /// `bytes1`.
     ///
     /// Note that this does not include `[u]fixedMxN` types.
     #[inline]
     pub fn is_elementary_type(&self) -> bool {
-        self.is_ident_where(Ident::is_elementary_type)
+        self.is_ident_where(|id| id.is_elementary_type() || id.name == kw::Byte)
     }
 
     /// Returns `true` if the token is the identifier `true` or `false`.
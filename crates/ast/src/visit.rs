@@ -329,7 +329,8 @@ declare_visitors! {
         }
 
         fn visit_catch_clause(&mut self, catch: &'ast #mut CatchClause<'ast>) {
-            let CatchClause { name, args, block } = catch;
+            let CatchClause { span, name, args, block } = catch;
+            self.visit_span #_mut(span);
             if let Some(name) = name {
                 self.visit_ident #_mut(name);
             }
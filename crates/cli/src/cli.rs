@@ -1,7 +1,11 @@
 //! Solar CLI arguments.
 
 use clap::{ColorChoice, Parser, ValueHint};
-use solar_config::{CompilerOutput, CompilerStage, Dump, EvmVersion, Language};
+use solar_config::{
+    CompilerOutput, CompilerStage, DedupKey, DedupScope, Dump, EvmVersion, FunctionSpecifier,
+    ImportGraphFormat, Language, LintLevel, SolcVersion, UnresolvedImports,
+};
+use solar_interface::{diagnostics::DiagnosticId, lint::PathPattern};
 use std::path::PathBuf;
 
 /// Blazingly fast Solidity compiler.
@@ -23,6 +27,31 @@ pub struct Args {
     /// Map to search for files. Can also be provided as a positional argument.
     #[arg(help_heading = "Input options", long, short = 'm', value_name = "MAP=PATH")]
     pub import_map: Vec<ImportMap>,
+    /// Do not follow symlinks when resolving files; fail instead.
+    #[arg(help_heading = "Input options", long)]
+    pub no_follow_symlinks: bool,
+    /// Directories from which files may be loaded, in addition to the import paths and the
+    /// directories of the input files.
+    ///
+    /// When specified, resolving any file whose target is outside of these directories fails,
+    /// even if it is reached through a symlink.
+    #[arg(help_heading = "Input options", long, value_delimiter = ',', value_hint = ValueHint::DirPath)]
+    pub allow_paths: Vec<PathBuf>,
+    /// How to handle imports that cannot be resolved.
+    ///
+    /// `continue` reports an error and analyzes the rest of the sources; `fatal` stops parsing.
+    #[arg(help_heading = "Input options", long, value_enum, default_value_t)]
+    pub unresolved_imports: UnresolvedImports,
+    /// The maximum number of nested imports through which a source can be reached.
+    ///
+    /// Deeper imports are reported as errors and not resolved.
+    #[arg(
+        help_heading = "Input options",
+        long,
+        value_name = "DEPTH",
+        default_value_t = solar_interface::Session::DEFAULT_MAX_IMPORT_DEPTH
+    )]
+    pub max_import_depth: usize,
     /// Source code language. Only Solidity is currently implemented.
     #[arg(help_heading = "Input options", long, value_enum, default_value_t, hide = true)]
     pub language: Language,
@@ -33,9 +62,34 @@ pub struct Args {
     /// EVM version.
     #[arg(long, value_enum, default_value_t)]
     pub evm_version: EvmVersion,
+    /// Emulate the language rules of the given solc version, regardless of the version pragma.
+    ///
+    /// This controls the availability of `transfer` and `send` on non-payable addresses, whether
+    /// contract functions must specify their visibility, whether functions in interfaces must be
+    /// `external`, whether events must be invoked with `emit`, whether return values can be in
+    /// `calldata`, whether `byte` is an alias for `bytes1`, whether `selfdestruct` is reported as
+    /// deprecated, and whether immutables can be read before they are assigned.
+    #[arg(long, value_name = "VERSION")]
+    pub solc_compat: Option<SolcVersion>,
     /// Stop execution after the given compiler stage.
     #[arg(long, value_enum)]
     pub stop_after: Option<CompilerStage>,
+    /// Run all analyses to report diagnostics, but do not emit any output.
+    ///
+    /// Equivalent to `--stop-after analysis`.
+    #[arg(long, conflicts_with = "stop_after")]
+    pub check: bool,
+    /// Comma separated list of warning codes to report as errors, like `3445,6162`.
+    ///
+    /// Warnings with other codes are still reported as warnings.
+    #[arg(long, value_delimiter = ',', value_name = "CODE")]
+    pub fail_on_warning: Vec<DiagnosticId>,
+    /// Fail if any diagnostic is emitted, including warnings and notes.
+    ///
+    /// Diagnostics that are not emitted, like disabled warnings, do not count. Compilation is not
+    /// stopped early; only the exit status is affected.
+    #[arg(long)]
+    pub fail_on_any_diagnostic: bool,
 
     /// Directory to write output files.
     #[arg(long, value_hint = ValueHint::DirPath)]
@@ -43,6 +97,12 @@ pub struct Args {
     /// Comma separated list of types of output for the compiler to emit.
     #[arg(long, value_delimiter = ',')]
     pub emit: Vec<CompilerOutput>,
+    /// Comma separated list of contract names to analyze and emit output for.
+    ///
+    /// All the sources are still parsed and resolved, but the other contracts are only checked
+    /// when they are inherited by a selected contract.
+    #[arg(long, value_delimiter = ',', value_name = "NAME")]
+    pub contract: Vec<String>,
 
     /// Coloring.
     #[arg(help_heading = "Display options", long, value_enum, default_value = "auto")]
@@ -61,6 +121,9 @@ pub struct Args {
     /// How errors and other messages are produced.
     #[arg(help_heading = "Display options", long, value_enum, default_value_t)]
     pub error_format: ErrorFormat,
+    /// The maximum width of human-readable diagnostics. Longer source lines are elided.
+    #[arg(help_heading = "Display options", long, value_name = "WIDTH")]
+    pub diagnostic_width: Option<usize>,
 
     /// Unstable flags. WARNING: these are completely unstable, and may change at any time.
     ///
@@ -112,6 +175,12 @@ pub struct UnstableFeatures {
     /// This is enabled by default on debug builds.
     #[arg(long)]
     pub track_diagnostics: bool,
+    /// The scope in which identical diagnostics are reported only once.
+    #[arg(long, value_enum, default_value_t)]
+    pub dedup_scope: DedupScope,
+    /// What makes two diagnostics identical for de-duplication.
+    #[arg(long, value_enum, default_value_t)]
+    pub dedup_key: DedupKey,
     /// Enables parsing Yul files for testing.
     #[arg(long)]
     pub parse_yul: bool,
@@ -120,6 +189,102 @@ pub struct UnstableFeatures {
     /// Valid kinds are `ast` and `hir`.
     #[arg(long, value_name = "KIND[=PATHS...]")]
     pub dump: Option<Dump>,
+    /// Emit the import graph of the sources after import resolution.
+    ///
+    /// Nodes are source files, and edges are imports, labeled with the kind of the import. The
+    /// graph is written to `import_graph.{dot,json}` in `--out-dir` if set, or to stdout.
+    #[arg(long, value_enum, value_name = "FORMAT")]
+    pub import_graph: Option<ImportGraphFormat>,
+    /// Emit a JSON report of the symbols reported by the `unused-*` lints after analysis.
+    ///
+    /// Only the lints that are not allowed contribute to the report. The report is written to
+    /// `unused.json` in `--out-dir` if set, or to stdout.
+    #[arg(long)]
+    pub unused_report: bool,
+    /// Warn about named imports, like `import {A} from "a.sol";`, that are never used.
+    #[arg(long)]
+    pub warn_unused_imports: bool,
+    /// Warn about private functions that are never used.
+    #[arg(long)]
+    pub warn_unused_functions: bool,
+    /// Warn about local variables and function parameters that are never used.
+    #[arg(long)]
+    pub warn_unused_variables: bool,
+    /// Warn about custom errors that are never used.
+    #[arg(long)]
+    pub warn_unused_errors: bool,
+    /// Warn about events that are never emitted.
+    #[arg(long)]
+    pub warn_unused_events: bool,
+    /// Warn about `payable` functions that never read `msg.value` nor forward value.
+    #[arg(long)]
+    pub warn_unused_payable: bool,
+    /// Warn about reads of `msg.data` and `msg.sig` in internal and private functions.
+    #[arg(long)]
+    pub warn_msg_data_in_internal: bool,
+    /// Warn about loops whose condition is never updated in their body.
+    #[arg(long)]
+    pub warn_unchanged_loop_condition: bool,
+    /// Warn about conversions of an expression to the type it already has.
+    #[arg(long)]
+    pub warn_redundant_conversions: bool,
+    /// Warn about implicit conversions from unsigned to signed integers, like `uint8` to `int16`.
+    #[arg(long)]
+    pub warn_sign_conversions: bool,
+    /// Warn about comparisons of boolean expressions to `true` or `false`, like `x == true`.
+    #[arg(long)]
+    pub warn_bool_literal_comparisons: bool,
+    /// Warn about functions whose cyclomatic complexity exceeds the given threshold.
+    ///
+    /// The cyclomatic complexity is one plus the number of decision points of the function:
+    /// `if` statements, loop conditions, `&&` and `||` operators, ternaries and `catch` clauses.
+    #[arg(long, value_name = "THRESHOLD")]
+    pub warn_cyclomatic_complexity: Option<usize>,
+    /// Warn about `assert(false)` and `require(false)`, which should be `revert` instead.
+    #[arg(long)]
+    pub warn_assert_false: bool,
+    /// Warn about `msg.data` slices and `msg.sig` comparisons that reimplement function dispatch.
+    #[arg(long)]
+    pub warn_manual_dispatch: bool,
+    /// Warn about `catch` clauses with an empty block, which silently ignore errors.
+    #[arg(long)]
+    pub warn_empty_catch: bool,
+    /// Warn about contracts that use `delegatecall` and declare state variables, which the
+    /// called code may overwrite.
+    #[arg(long)]
+    pub warn_delegatecall_storage: bool,
+    /// Warn about functions in the same contract or file with identical bodies, up to the names
+    /// of their parameters.
+    #[arg(long)]
+    pub warn_duplicate_function_bodies: bool,
+    /// Warn about function specifiers that are not in the canonical order.
+    #[arg(long)]
+    pub warn_specifier_order: bool,
+    /// The canonical order of function specifiers, like `visibility,mutability,modifiers`.
+    ///
+    /// Specifiers that are not listed must come after the listed ones. Defaults to the order
+    /// recommended by the Solidity style guide.
+    #[arg(long, value_enum, value_delimiter = ',', value_name = "SPECIFIERS")]
+    pub specifier_order: Option<Vec<FunctionSpecifier>>,
+    /// How to report the deprecated `pragma experimental SMTChecker;`.
+    ///
+    /// Equivalent to `-Zlint=smtchecker-pragma=LEVEL`.
+    #[arg(long, value_enum, value_name = "LEVEL")]
+    pub smtchecker_pragma: Option<LintLevel>,
+    /// Comma separated list of lint levels, like `unused-imports=warn,unused-events=deny`.
+    ///
+    /// The levels are `allow`, `warn` and `deny`. They override the `-Zwarn-*` flags, and the
+    /// default levels of the lints.
+    #[arg(long, value_delimiter = ',', value_name = "LINT=LEVEL")]
+    pub lint: Vec<LintArg>,
+    /// Comma separated list of lint levels for the files matching a glob pattern, like
+    /// `test/**:unused-payable=allow,*.t.sol:unused-variables=deny`.
+    ///
+    /// In the matching files, these override the levels set with `-Zlint`. `*` and `?` do not
+    /// match `/`, while `**` does. Patterns without a `/` are matched against the file name,
+    /// others against the path relative to the current directory.
+    #[arg(long, value_delimiter = ',', value_name = "PATTERN:LINT=LEVEL")]
+    pub lint_paths: Vec<LintPathArg>,
 
     /// Print help.
     #[arg(long, action = clap::ArgAction::Help)]
@@ -144,6 +309,8 @@ pub enum ErrorFormat {
     Json,
     /// Rustc-like JSON output.
     RustcJson,
+    /// GitHub Actions workflow commands, shown as inline annotations in pull requests.
+    Github,
 }
 
 /// A single import map, AKA remapping: `map=path`.
@@ -165,6 +332,39 @@ impl std::str::FromStr for ImportMap {
     }
 }
 
+/// The level of a lint: `name=level`.
+#[derive(Clone, Debug)]
+pub struct LintArg {
+    pub name: String,
+    pub level: LintLevel,
+}
+
+impl std::str::FromStr for LintArg {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((name, level)) = s.split_once('=') else { return Err("missing '='") };
+        let level = level.parse().map_err(|_| "invalid lint level")?;
+        Ok(Self { name: name.into(), level })
+    }
+}
+
+/// The level of a lint in the files matching a pattern: `pattern:name=level`.
+#[derive(Clone, Debug)]
+pub struct LintPathArg {
+    pub pattern: PathPattern,
+    pub lint: LintArg,
+}
+
+impl std::str::FromStr for LintPathArg {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((pattern, lint)) = s.rsplit_once(':') else { return Err("missing ':'") };
+        Ok(Self { pattern: pattern.parse()?, lint: lint.parse()? })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -208,5 +408,29 @@ mod tests {
         assert_eq!(unstable.test_value, Some(2));
         let unstable = parse(&["solar", "-Z", "test-value=2", "a.sol"]).unwrap();
         assert_eq!(unstable.test_value, Some(2));
+
+        let unstable =
+            parse(&["solar", "-Zspecifier-order=visibility,modifiers", "a.sol"]).unwrap();
+        let order = [FunctionSpecifier::Visibility, FunctionSpecifier::Modifiers];
+        assert_eq!(unstable.specifier_order.as_deref(), Some(&order[..]));
+        assert!(parse(&["solar", "-Zspecifier-order=visibility,returns", "a.sol"]).is_err());
+
+        let unstable =
+            parse(&["solar", "-Zlint=unused-imports=warn,unused-events=deny", "a.sol"]).unwrap();
+        let lints = unstable.lint.iter().map(|l| (&l.name[..], l.level)).collect::<Vec<_>>();
+        assert_eq!(
+            lints,
+            [("unused-imports", LintLevel::Warn), ("unused-events", LintLevel::Deny)]
+        );
+        assert!(parse(&["solar", "-Zlint=unused-imports", "a.sol"]).is_err());
+        assert!(parse(&["solar", "-Zlint=unused-imports=error", "a.sol"]).is_err());
+
+        let unstable =
+            parse(&["solar", "-Zlint-paths=test/**:unused-payable=allow", "a.sol"]).unwrap();
+        let [lint] = &unstable.lint_paths[..] else { panic!("{:?}", unstable.lint_paths) };
+        assert_eq!(lint.pattern.as_str(), "test/**");
+        assert_eq!((&lint.lint.name[..], lint.lint.level), ("unused-payable", LintLevel::Allow));
+        assert!(parse(&["solar", "-Zlint-paths=unused-payable=allow", "a.sol"]).is_err());
+        assert!(parse(&["solar", "-Zlint-paths=:unused-payable=allow", "a.sol"]).is_err());
     }
 }
@@ -6,12 +6,19 @@
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
 use clap::Parser as _;
-use cli::Args;
+use cli::{Args, LintPathArg};
+use solar_config::LintLevel;
 use solar_interface::{
-    diagnostics::{DiagCtxt, DynEmitter, HumanEmitter, JsonEmitter},
+    diagnostics::{DiagCtxt, DynEmitter, GithubEmitter, HumanEmitter, JsonEmitter},
+    lint::builtin,
     Result, Session, SourceMap,
 };
-use std::{collections::BTreeSet, num::NonZeroUsize, path::Path, sync::Arc};
+use std::{
+    collections::BTreeSet,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 pub mod cli;
 pub mod utils;
@@ -74,6 +81,16 @@ impl Compiler {
             non_stdin_args.filter(|arg| !arg.as_os_str().as_encoded_bytes().contains(&b'='));
 
         let mut pcx = solar_sema::ParsingContext::new(sess);
+        pcx.file_resolver.set_follow_symlinks(!args.no_follow_symlinks);
+        if !args.allow_paths.is_empty() {
+            let input_dirs = paths.clone().map(|path| match path.parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+                _ => PathBuf::from("."),
+            });
+            for path in args.allow_paths.iter().cloned().chain(input_dirs) {
+                pcx.file_resolver.add_allowed_path(path);
+            }
+        }
         let remappings = arg_remappings.chain(args.import_map.iter().cloned());
         for map in remappings {
             pcx.file_resolver.add_import_map(map.map, map.path);
@@ -97,7 +114,9 @@ impl Compiler {
     }
 
     fn finish_diagnostics(&self) -> Result {
-        self.sess.dcx.print_error_count()
+        let result = self.sess.dcx.print_error_count();
+        self.sess.dcx.flush();
+        result
     }
 }
 
@@ -112,9 +131,12 @@ fn run_compiler_with(args: Args, f: impl FnOnce(&Compiler) -> Result + Send) ->
                     clap::ColorChoice::Auto => solar_interface::ColorChoice::Auto,
                     clap::ColorChoice::Never => solar_interface::ColorChoice::Never,
                 };
-                let human = HumanEmitter::stderr(color)
+                let mut human = HumanEmitter::stderr(color)
                     .source_map(Some(source_map.clone()))
                     .ui_testing(ui_testing);
+                if let Some(width) = args.diagnostic_width {
+                    human = human.diagnostic_width(width);
+                }
                 Box::new(human)
             }
             cli::ErrorFormat::Json | cli::ErrorFormat::RustcJson => {
@@ -125,18 +147,70 @@ fn run_compiler_with(args: Args, f: impl FnOnce(&Compiler) -> Result + Send) ->
                     .ui_testing(ui_testing);
                 Box::new(json)
             }
+            cli::ErrorFormat::Github => {
+                let writer = Box::new(std::io::BufWriter::new(std::io::stdout()));
+                Box::new(GithubEmitter::new(writer, source_map.clone()))
+            }
         };
         let dcx = DiagCtxt::new(emitter).set_flags(|flags| {
-            flags.deduplicate_diagnostics &= !ui_testing;
+            flags.dedup_scope =
+                if ui_testing { solar_config::DedupScope::None } else { args.unstable.dedup_scope };
+            flags.dedup_key = args.unstable.dedup_key;
             flags.track_diagnostics &= !ui_testing;
             flags.track_diagnostics |= args.unstable.track_diagnostics;
+            flags.deny_warning_codes = args.fail_on_warning.clone();
+            flags.fail_on_any_diagnostic = args.fail_on_any_diagnostic;
         });
 
         let mut sess = Session::new(dcx, source_map);
         sess.evm_version = args.evm_version;
         sess.language = args.language;
-        sess.stop_after = args.stop_after;
+        sess.solc_compat = args.solc_compat;
+        sess.unresolved_imports = args.unresolved_imports;
+        sess.max_import_depth = args.max_import_depth;
+        sess.stop_after =
+            if args.check { Some(solar_config::CompilerStage::Analyzed) } else { args.stop_after };
         sess.dump = args.unstable.dump.clone();
+        sess.import_graph = args.unstable.import_graph;
+        sess.unused_report = args.unstable.unused_report;
+        let warn_lints = [
+            (args.unstable.warn_unused_imports, &builtin::UNUSED_IMPORTS),
+            (args.unstable.warn_unused_functions, &builtin::UNUSED_FUNCTIONS),
+            (args.unstable.warn_unused_variables, &builtin::UNUSED_VARIABLES),
+            (args.unstable.warn_unused_errors, &builtin::UNUSED_ERRORS),
+            (args.unstable.warn_unused_events, &builtin::UNUSED_EVENTS),
+            (args.unstable.warn_unused_payable, &builtin::UNUSED_PAYABLE),
+            (args.unstable.warn_msg_data_in_internal, &builtin::MSG_DATA_IN_INTERNAL),
+            (args.unstable.warn_unchanged_loop_condition, &builtin::UNCHANGED_LOOP_CONDITION),
+            (args.unstable.warn_redundant_conversions, &builtin::REDUNDANT_CONVERSIONS),
+            (args.unstable.warn_specifier_order, &builtin::SPECIFIER_ORDER),
+            (args.unstable.warn_cyclomatic_complexity.is_some(), &builtin::CYCLOMATIC_COMPLEXITY),
+            (args.unstable.warn_assert_false, &builtin::ASSERT_FALSE),
+            (args.unstable.warn_manual_dispatch, &builtin::MANUAL_DISPATCH),
+            (args.unstable.warn_empty_catch, &builtin::EMPTY_CATCH),
+            (args.unstable.warn_duplicate_function_bodies, &builtin::DUPLICATE_FUNCTION_BODIES),
+            (args.unstable.warn_delegatecall_storage, &builtin::DELEGATECALL_STORAGE),
+            (args.unstable.warn_sign_conversions, &builtin::SIGN_CONVERSIONS),
+            (args.unstable.warn_bool_literal_comparisons, &builtin::BOOL_LITERAL_COMPARISONS),
+        ];
+        for (_, lint) in warn_lints.into_iter().filter(|&(enabled, _)| enabled) {
+            sess.lint_levels.set(lint.name, LintLevel::Warn);
+        }
+        if let Some(threshold) = args.unstable.warn_cyclomatic_complexity {
+            sess.max_cyclomatic_complexity = threshold;
+        }
+        if let Some(order) = &args.unstable.specifier_order {
+            sess.specifier_order = order.clone();
+        }
+        if let Some(level) = args.unstable.smtchecker_pragma {
+            sess.lint_levels.set(builtin::SMTCHECKER_PRAGMA.name, level);
+        }
+        for lint in &args.unstable.lint {
+            sess.lint_levels.set(&lint.name, lint.level);
+        }
+        for LintPathArg { pattern, lint } in &args.unstable.lint_paths {
+            sess.lint_levels.set_for_paths(pattern.clone(), &lint.name, lint.level);
+        }
         sess.jobs = NonZeroUsize::new(jobs).unwrap();
         if !args.input.is_empty()
             && args.input.iter().all(|arg| arg.extension() == Some("yul".as_ref()))
@@ -154,6 +228,7 @@ fn run_compiler_with(args: Args, f: impl FnOnce(&Compiler) -> Result + Send) ->
             set
         };
         sess.out_dir = args.out_dir.clone();
+        sess.contracts = args.contract.clone();
         sess.pretty_json = args.pretty_json;
 
         let compiler = Compiler { sess, args };
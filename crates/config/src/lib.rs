@@ -20,6 +20,9 @@ str_enum! {
         /// Source code was parsed into an AST.
         #[strum(serialize = "parsed", serialize = "parsing")]
         Parsed,
+        /// Source code was fully analyzed. No output is emitted.
+        #[strum(serialize = "analyzed", serialize = "analysis")]
+        Analyzed,
         // TODO: More
     }
 }
@@ -97,6 +100,95 @@ impl EvmVersion {
     }
 }
 
+/// A `solc` version whose language rules to emulate, regardless of the version pragma.
+///
+/// Parsed from `MAJOR.MINOR` or `MAJOR.MINOR.PATCH`; the patch version defaults to `0`.
+///
+/// The following behaviors are controlled by this version:
+/// - `string` and `bytes` can be `constant` since `0.4.0`;
+/// - `transfer` and `send` are available on all addresses before `0.5.0`, and only on
+///   `address payable` afterwards;
+/// - contract functions must specify their visibility since `0.5.0`;
+/// - functions in interfaces must be `external` since `0.5.0`;
+/// - events must be invoked with `emit` since `0.5.0`;
+/// - return values can be in `calldata` since `0.6.9`;
+/// - `address.code` and `address.codehash` are available since `0.8.0`;
+/// - `byte` is an alias for `bytes1` before `0.8.0`;
+/// - `bytes.concat` is available since `0.8.4`, and `string.concat` since `0.8.12`;
+/// - `selfdestruct` is reported as deprecated since `0.8.18`;
+/// - immutables can be read before they are assigned in the constructor since `0.8.21`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SolcVersion {
+    pub major: u8,
+    pub minor: u8,
+    pub patch: u8,
+}
+
+impl SolcVersion {
+    /// Creates a new version.
+    pub const fn new(major: u8, minor: u8, patch: u8) -> Self {
+        Self { major, minor, patch }
+    }
+
+    pub fn has_constant_bytes(self) -> bool {
+        self >= Self::new(0, 4, 0)
+    }
+    pub fn has_address_payable(self) -> bool {
+        self >= Self::new(0, 5, 0)
+    }
+    pub fn requires_function_visibility(self) -> bool {
+        self >= Self::new(0, 5, 0)
+    }
+    pub fn requires_external_interface_functions(self) -> bool {
+        self >= Self::new(0, 5, 0)
+    }
+    pub fn requires_emit(self) -> bool {
+        self >= Self::new(0, 5, 0)
+    }
+    pub fn has_calldata_returns(self) -> bool {
+        self >= Self::new(0, 6, 9)
+    }
+    pub fn has_address_code(self) -> bool {
+        self >= Self::new(0, 8, 0)
+    }
+    pub fn has_byte_alias(self) -> bool {
+        self < Self::new(0, 8, 0)
+    }
+    pub fn has_bytes_concat(self) -> bool {
+        self >= Self::new(0, 8, 4)
+    }
+    pub fn has_string_concat(self) -> bool {
+        self >= Self::new(0, 8, 12)
+    }
+    pub fn has_selfdestruct_deprecation(self) -> bool {
+        self >= Self::new(0, 8, 18)
+    }
+    pub fn has_relaxed_immutables(self) -> bool {
+        self >= Self::new(0, 8, 21)
+    }
+}
+
+impl std::str::FromStr for SolcVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || format!("invalid solc version `{s}`, expected `MAJOR.MINOR[.PATCH]`");
+        let mut parts = s.split('.').map(|part| part.parse::<u8>().map_err(|_| err()));
+        let (Some(major), Some(minor)) = (parts.next(), parts.next()) else { return Err(err()) };
+        let patch = parts.next().unwrap_or(Ok(0))?;
+        if parts.next().is_some() {
+            return Err(err());
+        }
+        Ok(Self::new(major?, minor?, patch))
+    }
+}
+
+impl std::fmt::Display for SolcVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
 str_enum! {
     /// Type of output for the compiler to emit.
     #[strum(serialize_all = "kebab-case")]
@@ -147,6 +239,106 @@ str_enum! {
     }
 }
 
+str_enum! {
+    /// The format of the import graph. See `-Zimport-graph`.
+    #[strum(serialize_all = "kebab-case")]
+    pub enum ImportGraphFormat {
+        /// Graphviz DOT.
+        Dot,
+        /// JSON, with a list of nodes and a list of edges between them.
+        Json,
+    }
+}
+
+str_enum! {
+    /// The scope in which identical diagnostics are reported only once.
+    #[derive(Default)]
+    #[derive(EnumIs)]
+    #[strum(serialize_all = "kebab-case")]
+    pub enum DedupScope {
+        /// Identical diagnostics are reported once per compilation.
+        #[default]
+        Global,
+        /// Identical diagnostics are reported once per source file.
+        PerFile,
+        /// Diagnostics are never de-duplicated. Notes and helps that are marked to be reported
+        /// once are still only reported once.
+        None,
+    }
+}
+
+str_enum! {
+    /// What makes two diagnostics identical for de-duplication. See [`DedupScope`].
+    #[derive(Default)]
+    #[derive(EnumIs)]
+    #[strum(serialize_all = "kebab-case")]
+    pub enum DedupKey {
+        /// Diagnostics are identical if their level, messages, code, spans, and children are
+        /// equal.
+        #[default]
+        Exact,
+        /// Diagnostics are identical if their level, code, and messages are equal, ignoring
+        /// differences in whitespace. Spans and children are ignored, so the same error reported
+        /// at many positions is only reported once.
+        Message,
+    }
+}
+
+str_enum! {
+    /// How to handle imports that cannot be resolved.
+    #[derive(Default)]
+    #[derive(EnumIs)]
+    #[strum(serialize_all = "kebab-case")]
+    pub enum UnresolvedImports {
+        /// Emit an error and continue parsing and resolving the rest of the sources.
+        ///
+        /// Names imported from the unresolved file resolve to an error without further
+        /// diagnostics.
+        #[default]
+        Continue,
+        /// Emit an error and stop parsing.
+        Fatal,
+    }
+}
+
+str_enum! {
+    /// The level at which a lint is reported.
+    #[derive(EnumIs)]
+    #[strum(serialize_all = "kebab-case")]
+    pub enum LintLevel {
+        /// Do not report it.
+        Allow,
+        /// Report it as a warning.
+        Warn,
+        /// Report it as an error.
+        Deny,
+    }
+}
+
+str_enum! {
+    /// A kind of function specifier, used to configure their canonical order.
+    #[derive(EnumIs)]
+    #[strum(serialize_all = "kebab-case")]
+    pub enum FunctionSpecifier {
+        /// `public`, `external`, `internal`, or `private`.
+        Visibility,
+        /// `pure`, `view`, or `payable`.
+        Mutability,
+        /// `virtual`.
+        Virtual,
+        /// `override` or `override(A, B)`.
+        Override,
+        /// Modifier invocations, like `onlyOwner`.
+        Modifiers,
+    }
+}
+
+impl FunctionSpecifier {
+    /// The order of function specifiers recommended by the Solidity style guide.
+    pub const DEFAULT_ORDER: [Self; 5] =
+        [Self::Visibility, Self::Mutability, Self::Virtual, Self::Override, Self::Modifiers];
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,4 +361,27 @@ mod tests {
             }
         }
     }
+    #[test]
+    fn solc_version() {
+        assert_eq!("0.8".parse(), Ok(SolcVersion::new(0, 8, 0)));
+        assert_eq!("0.4.26".parse(), Ok(SolcVersion::new(0, 4, 26)));
+        assert_eq!(SolcVersion::new(0, 4, 26).to_string(), "0.4.26");
+        for s in ["", "0", "0.", "0.8.", "0.8.1.2", "v0.8", "0.x"] {
+            assert!(s.parse::<SolcVersion>().is_err(), "{s:?}");
+        }
+        assert!(!SolcVersion::new(0, 4, 26).has_address_payable());
+        assert!(SolcVersion::new(0, 5, 0).has_address_payable());
+        assert!(!SolcVersion::new(0, 4, 26).requires_emit());
+        assert!(SolcVersion::new(0, 5, 0).requires_emit());
+        assert!(!SolcVersion::new(0, 6, 8).has_calldata_returns());
+        assert!(SolcVersion::new(0, 6, 9).has_calldata_returns());
+        assert!(!SolcVersion::new(0, 7, 6).has_address_code());
+        assert!(SolcVersion::new(0, 8, 0).has_address_code());
+        assert!(SolcVersion::new(0, 7, 6).has_byte_alias());
+        assert!(!SolcVersion::new(0, 8, 0).has_byte_alias());
+        assert!(!SolcVersion::new(0, 8, 17).has_selfdestruct_deprecation());
+        assert!(SolcVersion::new(0, 8, 18).has_selfdestruct_deprecation());
+        assert!(!SolcVersion::new(0, 8, 20).has_relaxed_immutables());
+        assert!(SolcVersion::new(0, 8, 21).has_relaxed_immutables());
+    }
 }
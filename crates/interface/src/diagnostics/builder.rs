@@ -1,6 +1,6 @@
 use super::{
-    BugAbort, DiagCtxt, Diagnostic, DiagnosticId, DiagnosticMessage, ErrorGuaranteed, ExplicitBug,
-    FatalAbort, Level, MultiSpan, Style,
+    Applicability, BugAbort, DiagCtxt, Diagnostic, DiagnosticId, DiagnosticMessage,
+    ErrorGuaranteed, ExplicitBug, FatalAbort, Level, MultiSpan, Style, Subdiagnostic,
 };
 use crate::Span;
 use solar_data_structures::Never;
@@ -219,5 +219,9 @@ impl<G: EmissionGuarantee> DiagnosticBuilder<'_, G> {
         pub fn help_once(msg: impl Into<DiagnosticMessage>);
         pub fn highlighted_help(messages: Vec<(impl Into<DiagnosticMessage>, Style)>);
         pub fn span_help(span: impl Into<MultiSpan>, msg: impl Into<DiagnosticMessage>);
+        pub fn span_help_once(span: impl Into<MultiSpan>, msg: impl Into<DiagnosticMessage>);
+        pub fn span_suggestion(span: Span, msg: impl Into<DiagnosticMessage>, replacement: impl Into<String>, applicability: Applicability);
+
+        pub fn subdiagnostic(subdiagnostic: impl Subdiagnostic);
     }
 }
@@ -1,25 +1,37 @@
 use super::{
-    emitter::HumanEmitter, BugAbort, Diagnostic, DiagnosticBuilder, DiagnosticMessage, DynEmitter,
-    EmissionGuarantee, EmittedDiagnostics, ErrorGuaranteed, FatalAbort, HumanBufferEmitter, Level,
-    SilentEmitter,
+    emitter::HumanEmitter, BugAbort, Diagnostic, DiagnosticBuilder, DiagnosticId,
+    DiagnosticMessage, DynEmitter, EmissionGuarantee, EmittedDiagnostics, ErrorGuaranteed,
+    FatalAbort, HumanBufferEmitter, Level, SilentEmitter,
 };
 use crate::{Result, SourceMap};
 use anstream::ColorChoice;
+use solar_config::{DedupKey, DedupScope};
 use solar_data_structures::{map::FxHashSet, sync::Lock};
 use std::{borrow::Cow, hash::BuildHasher, num::NonZeroUsize, sync::Arc};
 
 /// Flags that control the behaviour of a [`DiagCtxt`].
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub struct DiagCtxtFlags {
     /// If false, warning-level lints are suppressed.
     pub can_emit_warnings: bool,
     /// If Some, the Nth error-level diagnostic is upgraded to bug-level.
     pub treat_err_as_bug: Option<NonZeroUsize>,
-    /// If true, identical diagnostics are reported only once.
-    pub deduplicate_diagnostics: bool,
+    /// The scope in which identical diagnostics are reported only once.
+    pub dedup_scope: DedupScope,
+    /// What makes two diagnostics identical for de-duplication.
+    pub dedup_key: DedupKey,
     /// Track where errors are created. Enabled with `-Ztrack-diagnostics`, and by default in debug
     /// builds.
     pub track_diagnostics: bool,
+    /// Warnings with one of these codes are promoted to errors. Set with `--fail-on-warning`.
+    pub deny_warning_codes: Vec<DiagnosticId>,
+    /// If true, emitting any diagnostic, of any level, makes
+    /// [`print_error_count`](DiagCtxt::print_error_count) fail. Diagnostics that are suppressed or
+    /// de-duplicated are not counted. Set with `--fail-on-any-diagnostic`.
+    ///
+    /// This does not affect [`has_errors`](DiagCtxt::has_errors), so compilation is not stopped
+    /// early by non-error diagnostics.
+    pub fail_on_any_diagnostic: bool,
 }
 
 impl Default for DiagCtxtFlags {
@@ -27,8 +39,11 @@ impl Default for DiagCtxtFlags {
         Self {
             can_emit_warnings: true,
             treat_err_as_bug: None,
-            deduplicate_diagnostics: true,
+            dedup_scope: DedupScope::Global,
+            dedup_key: DedupKey::Exact,
             track_diagnostics: cfg!(debug_assertions),
+            deny_warning_codes: Vec::new(),
+            fail_on_any_diagnostic: false,
         }
     }
 }
@@ -54,6 +69,8 @@ struct DiagCtxtInner {
     warn_count: usize,
     /// The warning count, used for a recap upon finishing
     deduplicated_warn_count: usize,
+    /// The number of diagnostics of any level that have been emitted, excluding duplicates.
+    deduplicated_count: usize,
 
     /// This set contains a hash of every diagnostic that has been emitted by this `DiagCtxt`.
     /// These hashes are used to avoid emitting the same error twice.
@@ -71,6 +88,7 @@ impl DiagCtxt {
                 deduplicated_err_count: 0,
                 warn_count: 0,
                 deduplicated_warn_count: 0,
+                deduplicated_count: 0,
                 emitted_diagnostics: FxHashSet::default(),
             }),
         }
@@ -114,6 +132,22 @@ impl DiagCtxt {
         Self::new(Box::new(HumanBufferEmitter::new(color_choice).source_map(source_map)))
     }
 
+    /// Replaces the emitter and resets the context for a fresh run, returning the previous
+    /// emitter.
+    ///
+    /// The error and warning counts are reset, and diagnostics that were already emitted are no
+    /// longer de-duplicated. The flags are kept.
+    pub fn reset(&self, emitter: Box<DynEmitter>) -> Box<DynEmitter> {
+        let mut inner = self.inner.lock();
+        inner.err_count = 0;
+        inner.deduplicated_err_count = 0;
+        inner.warn_count = 0;
+        inner.deduplicated_warn_count = 0;
+        inner.deduplicated_count = 0;
+        inner.emitted_diagnostics.clear();
+        std::mem::replace(&mut inner.emitter, emitter)
+    }
+
     /// Gets the source map associated with this context.
     pub fn source_map(&self) -> Option<Arc<SourceMap>> {
         self.inner.lock().emitter.source_map().cloned()
@@ -185,9 +219,19 @@ impl DiagCtxt {
     }
 
     /// Emits a diagnostic if any warnings or errors have been emitted.
+    ///
+    /// Returns `Err` if any errors have been emitted, or any diagnostics at all with
+    /// [`fail_on_any_diagnostic`](DiagCtxtFlags::fail_on_any_diagnostic).
     pub fn print_error_count(&self) -> Result {
         self.inner.lock().print_error_count()
     }
+
+    /// Writes out the diagnostics buffered by the emitter.
+    ///
+    /// See [`Emitter::flush`](super::Emitter::flush).
+    pub fn flush(&self) {
+        self.inner.lock().emitter.flush();
+    }
 }
 
 /// Diagnostic constructors.
@@ -252,6 +296,16 @@ impl DiagCtxtInner {
         &mut self,
         diagnostic: &mut Diagnostic,
     ) -> Result<(), ErrorGuaranteed> {
+        if diagnostic.level == Level::Warning {
+            if let Some(code) = &diagnostic.code {
+                if self.flags.deny_warning_codes.contains(code) {
+                    diagnostic.level = Level::Error;
+                    let note = format!("requested with `--fail-on-warning {}`", code.as_string());
+                    diagnostic.note(note);
+                }
+            }
+        }
+
         if diagnostic.level == Level::Warning && !self.flags.can_emit_warnings {
             return Ok(());
         }
@@ -264,14 +318,23 @@ impl DiagCtxtInner {
             diagnostic.level = Level::Bug;
         }
 
-        let already_emitted = self.insert_diagnostic(diagnostic);
-        if !(self.flags.deduplicate_diagnostics && already_emitted) {
+        let file = self.dedup_file(diagnostic);
+        let already_emitted = match self.flags.dedup_key {
+            DedupKey::Exact => self.insert_diagnostic(&(file, &*diagnostic)),
+            DedupKey::Message => {
+                let message = diagnostic.label().split_whitespace().collect::<Vec<_>>().join(" ");
+                let key = (file, diagnostic.level, &diagnostic.code, message);
+                self.insert_diagnostic(&key)
+            }
+        };
+        let deduplicate = !self.flags.dedup_scope.is_none();
+        if !(deduplicate && already_emitted) {
             // Remove duplicate `Once*` subdiagnostics.
             diagnostic.children.retain(|sub| {
                 if !matches!(sub.level, Level::OnceNote | Level::OnceHelp) {
                     return true;
                 }
-                let sub_already_emitted = self.insert_diagnostic(sub);
+                let sub_already_emitted = self.insert_diagnostic(&(file, sub));
                 !sub_already_emitted
             });
 
@@ -282,6 +345,7 @@ impl DiagCtxtInner {
             // }
 
             self.emitter.emit_diagnostic(diagnostic);
+            self.deduplicated_count += 1;
             if diagnostic.is_error() {
                 self.deduplicated_err_count += 1;
             } else if diagnostic.level == Level::Warning {
@@ -305,6 +369,19 @@ impl DiagCtxtInner {
             return Ok(());
         }
 
+        if self.deduplicated_err_count == 0
+            && self.flags.fail_on_any_diagnostic
+            && self.deduplicated_count > 0
+        {
+            let msg = match self.deduplicated_count {
+                1 => Cow::from("aborting due to 1 previous diagnostic"),
+                count => Cow::from(format!("aborting due to {count} previous diagnostics")),
+            };
+            let mut diagnostic = Diagnostic::new(Level::Error, msg);
+            diagnostic.note("requested with `--fail-on-any-diagnostic`");
+            return self.emit_diagnostic(diagnostic);
+        }
+
         let warnings = |count| match count {
             0 => unreachable!(),
             1 => Cow::from("1 warning emitted"),
@@ -330,6 +407,20 @@ impl DiagCtxtInner {
         }
     }
 
+    /// Returns the index of the source file that the given diagnostic is de-duplicated in, if
+    /// de-duplication is [per file](DedupScope::PerFile).
+    fn dedup_file(&self, diagnostic: &Diagnostic) -> Option<usize> {
+        if !self.flags.dedup_scope.is_per_file() {
+            return None;
+        }
+        let span = diagnostic.span.primary_span().filter(|span| !span.is_dummy())?;
+        let sm = self.emitter.source_map()?;
+        if sm.files().is_empty() {
+            return None;
+        }
+        Some(sm.lookup_source_file_idx(span.lo()))
+    }
+
     /// Inserts the given diagnostic into the set of emitted diagnostics.
     /// Returns `true` if the diagnostic was already emitted.
     fn insert_diagnostic<H: std::hash::Hash>(&mut self, diag: &H) -> bool {
@@ -365,3 +456,153 @@ impl DiagCtxtInner {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::{
+        test_utils::{span, TestDcx},
+        Emitter,
+    };
+    use std::sync::Mutex;
+
+    #[test]
+    fn flush() {
+        #[derive(Default)]
+        struct BufferingEmitter {
+            pending: Vec<String>,
+            written: Arc<Mutex<Vec<String>>>,
+        }
+        impl Emitter for BufferingEmitter {
+            fn emit_diagnostic(&mut self, diagnostic: &Diagnostic) {
+                self.pending.push(diagnostic.label().into_owned());
+            }
+            fn flush(&mut self) {
+                self.written.lock().unwrap().append(&mut self.pending);
+            }
+        }
+
+        let emitter = BufferingEmitter::default();
+        let written = emitter.written.clone();
+        let dcx = DiagCtxt::new(Box::new(emitter));
+        dcx.warn("first").emit();
+        dcx.warn("second").emit();
+        assert!(written.lock().unwrap().is_empty());
+
+        dcx.flush();
+        assert_eq!(*written.lock().unwrap(), ["first", "second"]);
+
+        // Flushing again does not write anything new.
+        dcx.flush();
+        assert_eq!(written.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn reset() {
+        let dcx = DiagCtxt::with_buffer_emitter(None, ColorChoice::Never)
+            .set_flags(|flags| flags.track_diagnostics = false);
+        dcx.err("first").emit();
+        dcx.err("first").emit();
+        dcx.warn("warning").emit();
+        assert_eq!(dcx.err_count(), 2);
+        let err = dcx.emitted_diagnostics().unwrap().unwrap_err().to_string();
+        assert_eq!(err.matches("error: first").count(), 1, "{err}");
+
+        let old = dcx.reset(Box::new(HumanBufferEmitter::new(ColorChoice::Never)));
+        assert_eq!(old.local_buffer().unwrap(), err);
+        assert_eq!(dcx.err_count(), 0);
+        assert!(dcx.has_errors().is_ok());
+        assert!(matches!(dcx.emitted_diagnostics(), Some(Ok(()))));
+
+        // The same diagnostic is emitted again in the new run.
+        dcx.err("first").emit();
+        assert_eq!(dcx.err_count(), 1);
+        let err = dcx.emitted_diagnostics().unwrap().unwrap_err().to_string();
+        assert_eq!(err.matches("error: first").count(), 1, "{err}");
+        assert!(!err.contains("warning"), "{err}");
+    }
+
+    #[test]
+    fn deny_warning_codes() {
+        let dcx = DiagCtxt::with_buffer_emitter(None, ColorChoice::Never)
+            .set_flags(|flags| flags.deny_warning_codes = vec!["6162".parse().unwrap()]);
+        dcx.warn("allowed").code(crate::error_code!(3445)).emit();
+        dcx.warn("denied").code(crate::error_code!(6162)).emit();
+        dcx.warn("uncoded").emit();
+        assert_eq!(dcx.err_count(), 1);
+
+        let err = dcx.emitted_diagnostics().unwrap().unwrap_err().to_string();
+        assert!(err.contains("warning[3445]: allowed"), "{err}");
+        assert!(err.contains("error[6162]: denied"), "{err}");
+        assert!(err.contains("requested with `--fail-on-warning 6162`"), "{err}");
+        assert!(err.contains("warning: uncoded"), "{err}");
+
+        assert!("123".parse::<DiagnosticId>().is_err());
+        assert!("0000".parse::<DiagnosticId>().is_err());
+        assert!("12a4".parse::<DiagnosticId>().is_err());
+    }
+
+    #[test]
+    fn fail_on_any_diagnostic() {
+        fn emit(fail_on_any_diagnostic: bool, can_emit_warnings: bool) -> Result<(), String> {
+            let dcx = DiagCtxt::with_buffer_emitter(None, ColorChoice::Never).set_flags(|flags| {
+                flags.fail_on_any_diagnostic = fail_on_any_diagnostic;
+                flags.can_emit_warnings = can_emit_warnings;
+            });
+            dcx.warn("only a warning").emit();
+            assert_eq!(dcx.err_count(), 0);
+            assert!(dcx.has_errors().is_ok());
+            let failed = dcx.print_error_count().is_err();
+            assert_eq!(failed, fail_on_any_diagnostic && can_emit_warnings);
+            match dcx.emitted_diagnostics().unwrap() {
+                Ok(()) if !failed => Ok(()),
+                Err(err) if failed => Err(err.to_string()),
+                result => panic!("{result:?}"),
+            }
+        }
+
+        let err = emit(true, true).unwrap_err();
+        assert!(err.contains("warning: only a warning"), "{err}");
+        assert!(err.contains("error: aborting due to 1 previous diagnostic"), "{err}");
+        assert!(err.contains("requested with `--fail-on-any-diagnostic`"), "{err}");
+        assert_eq!(emit(true, false), Ok(()));
+        assert_eq!(emit(false, true), Ok(()));
+    }
+
+    #[test]
+    fn dedup_scope() {
+        fn emit(scope: DedupScope) -> (usize, usize) {
+            let t = TestDcx::human(std::convert::identity).flags(|flags| flags.dedup_scope = scope);
+            for name in ["a.sol", "b.sol"] {
+                let span = span(&t.file(name, "contract A {}"), 0, 8);
+                for _ in 0..2 {
+                    t.dcx.err("test").span(span).note_once("once").emit();
+                }
+            }
+            let err = t.emitted();
+            (err.matches("error: test").count(), err.matches("note: once").count())
+        }
+
+        assert_eq!(emit(DedupScope::Global), (2, 1));
+        assert_eq!(emit(DedupScope::PerFile), (2, 2));
+        assert_eq!(emit(DedupScope::None), (4, 1));
+    }
+
+    #[test]
+    fn dedup_key() {
+        fn emit(key: DedupKey) -> usize {
+            let t = TestDcx::human(std::convert::identity).flags(|flags| flags.dedup_key = key);
+            let file = t.file("a.sol", "contract A {}");
+            t.dcx.err("unexpected  token").span(span(&file, 0, 8)).emit();
+            t.dcx.err("unexpected token").span(span(&file, 0, 8)).emit();
+            t.dcx.err("unexpected token").span(span(&file, 4, 4)).emit();
+            t.dcx.err("unexpected\ntoken").span(span(&file, 4, 4)).note("note").emit();
+            t.dcx.warn("unexpected token").span(span(&file, 0, 8)).emit();
+            let err = t.emitted();
+            err.matches("error: unexpected").count() + err.matches("warning: unexpected").count()
+        }
+
+        assert_eq!(emit(DedupKey::Exact), 5);
+        assert_eq!(emit(DedupKey::Message), 2);
+    }
+}
@@ -0,0 +1,130 @@
+use super::{io_panic, Emitter};
+use crate::{
+    diagnostics::{Diagnostic, Level},
+    SourceMap,
+};
+use std::{fmt::Write as _, io, sync::Arc};
+
+/// Diagnostic emitter that emits diagnostics as GitHub Actions workflow commands.
+///
+/// Each diagnostic is printed on its own line as
+/// `::{error,warning,notice} file={file},line={line},col={col},...::{message}`, which GitHub
+/// turns into an inline annotation on the pull request.
+///
+/// See <https://docs.github.com/en/actions/reference/workflow-commands-for-github-actions>.
+pub struct GithubEmitter {
+    writer: Box<dyn io::Write + Send>,
+    source_map: Arc<SourceMap>,
+}
+
+impl Emitter for GithubEmitter {
+    fn emit_diagnostic(&mut self, diagnostic: &Diagnostic) {
+        let command = self.command(diagnostic);
+        self.emit(&command).unwrap_or_else(|e| io_panic(e));
+    }
+
+    fn source_map(&self) -> Option<&Arc<SourceMap>> {
+        Some(&self.source_map)
+    }
+}
+
+impl GithubEmitter {
+    /// Creates a new `GithubEmitter` that writes to given writer.
+    pub fn new(writer: Box<dyn io::Write + Send>, source_map: Arc<SourceMap>) -> Self {
+        Self { writer, source_map }
+    }
+
+    fn command(&self, diagnostic: &Diagnostic) -> String {
+        let mut command = format!("::{}", to_command(diagnostic.level));
+
+        if let Some(span) = diagnostic.span.primary_span() {
+            let sm = &*self.source_map;
+            let start = sm.lookup_char_pos(span.lo());
+            let end = sm.lookup_char_pos(span.hi());
+            let file = sm.filename_for_diagnostics(&start.file.name).to_string();
+            let _ = write!(
+                command,
+                " file={},line={},col={},endLine={},endColumn={}",
+                escape_property(&file),
+                start.line,
+                start.col.0 + 1,
+                end.line,
+                end.col.0 + 1,
+            );
+        }
+
+        let mut message = match diagnostic.id() {
+            Some(code) => format!("[{code}] {}", diagnostic.label()),
+            None => diagnostic.label().into_owned(),
+        };
+        for sub in &diagnostic.children {
+            let _ = write!(message, "\n{}: {}", sub.level.to_str(), sub.label());
+        }
+        for sugg in &diagnostic.suggestions {
+            let _ = write!(message, "\n{}: {}", Level::Help.to_str(), sugg.label());
+        }
+
+        command.push_str("::");
+        command.push_str(&escape_data(&message));
+        command
+    }
+
+    fn emit(&mut self, command: &str) -> io::Result<()> {
+        self.writer.write_all(command.as_bytes())?;
+        self.writer.write_all(b"\n")?;
+        self.writer.flush()
+    }
+}
+
+fn to_command(level: Level) -> &'static str {
+    match level {
+        Level::Bug | Level::Fatal | Level::Error => "error",
+        Level::Warning => "warning",
+        #[rustfmt::skip]
+        Level::Note | Level::OnceNote | Level::FailureNote |
+        Level::Help | Level::OnceHelp |
+        Level::Allow => "notice",
+    }
+}
+
+/// Escapes the message of a workflow command.
+fn escape_data(s: &str) -> String {
+    s.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+/// Escapes a property value of a workflow command.
+fn escape_property(s: &str) -> String {
+    escape_data(s).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::test_utils::{span, Buf, TestDcx};
+
+    #[test]
+    fn github_annotations() {
+        let buf = Buf::default();
+        let t = TestDcx::new(|sm| Box::new(GithubEmitter::new(Box::new(buf.clone()), sm)));
+        let file = t.file("a,b.sol", "contract A {\n    uint x = y;\n}");
+        t.dcx
+            .err("unresolved symbol: 100% sure")
+            .code(crate::error_code!(7576))
+            .span(span(&file, 26, 1))
+            .note("first line\r\nsecond line")
+            .emit();
+        t.dcx.warn("unspanned").emit();
+
+        let out = buf.contents();
+        let lines = out.lines().collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            [
+                "::error file=a%2Cb.sol,line=2,col=14,endLine=2,endColumn=15::[7576] unresolved \
+                 symbol: 100%25 sure%0Anote: first line%0D%0Asecond line",
+                "::warning::unspanned",
+            ],
+            "{out}"
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use super::{io_panic, rustc::FileWithAnnotatedLines, Diagnostic, Emitter};
 use crate::{
-    diagnostics::{Level, MultiSpan, Style, SubDiagnostic},
+    diagnostics::{CodeSuggestion, Level, MultiSpan, Style, SubDiagnostic},
     source_map::SourceFile,
     SourceMap,
 };
@@ -34,6 +34,12 @@ pub struct HumanEmitter {
     writer: AutoStream<Box<Writer>>,
     source_map: Option<Arc<SourceMap>>,
     renderer: Renderer,
+    ui_testing: bool,
+    diagnostic_width: Option<usize>,
+    sort_files: bool,
+    notes_first: bool,
+    underline: Underline,
+    uniform_underlines: bool,
 }
 
 // SAFETY: `real_writer` always points to the `Writer` in `writer`.
@@ -41,11 +47,18 @@ unsafe impl Send for HumanEmitter {}
 
 impl Emitter for HumanEmitter {
     fn emit_diagnostic(&mut self, diagnostic: &Diagnostic) {
-        self.snippet(diagnostic, |this, snippet| {
-            writeln!(this.writer, "{}\n", this.renderer.render(snippet))?;
-            this.writer.flush()
-        })
-        .unwrap_or_else(|e| io_panic(e));
+        let rendered = render(
+            &self.renderer,
+            self.source_map.as_deref(),
+            diagnostic,
+            self.sort_files,
+            self.notes_first,
+            self.underline,
+            self.uniform_underlines,
+        );
+        writeln!(self.writer, "{rendered}\n")
+            .and_then(|()| self.writer.flush())
+            .unwrap_or_else(|e| io_panic(e));
     }
 
     fn source_map(&self) -> Option<&Arc<SourceMap>> {
@@ -76,6 +89,12 @@ impl HumanEmitter {
             writer: AutoStream::new(real_writer, color),
             source_map: None,
             renderer: DEFAULT_RENDERER,
+            ui_testing: false,
+            diagnostic_width: None,
+            sort_files: false,
+            notes_first: false,
+            underline: Underline::Caret,
+            uniform_underlines: false,
         }
     }
 
@@ -126,14 +145,105 @@ impl HumanEmitter {
 
     /// Sets whether to emit diagnostics in a way that is suitable for UI testing.
     pub fn ui_testing(mut self, yes: bool) -> Self {
-        self.renderer = self.renderer.anonymized_line_numbers(yes);
+        self.set_ui_testing(yes);
         self
     }
 
     /// Sets whether to emit diagnostics in a way that is suitable for UI testing.
     pub fn set_ui_testing(&mut self, yes: bool) {
-        self.renderer =
-            std::mem::replace(&mut self.renderer, DEFAULT_RENDERER).anonymized_line_numbers(yes);
+        self.ui_testing = yes;
+        self.update_renderer();
+    }
+
+    /// Sets the maximum width of the rendered diagnostics.
+    ///
+    /// Source lines that are longer than this are cut to a window around the annotated spans, with
+    /// the elided parts replaced by `...`. Defaults to 140 columns.
+    pub fn diagnostic_width(mut self, width: usize) -> Self {
+        self.set_diagnostic_width(width);
+        self
+    }
+
+    /// Sets the maximum width of the rendered diagnostics. See [`diagnostic_width`].
+    ///
+    /// [`diagnostic_width`]: Self::diagnostic_width
+    pub fn set_diagnostic_width(&mut self, width: usize) {
+        self.diagnostic_width = Some(width);
+        self.update_renderer();
+    }
+
+    /// Sets whether to render the files of the secondary spans of a diagnostic in source order.
+    ///
+    /// By default, the files are rendered in the order in which their first label or note was
+    /// added. The file of the primary span is always rendered first, and the spans in the same file
+    /// are always rendered top-to-bottom. Notes and helps without a span keep their insertion
+    /// order.
+    pub fn sort_files(mut self, yes: bool) -> Self {
+        self.set_sort_files(yes);
+        self
+    }
+
+    /// Sets whether to render the files of the secondary spans of a diagnostic in source order.
+    /// See [`sort_files`].
+    ///
+    /// [`sort_files`]: Self::sort_files
+    pub fn set_sort_files(&mut self, yes: bool) {
+        self.sort_files = yes;
+    }
+
+    /// Sets whether to render the notes and helps of a diagnostic before its source snippet.
+    ///
+    /// By default, they are rendered after the snippet, like rustc does. This only affects the
+    /// notes and helps without a span, and suggestions; the ones with a span are always rendered
+    /// as part of the snippet.
+    pub fn notes_first(mut self, yes: bool) -> Self {
+        self.set_notes_first(yes);
+        self
+    }
+
+    /// Sets whether to render the notes and helps of a diagnostic before its source snippet. See
+    /// [`notes_first`].
+    ///
+    /// [`notes_first`]: Self::notes_first
+    pub fn set_notes_first(&mut self, yes: bool) {
+        self.notes_first = yes;
+    }
+
+    /// Sets the characters used to underline the annotated spans of a diagnostic.
+    ///
+    /// Defaults to [`Underline::Caret`], like rustc.
+    pub fn underline(mut self, underline: Underline) -> Self {
+        self.set_underline(underline);
+        self
+    }
+
+    /// Sets the characters used to underline the annotated spans of a diagnostic. See
+    /// [`underline`].
+    ///
+    /// [`underline`]: Self::underline
+    pub fn set_underline(&mut self, underline: Underline) {
+        self.underline = underline;
+    }
+
+    /// Sets whether to underline secondary spans with the same character as the primary span.
+    ///
+    /// By default, primary spans are underlined with `^` and secondary spans with `-`, or the
+    /// equivalent characters of the [`underline`](Self::underline) style.
+    pub fn uniform_underlines(mut self, yes: bool) -> Self {
+        self.set_uniform_underlines(yes);
+        self
+    }
+
+    /// Sets whether to underline secondary spans with the same character as the primary span. See
+    /// [`uniform_underlines`].
+    ///
+    /// [`uniform_underlines`]: Self::uniform_underlines
+    pub fn set_uniform_underlines(&mut self, yes: bool) {
+        self.uniform_underlines = yes;
+    }
+
+    fn update_renderer(&mut self) {
+        self.renderer = make_renderer(true, self.ui_testing, self.diagnostic_width);
     }
 
     /// Downcasts the underlying writer to the specified type.
@@ -153,54 +263,199 @@ impl HumanEmitter {
             None
         }
     }
+}
 
-    /// Formats the given `diagnostic` into a [`Message`] suitable for use with the renderer.
-    fn snippet<R>(
-        &mut self,
-        diagnostic: &Diagnostic,
-        f: impl FnOnce(&mut Self, Message<'_>) -> R,
-    ) -> R {
-        // Current format (annotate-snippets 0.10.0) (comments in <...>):
-        /*
-        title.level[title.id]: title.label
-           --> snippets[0].origin
-            |
-         LL | snippets[0].source[ann[0].range] <ann = snippets[0].annotations>
-            | ^^^^^^^^^^^^^^^^ ann[0].level: ann[0].label <type is skipped for error, warning>
-         LL | snippets[0].source[ann[1].range]
-            | ---------------- ann[1].level: ann[1].label
-            |
-           ::: snippets[1].origin
-            |
-        etc...
-            |
-            = footer[0].level: footer[0].label <I believe the .id here is always ignored>
-            = footer[1].level: footer[1].label
-            = ...
-        */
-
-        let title = OwnedMessage::from_diagnostic(diagnostic);
-
-        let owned_snippets = self
-            .source_map
-            .as_deref()
-            .map(|sm| OwnedSnippet::collect(sm, diagnostic))
-            .unwrap_or_default();
-
-        // Dummy subdiagnostics go in the footer, while non-dummy ones go in the slices.
-        let owned_footers: Vec<_> = diagnostic
-            .children
-            .iter()
-            .filter(|sub| sub.span.is_dummy())
-            .map(OwnedMessage::from_subdiagnostic)
-            .collect();
+/// Options for rendering a single diagnostic with [`Diagnostic::render_to_string`].
+///
+/// These are the same as the options of [`HumanEmitter`] with the same names.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RenderOptions {
+    /// Whether to style the output with ANSI escape codes.
+    pub color: bool,
+    /// See [`HumanEmitter::ui_testing`].
+    pub ui_testing: bool,
+    /// See [`HumanEmitter::diagnostic_width`].
+    pub diagnostic_width: Option<usize>,
+    /// See [`HumanEmitter::sort_files`].
+    pub sort_files: bool,
+    /// See [`HumanEmitter::notes_first`].
+    pub notes_first: bool,
+    /// See [`HumanEmitter::underline`].
+    pub underline: Underline,
+    /// See [`HumanEmitter::uniform_underlines`].
+    pub uniform_underlines: bool,
+}
+
+/// The characters used to underline the annotated spans of a diagnostic.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Underline {
+    /// `^` for the primary span and `-` for secondary spans, like rustc.
+    #[default]
+    Caret,
+    /// `~` for the primary span and `-` for secondary spans.
+    Tilde,
+    /// The box-drawing characters `━` for the primary span and `─` for secondary spans.
+    Unicode,
+}
+
+impl Underline {
+    /// Returns the characters used for the primary and secondary spans.
+    pub const fn chars(self) -> (char, char) {
+        match self {
+            Self::Caret => ('^', '-'),
+            Self::Tilde => ('~', '-'),
+            Self::Unicode => ('━', '─'),
+        }
+    }
+}
+
+/// Renders the given `diagnostic` like [`HumanEmitter`]. See [`Diagnostic::render_to_string`].
+pub(crate) fn render_to_string(
+    diagnostic: &Diagnostic,
+    source_map: &SourceMap,
+    options: &RenderOptions,
+) -> String {
+    let renderer = make_renderer(options.color, options.ui_testing, options.diagnostic_width);
+    render(
+        &renderer,
+        Some(source_map),
+        diagnostic,
+        options.sort_files,
+        options.notes_first,
+        options.underline,
+        options.uniform_underlines,
+    )
+}
+
+fn make_renderer(color: bool, ui_testing: bool, diagnostic_width: Option<usize>) -> Renderer {
+    let renderer = if color { DEFAULT_RENDERER } else { Renderer::plain() };
+    let mut renderer = renderer.anonymized_line_numbers(ui_testing);
+    if let Some(width) = diagnostic_width {
+        renderer = renderer.term_width(width);
+    }
+    renderer
+}
+
+/// Renders the given `diagnostic` with the renderer.
+fn render(
+    renderer: &Renderer,
+    source_map: Option<&SourceMap>,
+    diagnostic: &Diagnostic,
+    sort_files: bool,
+    notes_first: bool,
+    underline: Underline,
+    uniform_underlines: bool,
+) -> String {
+    // Current format (annotate-snippets 0.10.0) (comments in <...>):
+    /*
+    title.level[title.id]: title.label
+       --> snippets[0].origin
+        |
+     LL | snippets[0].source[ann[0].range] <ann = snippets[0].annotations>
+        | ^^^^^^^^^^^^^^^^ ann[0].level: ann[0].label <type is skipped for error, warning>
+     LL | snippets[0].source[ann[1].range]
+        | ---------------- ann[1].level: ann[1].label
+        |
+       ::: snippets[1].origin
+        |
+    etc...
+        |
+        = footer[0].level: footer[0].label <I believe the .id here is always ignored>
+        = footer[1].level: footer[1].label
+        = ...
+    */
+
+    let title = OwnedMessage::from_diagnostic(diagnostic);
+
+    let owned_snippets =
+        source_map.map(|sm| OwnedSnippet::collect(sm, diagnostic, sort_files)).unwrap_or_default();
+
+    // Dummy subdiagnostics go in the footer, while non-dummy ones go in the slices.
+    // Suggestions are always in the footer.
+    let owned_footers: Vec<_> = diagnostic
+        .children
+        .iter()
+        .filter(|sub| sub.span.is_dummy())
+        .map(OwnedMessage::from_subdiagnostic)
+        .chain(diagnostic.suggestions.iter().map(OwnedMessage::from_suggestion))
+        .collect();
+
+    let snippet = title
+        .as_ref()
+        .snippets(owned_snippets.iter().map(OwnedSnippet::as_ref))
+        .footers(owned_footers.iter().map(OwnedMessage::as_ref));
+    let mut rendered = renderer.render(snippet).to_string();
+    if notes_first && !owned_snippets.is_empty() && !owned_footers.is_empty() {
+        let footers = title.as_ref().footers(owned_footers.iter().map(OwnedMessage::as_ref));
+        let footers = renderer.render(footers).to_string();
+        rendered = move_footers_first(&rendered, footers.lines().count() - 1);
+    }
+    if underline != Underline::Caret || uniform_underlines {
+        rendered = replace_underlines(&rendered, underline, uniform_underlines);
+    }
+    rendered
+}
 
-        let snippet = title
-            .as_ref()
-            .snippets(owned_snippets.iter().map(OwnedSnippet::as_ref))
-            .footers(owned_footers.iter().map(OwnedMessage::as_ref));
-        f(self, snippet)
+/// Replaces the `^` and `-` underlines of the rendered diagnostic with the given style.
+///
+/// annotate-snippets does not support custom underline characters, so they are replaced in the
+/// annotation lines, the ones with an empty line number gutter, up to the first label character.
+fn replace_underlines(rendered: &str, underline: Underline, uniform: bool) -> String {
+    let (primary, secondary) = underline.chars();
+    let secondary = if uniform { primary } else { secondary };
+    let mut out = String::with_capacity(rendered.len());
+    for (i, line) in rendered.lines().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        let mut chars = line.chars().peekable();
+        let mut in_gutter = true;
+        let mut seen_space = false;
+        while let Some(c) = chars.next() {
+            // Copy ANSI escape sequences as is.
+            if c == '\x1b' {
+                out.push(c);
+                for c in chars.by_ref() {
+                    out.push(c);
+                    if c.is_ascii_alphabetic() {
+                        break;
+                    }
+                }
+                continue;
+            }
+            if in_gutter {
+                out.push(c);
+                match c {
+                    ' ' => seen_space = true,
+                    '|' if seen_space => in_gutter = false,
+                    _ => break,
+                }
+                continue;
+            }
+            match c {
+                '^' => out.push(primary),
+                '-' => out.push(secondary),
+                ' ' | '|' | '_' | '/' | '\\' => out.push(c),
+                _ => {
+                    out.push(c);
+                    break;
+                }
+            }
+        }
+        out.extend(chars);
+    }
+    if rendered.ends_with('\n') {
+        out.push('\n');
     }
+    out
+}
+
+/// Moves the last `n` lines of the rendered diagnostic, its footers, right after the title line.
+fn move_footers_first(rendered: &str, n: usize) -> String {
+    let lines = rendered.lines().collect::<Vec<_>>();
+    let (body, footers) = lines.split_at(lines.len().saturating_sub(n));
+    let Some((title, snippets)) = body.split_first() else { return rendered.to_string() };
+    std::iter::once(title).chain(footers).chain(snippets).copied().collect::<Vec<_>>().join("\n")
 }
 
 /// Diagnostic emitter that emits diagnostics in human-readable format to a local buffer.
@@ -246,6 +501,41 @@ impl HumanBufferEmitter {
         self
     }
 
+    /// Sets the maximum width of the rendered diagnostics. See
+    /// [`HumanEmitter::diagnostic_width`].
+    pub fn diagnostic_width(mut self, width: usize) -> Self {
+        self.inner = self.inner.diagnostic_width(width);
+        self
+    }
+
+    /// Sets whether to render the files of the secondary spans of a diagnostic in source order.
+    /// See [`HumanEmitter::sort_files`].
+    pub fn sort_files(mut self, yes: bool) -> Self {
+        self.inner = self.inner.sort_files(yes);
+        self
+    }
+
+    /// Sets whether to render the notes and helps of a diagnostic before its source snippet. See
+    /// [`HumanEmitter::notes_first`].
+    pub fn notes_first(mut self, yes: bool) -> Self {
+        self.inner = self.inner.notes_first(yes);
+        self
+    }
+
+    /// Sets the characters used to underline the annotated spans of a diagnostic. See
+    /// [`HumanEmitter::underline`].
+    pub fn underline(mut self, underline: Underline) -> Self {
+        self.inner = self.inner.underline(underline);
+        self
+    }
+
+    /// Sets whether to underline secondary spans with the same character as the primary span. See
+    /// [`HumanEmitter::uniform_underlines`].
+    pub fn uniform_underlines(mut self, yes: bool) -> Self {
+        self.inner = self.inner.uniform_underlines(yes);
+        self
+    }
+
     /// Returns a reference to the underlying human emitter.
     pub fn inner(&self) -> &HumanEmitter {
         &self.inner
@@ -289,6 +579,10 @@ impl OwnedMessage {
         Self { id: None, label: sub.label().into_owned(), level: to_as_level(sub.level) }
     }
 
+    fn from_suggestion(sugg: &CodeSuggestion) -> Self {
+        Self { id: None, label: sugg.label(), level: to_as_level(Level::Help) }
+    }
+
     fn as_ref(&self) -> Message<'_> {
         let mut msg = self.level.title(&self.label);
         if let Some(id) = &self.id {
@@ -321,7 +615,7 @@ struct OwnedSnippet {
 }
 
 impl OwnedSnippet {
-    fn collect(sm: &SourceMap, diagnostic: &Diagnostic) -> Vec<Self> {
+    fn collect(sm: &SourceMap, diagnostic: &Diagnostic, sort: bool) -> Vec<Self> {
         // Collect main diagnostic.
         let mut files = Self::collect_files(sm, &diagnostic.span);
         files.iter_mut().for_each(|file| file.set_level(diagnostic.level));
@@ -349,6 +643,11 @@ impl OwnedSnippet {
             }
         }
 
+        // The main file is always first.
+        if sort && files.len() > 2 {
+            files[1..].sort_by_key(|file| file.file.start_pos);
+        }
+
         files
             .iter()
             .map(|file| file_to_snippet(sm, &file.file, &file.lines, diagnostic.level))
@@ -458,3 +757,131 @@ fn to_as_level(level: Level) -> ASLevel {
 fn char_to_byte_pos(s: &str, char_pos: usize) -> usize {
     s.chars().take(char_pos).map(char::len_utf8).sum()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::test_utils::{pos, span, TestDcx};
+
+    #[test]
+    fn sort_files() {
+        fn emit(sort: bool) -> String {
+            let t = TestDcx::human(|emitter| emitter.sort_files(sort));
+            let spans =
+                ["a.sol", "b.sol", "c.sol"].map(|name| span(&t.file(name, "contract A {}"), 0, 8));
+            t.dcx
+                .err("test")
+                .span(spans[0])
+                .span_note(spans[2], "second")
+                .span_note(spans[1], "first")
+                .note("unspanned second")
+                .help("unspanned first")
+                .emit();
+            t.emitted()
+        }
+
+        let err = emit(false);
+        assert!(pos(&err, "c.sol") < pos(&err, "b.sol"), "{err}");
+
+        let err = emit(true);
+        assert!(pos(&err, "a.sol") < pos(&err, "b.sol"), "{err}");
+        assert!(pos(&err, "b.sol") < pos(&err, "c.sol"), "{err}");
+        assert!(pos(&err, "note: first") < pos(&err, "note: second"), "{err}");
+        // Notes and helps without a span keep their insertion order.
+        assert!(pos(&err, "unspanned second") < pos(&err, "unspanned first"), "{err}");
+    }
+
+    #[test]
+    fn notes_first() {
+        fn emit(notes_first: bool) -> String {
+            let t = TestDcx::human(|emitter| emitter.notes_first(notes_first));
+            let span = span(&t.file("a.sol", "contract A {}"), 0, 8);
+            t.dcx.err("test").span(span).note("a note").help("a help").emit();
+            t.emitted()
+        }
+
+        let err = emit(false);
+        assert!(pos(&err, "error: test") < pos(&err, "a.sol"), "{err}");
+        assert!(pos(&err, "a.sol") < pos(&err, "note: a note"), "{err}");
+        assert!(pos(&err, "note: a note") < pos(&err, "help: a help"), "{err}");
+
+        let err = emit(true);
+        assert!(pos(&err, "error: test") < pos(&err, "note: a note"), "{err}");
+        assert!(pos(&err, "note: a note") < pos(&err, "help: a help"), "{err}");
+        assert!(pos(&err, "help: a help") < pos(&err, "a.sol"), "{err}");
+        assert!(pos(&err, "a.sol") < pos(&err, "contract A"), "{err}");
+    }
+
+    #[test]
+    fn underline() {
+        fn emit(underline: Underline, uniform: bool) -> String {
+            let t =
+                TestDcx::human(|emitter| emitter.underline(underline).uniform_underlines(uniform));
+            let file = t.file("a.sol", "contract A-B {}");
+            t.dcx.err("test").span(span(&file, 0, 8)).span_note(span(&file, 9, 3), "a-note").emit();
+            t.emitted()
+        }
+
+        let underlines = |err: &str| {
+            let lines = err.lines().filter(|line| line.trim_start().starts_with('|'));
+            let lines = lines.map(|line| line.trim_start_matches([' ', '|']));
+            lines.filter(|line| !line.is_empty()).collect::<Vec<_>>().join("\n")
+        };
+
+        let err = emit(Underline::Caret, false);
+        assert_eq!(underlines(&err), "^^^^^^^^ --- note: a-note", "{err}");
+        assert!(err.contains("contract A-B {}"), "{err}");
+
+        let err = emit(Underline::Tilde, false);
+        assert_eq!(underlines(&err), "~~~~~~~~ --- note: a-note", "{err}");
+        assert!(err.contains("contract A-B {}"), "{err}");
+
+        let err = emit(Underline::Unicode, false);
+        assert_eq!(underlines(&err), "━━━━━━━━ ─── note: a-note", "{err}");
+
+        let err = emit(Underline::Caret, true);
+        assert_eq!(underlines(&err), "^^^^^^^^ ^^^ note: a-note", "{err}");
+    }
+
+    #[test]
+    fn diagnostic_width() {
+        let t = TestDcx::human(|emitter| emitter.diagnostic_width(80));
+        let src = format!("{}marker{}", "a".repeat(500), "b".repeat(500));
+        let file = t.file("long.sol", &src);
+        t.dcx.err("test").span(span(&file, 500, 6)).emit();
+        let err = t.emitted();
+        let code = err.lines().find(|line| line.contains("marker")).expect(&err);
+        let carets = err.lines().find(|line| line.contains('^')).expect(&err);
+        assert!(code.len() <= 80, "{err}");
+        assert_eq!(code.matches("...").count(), 2, "{err}");
+        assert_eq!(code.find("marker"), carets.find('^'), "{err}");
+        assert_eq!(carets.matches('^').count(), 6, "{err}");
+    }
+
+    #[test]
+    fn render_to_string() {
+        let sm = SourceMap::default();
+        let file = sm.new_dummy_source_file("a.sol".into(), "contract A {}".into()).unwrap();
+        let mut diag = Diagnostic::new(Level::Error, "unexpected token");
+        diag.span(span(&file, 9, 1)).help("remove it");
+
+        let rendered = diag.render_to_string(&sm, &RenderOptions::default());
+        let expected = "\
+error: unexpected token
+ --> a.sol:1:10
+  |
+1 | contract A {}
+  |          ^
+  |
+  = help: remove it";
+        assert_eq!(rendered, expected);
+
+        let options = RenderOptions { notes_first: true, ..Default::default() };
+        let rendered = diag.render_to_string(&sm, &options);
+        let expected = "error: unexpected token\n  = help: remove it\n --> a.sol:1:10\n";
+        assert!(rendered.starts_with(expected), "{rendered}");
+
+        let options = RenderOptions { color: true, ..Default::default() };
+        assert!(diag.render_to_string(&sm, &options).contains('\x1b'));
+    }
+}
@@ -1,6 +1,6 @@
 use super::{human::HumanBufferEmitter, io_panic, Emitter};
 use crate::{
-    diagnostics::{Level, MultiSpan, SpanLabel},
+    diagnostics::{CodeSuggestion, Level, MultiSpan, SpanLabel},
     source_map::{LineInfo, SourceFile},
     SourceMap, Span,
 };
@@ -75,7 +75,12 @@ impl JsonEmitter {
             code: diagnostic.id().map(|code| DiagnosticCode { code, explanation: None }),
             level: diagnostic.level.to_str(),
             spans: self.spans(&diagnostic.span),
-            children: diagnostic.children.iter().map(|sub| self.sub_diagnostic(sub)).collect(),
+            children: diagnostic
+                .children
+                .iter()
+                .map(|sub| self.sub_diagnostic(sub))
+                .chain(diagnostic.suggestions.iter().map(|sugg| self.suggestion(sugg)))
+                .collect(),
             rendered: Some(self.emit_diagnostic_to_buffer(diagnostic)),
         }
     }
@@ -91,6 +96,23 @@ impl JsonEmitter {
         }
     }
 
+    fn suggestion(&self, suggestion: &CodeSuggestion) -> Diagnostic {
+        let label = SpanLabel { span: suggestion.span, is_primary: true, label: None };
+        let span = DiagnosticSpan {
+            suggested_replacement: Some(suggestion.replacement.clone()),
+            suggestion_applicability: Some(suggestion.applicability.to_str()),
+            ..self.span(&label)
+        };
+        Diagnostic {
+            message: suggestion.msg.as_str().into(),
+            code: None,
+            level: Level::Help.to_str(),
+            spans: vec![span],
+            children: vec![],
+            rendered: None,
+        }
+    }
+
     fn spans(&self, msp: &MultiSpan) -> Vec<DiagnosticSpan> {
         msp.span_labels().iter().map(|label| self.span(label)).collect()
     }
@@ -111,6 +133,8 @@ impl JsonEmitter {
             is_primary: label.is_primary,
             text: self.span_lines(span),
             label: label.label.as_ref().map(|msg| msg.as_str().into()),
+            suggested_replacement: None,
+            suggestion_applicability: None,
         }
     }
 
@@ -148,6 +172,9 @@ impl JsonEmitter {
                 .children
                 .iter()
                 .map(|sub| self.solc_span(&sub.span, &file, Some(sub.label().into_owned())))
+                .chain(diagnostic.suggestions.iter().map(|sugg| {
+                    self.solc_span(&MultiSpan::from_span(sugg.span), &file, Some(sugg.label()))
+                }))
                 .collect(),
             r#type: match severity {
                 Severity::Error => match diagnostic.level {
@@ -250,9 +277,11 @@ struct DiagnosticSpan {
     text: Vec<DiagnosticSpanLine>,
     /// Label that should be placed at this location (if any)
     label: Option<String>,
-    // /// If we are suggesting a replacement, this will contain text
-    // /// that should be sliced in atop this span.
-    // suggested_replacement: Option<String>,
+    /// If we are suggesting a replacement, this will contain text
+    /// that should be sliced in atop this span.
+    suggested_replacement: Option<String>,
+    /// If the suggestion is approximate.
+    suggestion_applicability: Option<&'static str>,
 }
 
 #[derive(Serialize)]
@@ -316,3 +345,39 @@ fn to_severity(level: Level) -> Severity {
         Level::Allow => Severity::Info,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::{
+        test_utils::{Buf, TestDcx},
+        Applicability,
+    };
+
+    #[test]
+    fn suggestion() {
+        let buf = Buf::default();
+        let t = TestDcx::new(|sm| {
+            Box::new(JsonEmitter::new(Box::new(buf.clone()), sm).rustc_like(true))
+        });
+        let file = t.file("a.sol", "uint(x)");
+        let span = Span::new(file.start_pos, file.end_position());
+        t.dcx
+            .warn("test")
+            .span(span)
+            .span_suggestion(span, "remove it", "x", Applicability::MachineApplicable)
+            .emit();
+
+        let out = buf.contents();
+        let json: serde_json::Value = serde_json::from_str(&out).unwrap();
+        let child = &json["children"][0];
+        assert_eq!(child["level"], "help", "{out}");
+        assert_eq!(child["message"], "remove it", "{out}");
+        assert_eq!(child["spans"][0]["byte_start"], 0, "{out}");
+        assert_eq!(child["spans"][0]["byte_end"], 7, "{out}");
+        assert_eq!(child["spans"][0]["suggested_replacement"], "x", "{out}");
+        assert_eq!(child["spans"][0]["suggestion_applicability"], "MachineApplicable", "{out}");
+        let rendered = json["rendered"].as_str().unwrap();
+        assert!(rendered.contains("help: remove it: `x`"), "{out}");
+    }
+}
@@ -2,8 +2,12 @@ use super::{DiagCtxt, Diagnostic, Level};
 use crate::SourceMap;
 use std::{any::Any, sync::Arc};
 
+mod github;
+pub use github::GithubEmitter;
+
 mod human;
-pub use human::{HumanBufferEmitter, HumanEmitter};
+pub(crate) use human::render_to_string;
+pub use human::{HumanBufferEmitter, HumanEmitter, RenderOptions, Underline};
 
 #[cfg(feature = "json")]
 mod json;
@@ -31,6 +35,13 @@ pub trait Emitter: Any {
     fn supports_color(&self) -> bool {
         false
     }
+
+    /// Writes out the diagnostics buffered by the emitter, if any.
+    ///
+    /// Called with [`DiagCtxt::flush`], which the driver does at the end of compilation. Emitters
+    /// that write each diagnostic as it is emitted don't need to implement this.
+    #[inline]
+    fn flush(&mut self) {}
 }
 
 impl DynEmitter {
@@ -79,6 +90,10 @@ impl Emitter for SilentEmitter {
         }
         let _ = self.fatal_dcx.emit_diagnostic(diagnostic);
     }
+
+    fn flush(&mut self) {
+        self.fatal_dcx.flush();
+    }
 }
 
 /// Diagnostic emitter that only stores emitted diagnostics.
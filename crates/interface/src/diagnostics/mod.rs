@@ -2,7 +2,7 @@
 //!
 //! Modified from [`rustc_errors`](https://github.com/rust-lang/rust/blob/520e30be83b4ed57b609d33166c988d1512bf4f3/compiler/rustc_errors/src/diagnostic.rs).
 
-use crate::Span;
+use crate::{SourceMap, Span};
 use anstyle::{AnsiColor, Color};
 use std::{borrow::Cow, fmt, panic::Location};
 
@@ -16,12 +16,16 @@ mod emitter;
 #[cfg(feature = "json")]
 pub use emitter::JsonEmitter;
 pub use emitter::{
-    DynEmitter, Emitter, HumanBufferEmitter, HumanEmitter, LocalEmitter, SilentEmitter,
+    DynEmitter, Emitter, GithubEmitter, HumanBufferEmitter, HumanEmitter, LocalEmitter,
+    RenderOptions, SilentEmitter, Underline,
 };
 
 mod message;
 pub use message::{DiagnosticMessage, MultiSpan, SpanLabel};
 
+#[cfg(test)]
+pub(crate) mod test_utils;
+
 /// Represents all the diagnostics emitted up to a certain point.
 ///
 /// Returned by [`DiagCtxt::emitted_diagnostics`].
@@ -100,6 +104,23 @@ impl DiagnosticId {
     }
 }
 
+impl std::str::FromStr for DiagnosticId {
+    type Err = String;
+
+    /// Parses an error code from exactly 4 decimal digits, like `1234`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err =
+            || format!("invalid error code `{s}`: expected 4 decimal digits in range 0001-9999");
+        if s.len() != 4 || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(err());
+        }
+        match s.parse() {
+            Ok(id @ 1..=9999) => Ok(Self { id }),
+            _ => Err(err()),
+        }
+    }
+}
+
 /// Used for creating an error code. The input must be exactly 4 decimal digits.
 ///
 /// # Examples
@@ -298,6 +319,84 @@ impl SubDiagnostic {
     }
 }
 
+/// A reusable set of sub-diagnostics, such as notes and labels, that can be added to a
+/// diagnostic with [`Diagnostic::subdiagnostic`].
+pub trait Subdiagnostic {
+    /// Adds the sub-diagnostics to the given diagnostic.
+    fn add_to_diag(self, diag: &mut Diagnostic);
+}
+
+/// A note pointing to a declaration: "{kind} declared here".
+#[derive(Clone, Copy, Debug)]
+pub struct DeclaredHere {
+    /// The kind of the declaration, e.g. `function` or `parameter`.
+    pub kind: &'static str,
+    /// The span of the declaration.
+    pub span: Span,
+}
+
+impl DeclaredHere {
+    /// Creates a new "declared here" note.
+    pub fn new(kind: &'static str, span: Span) -> Self {
+        Self { kind, span }
+    }
+}
+
+impl Subdiagnostic for DeclaredHere {
+    fn add_to_diag(self, diag: &mut Diagnostic) {
+        diag.span_note(self.span, format!("{} declared here", self.kind));
+    }
+}
+
+/// Indicates the confidence in the correctness of a suggestion.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Applicability {
+    /// The suggestion is definitely what the user intended, and can be applied automatically.
+    MachineApplicable,
+    /// The suggestion may be what the user intended, but it is uncertain.
+    MaybeIncorrect,
+    /// The suggestion contains placeholders like `(...)` that must be filled in by the user.
+    HasPlaceholders,
+    /// The applicability of the suggestion is unknown.
+    Unspecified,
+}
+
+impl Applicability {
+    /// Returns the string representation of the applicability.
+    pub fn to_str(self) -> &'static str {
+        match self {
+            Self::MachineApplicable => "MachineApplicable",
+            Self::MaybeIncorrect => "MaybeIncorrect",
+            Self::HasPlaceholders => "HasPlaceholders",
+            Self::Unspecified => "Unspecified",
+        }
+    }
+}
+
+/// A suggestion to replace the source code at a span with some other code.
+#[derive(Clone, Debug, PartialEq, Hash)]
+pub struct CodeSuggestion {
+    /// The span of the code to replace.
+    pub span: Span,
+    /// The message describing the suggestion.
+    pub msg: DiagnosticMessage,
+    /// The code to replace the span with.
+    pub replacement: String,
+    /// The confidence in the correctness of the suggestion.
+    pub applicability: Applicability,
+}
+
+impl CodeSuggestion {
+    /// Formats the suggestion into a single label, including the replacement if it is short.
+    pub fn label(&self) -> String {
+        if self.replacement.is_empty() || self.replacement.contains('\n') {
+            self.msg.as_str().to_string()
+        } else {
+            format!("{}: `{}`", self.msg.as_str(), self.replacement)
+        }
+    }
+}
+
 /// A compiler diagnostic.
 #[must_use]
 #[derive(Clone, Debug)]
@@ -307,6 +406,7 @@ pub struct Diagnostic {
     pub messages: Vec<(DiagnosticMessage, Style)>,
     pub span: MultiSpan,
     pub children: Vec<SubDiagnostic>,
+    pub suggestions: Vec<CodeSuggestion>,
     pub code: Option<DiagnosticId>,
 
     pub created_at: &'static Location<'static>,
@@ -340,7 +440,7 @@ impl Diagnostic {
             code: None,
             span: MultiSpan::new(),
             children: vec![],
-            // suggestions: Ok(vec![]),
+            suggestions: vec![],
             // args: Default::default(),
             // sort_span: DUMMY_SP,
             // is_lint: false,
@@ -374,6 +474,15 @@ impl Diagnostic {
         self.code.as_ref().map(|code| code.as_string())
     }
 
+    /// Renders this diagnostic in the human-readable format of [`HumanEmitter`], without emitting
+    /// it.
+    ///
+    /// The source snippets are looked up in the given source map. Unlike the output of the
+    /// emitter, the returned string does not end with a newline.
+    pub fn render_to_string(&self, source_map: &SourceMap, options: &RenderOptions) -> String {
+        emitter::render_to_string(self, source_map, options)
+    }
+
     /// Fields used for `PartialEq` and `Hash` implementations.
     fn keys(&self) -> impl PartialEq + std::hash::Hash + '_ {
         (
@@ -382,7 +491,7 @@ impl Diagnostic {
             // self.args().collect(),
             &self.code,
             &self.span,
-            // &self.suggestions,
+            &self.suggestions,
             // (if self.is_lint { None } else { Some(&self.children) }),
             &self.children,
         )
@@ -501,8 +610,8 @@ impl Diagnostic {
         self.sub(Level::Help, msg, MultiSpan::new())
     }
 
-    /// Prints the span with a help above it.
-    /// This is like [`Diagnostic::help()`], but it gets its own span.
+    /// Add a help message attached to this diagnostic.
+    /// This is like [`Diagnostic::help()`], but it gets emitted only once.
     pub fn help_once(&mut self, msg: impl Into<DiagnosticMessage>) -> &mut Self {
         self.sub(Level::OnceHelp, msg, MultiSpan::new())
     }
@@ -525,6 +634,42 @@ impl Diagnostic {
         self.sub(Level::Help, msg, span)
     }
 
+    /// Prints the span with some help above it.
+    /// This is like [`Diagnostic::help_once()`], but it gets its own span.
+    pub fn span_help_once(
+        &mut self,
+        span: impl Into<MultiSpan>,
+        msg: impl Into<DiagnosticMessage>,
+    ) -> &mut Self {
+        self.sub(Level::OnceHelp, msg, span)
+    }
+
+    /// Suggests replacing the code at `span` with `replacement`.
+    ///
+    /// The suggestion is rendered as a help message in human-readable output, and includes the
+    /// span and the replacement in JSON output.
+    pub fn span_suggestion(
+        &mut self,
+        span: Span,
+        msg: impl Into<DiagnosticMessage>,
+        replacement: impl Into<String>,
+        applicability: Applicability,
+    ) -> &mut Self {
+        self.suggestions.push(CodeSuggestion {
+            span,
+            msg: msg.into(),
+            replacement: replacement.into(),
+            applicability,
+        });
+        self
+    }
+
+    /// Adds the given sub-diagnostics.
+    pub fn subdiagnostic(&mut self, subdiagnostic: impl Subdiagnostic) -> &mut Self {
+        subdiagnostic.add_to_diag(self);
+        self
+    }
+
     fn sub(
         &mut self,
         level: Level,
@@ -559,3 +704,54 @@ fn flatten_messages(messages: &[(DiagnosticMessage, Style)]) -> Cow<'_, str> {
         messages => messages.iter().map(|(msg, _)| msg.as_str()).collect(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_utils::{span, TestDcx};
+
+    #[test]
+    fn subdiagnostic() {
+        struct Shadowed {
+            previous: Span,
+        }
+
+        impl Subdiagnostic for Shadowed {
+            fn add_to_diag(self, diag: &mut Diagnostic) {
+                diag.subdiagnostic(DeclaredHere::new("previous variable", self.previous))
+                    .help("rename one of the variables");
+            }
+        }
+
+        let t = TestDcx::human(std::convert::identity);
+        let file = t.file("a.sol", "uint x; uint x;");
+        let previous = span(&file, 0, 6);
+        t.dcx.err("shadowed").span(span(&file, 8, 6)).subdiagnostic(Shadowed { previous }).emit();
+        let err = t.emitted();
+        assert!(err.contains("note: previous variable declared here"), "{err}");
+        assert!(err.contains("help: rename one of the variables"), "{err}");
+    }
+
+    #[test]
+    fn span_notes() {
+        let t = TestDcx::human(std::convert::identity);
+        let file = t.file("a.sol", "contract A {}\ncontract A {}\n");
+        for i in 0..2 {
+            t.dcx
+                .err(format!("duplicate definition {i}"))
+                .span(span(&file, 14, 13))
+                .span_note(span(&file, 0, 13), "the conflicting definition is here")
+                .span_help(span(&file, 0, 13), "rename it")
+                .span_note_once(span(&file, 0, 13), "noted once")
+                .span_help_once(span(&file, 0, 13), "helped once")
+                .emit();
+        }
+        let err = t.emitted();
+        assert!(err.contains("1 | contract A {}"), "{err}");
+        assert!(err.contains("2 | contract A {}"), "{err}");
+        assert_eq!(err.matches("note: the conflicting definition is here").count(), 2, "{err}");
+        assert_eq!(err.matches("help: rename it").count(), 2, "{err}");
+        assert_eq!(err.matches("note: noted once").count(), 1, "{err}");
+        assert_eq!(err.matches("help: helped once").count(), 1, "{err}");
+    }
+}
@@ -0,0 +1,79 @@
+//! Helpers for the tests of the diagnostics context and emitters.
+
+use super::{DiagCtxt, DiagCtxtFlags, DynEmitter, HumanBufferEmitter};
+use crate::{source_map::SourceFile, BytePos, ColorChoice, SourceMap, Span};
+use std::{
+    io::{self, Write},
+    sync::{Arc, Mutex},
+};
+
+/// A diagnostics context whose emitter shares its source map with the test.
+pub(crate) struct TestDcx {
+    pub(crate) sm: Arc<SourceMap>,
+    pub(crate) dcx: DiagCtxt,
+}
+
+impl TestDcx {
+    /// Creates a context with a human buffer emitter, configured with `f`.
+    pub(crate) fn human(f: impl FnOnce(HumanBufferEmitter) -> HumanBufferEmitter) -> Self {
+        Self::new(|sm| {
+            Box::new(f(HumanBufferEmitter::new(ColorChoice::Never).source_map(Some(sm))))
+        })
+    }
+
+    /// Creates a context with the emitter returned by `f` for the source map.
+    pub(crate) fn new(f: impl FnOnce(Arc<SourceMap>) -> Box<DynEmitter>) -> Self {
+        let sm = Arc::<SourceMap>::default();
+        let dcx = DiagCtxt::new(f(sm.clone()));
+        Self { sm, dcx }
+    }
+
+    /// Sets the flags of the context.
+    pub(crate) fn flags(self, f: impl FnOnce(&mut DiagCtxtFlags)) -> Self {
+        Self { dcx: self.dcx.set_flags(f), ..self }
+    }
+
+    /// Adds a source file with the given name and contents to the source map.
+    pub(crate) fn file(&self, name: &str, src: &str) -> Arc<SourceFile> {
+        self.sm.new_dummy_source_file(name.into(), src.into()).unwrap()
+    }
+
+    /// Returns the diagnostics rendered to the buffer of a human buffer emitter.
+    ///
+    /// Panics if no error has been emitted.
+    pub(crate) fn emitted(&self) -> String {
+        self.dcx.emitted_diagnostics().unwrap().unwrap_err().to_string()
+    }
+}
+
+/// Returns the span of `len` bytes starting at byte `lo` of the given file.
+pub(crate) fn span(file: &SourceFile, lo: u32, len: u32) -> Span {
+    let lo = file.start_pos + BytePos(lo);
+    Span::new(lo, lo + BytePos(len))
+}
+
+/// Returns the position of `s` in the rendered diagnostics `out`.
+pub(crate) fn pos(out: &str, s: &str) -> usize {
+    out.find(s).unwrap_or_else(|| panic!("{s:?}: {out}"))
+}
+
+/// A writer to a buffer that can still be read after it was given to an emitter.
+#[derive(Clone, Default)]
+pub(crate) struct Buf(Arc<Mutex<Vec<u8>>>);
+
+impl Buf {
+    /// Returns the contents written so far.
+    pub(crate) fn contents(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+impl Write for Buf {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
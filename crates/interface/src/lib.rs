@@ -15,6 +15,8 @@ use diagnostics::ErrorGuaranteed;
 mod globals;
 pub use globals::SessionGlobals;
 
+pub mod lint;
+
 mod pos;
 pub use pos::{BytePos, CharPos, Pos, RelativeBytePos};
 
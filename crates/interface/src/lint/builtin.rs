@@ -0,0 +1,93 @@
+//! The built-in lints.
+//!
+//! Unless noted otherwise, they are allowed by default and must be enabled explicitly.
+
+use super::LintInfo;
+use solar_config::LintLevel;
+
+macro_rules! declare_lints {
+    ($($(#[$attr:meta])* $id:ident = $name:literal, $level:ident, $message:literal;)*) => {
+        $(
+            $(#[$attr])*
+            pub static $id: LintInfo = LintInfo {
+                name: $name,
+                default_level: LintLevel::$level,
+                message: $message,
+            };
+        )*
+
+        /// All the built-in lints.
+        pub static BUILTIN_LINTS: &[&LintInfo] = &[$(&$id),*];
+    };
+}
+
+declare_lints! {
+    /// Named imports that are never referenced in the importing file.
+    UNUSED_IMPORTS = "unused-imports", Allow,
+        "import is never used";
+    /// Private functions that are never referenced in their contract.
+    UNUSED_FUNCTIONS = "unused-functions", Allow,
+        "private function is never used";
+    /// Local variables and function parameters that are never referenced in their function.
+    UNUSED_VARIABLES = "unused-variables", Allow,
+        "variable is never used";
+    /// Custom errors that are never referenced.
+    UNUSED_ERRORS = "unused-errors", Allow,
+        "error is never used";
+    /// Events that are never referenced.
+    UNUSED_EVENTS = "unused-events", Allow,
+        "event is never used";
+    /// `payable` functions that never use `msg.value`.
+    UNUSED_PAYABLE = "unused-payable", Allow,
+        "`payable` function never uses `msg.value`";
+    /// Reads of `msg.data` and `msg.sig` in internal and private functions.
+    MSG_DATA_IN_INTERNAL = "msg-data-in-internal", Allow,
+        "`msg.data` or `msg.sig` read in an internal function";
+    /// Loops whose condition is never updated in their body.
+    UNCHANGED_LOOP_CONDITION = "unchanged-loop-condition", Allow,
+        "loop condition is never updated in the loop body";
+    /// Conversions of an expression to the type it already has.
+    REDUNDANT_CONVERSIONS = "redundant-conversions", Allow,
+        "redundant conversion to the same type";
+    /// Function specifiers that are not in the order of
+    /// [`Session::specifier_order`](crate::Session::specifier_order).
+    SPECIFIER_ORDER = "specifier-order", Allow,
+        "function specifiers are not in the canonical order";
+    /// Functions whose cyclomatic complexity exceeds
+    /// [`Session::max_cyclomatic_complexity`](crate::Session::max_cyclomatic_complexity).
+    CYCLOMATIC_COMPLEXITY = "cyclomatic-complexity", Allow,
+        "function is too complex";
+    /// `assert` and `require` calls whose condition is always false.
+    ASSERT_FALSE = "assert-false", Allow,
+        "`assert` or `require` used to revert";
+    /// `msg.data` slices and `msg.sig` comparisons that reimplement function dispatch.
+    MANUAL_DISPATCH = "manual-dispatch", Allow,
+        "calldata decoded manually";
+    /// `catch` clauses with an empty block.
+    EMPTY_CATCH = "empty-catch", Allow,
+        "empty `catch` clause swallows the error";
+    /// Functions in the same contract or file that have identical bodies.
+    DUPLICATE_FUNCTION_BODIES = "duplicate-function-bodies", Allow,
+        "function has the same body as another function";
+    /// The deprecated `pragma experimental SMTChecker;`. Warns by default.
+    SMTCHECKER_PRAGMA = "smtchecker-pragma", Warn,
+        "`pragma experimental SMTChecker` is deprecated";
+    /// Contracts that use `delegatecall` and declare state variables.
+    DELEGATECALL_STORAGE = "delegatecall-storage", Allow,
+        "contract uses `delegatecall` and declares state variables";
+    /// Implicit conversions from unsigned to signed integers.
+    SIGN_CONVERSIONS = "sign-conversions", Allow,
+        "implicit conversion from an unsigned to a signed integer";
+    /// Comparisons of boolean expressions to `true` or `false`.
+    BOOL_LITERAL_COMPARISONS = "bool-literal-comparisons", Allow,
+        "comparison to a boolean literal";
+    /// `block.difficulty` on EVM versions where it was replaced by `block.prevrandao`. Warns by
+    /// default.
+    BLOCK_DIFFICULTY = "block-difficulty", Warn,
+        "`block.difficulty` has been deprecated in favor of `block.prevrandao`";
+}
+
+/// Returns the built-in lint with the given name.
+pub fn find(name: &str) -> Option<&'static LintInfo> {
+    BUILTIN_LINTS.iter().copied().find(|lint| lint.name == name)
+}
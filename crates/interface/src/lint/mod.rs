@@ -0,0 +1,135 @@
+//! Lint metadata, levels and the registry of custom lints.
+//!
+//! A lint is a check that reports its diagnostics at a configurable [`LintLevel`]. Each lint is
+//! described by a static [`LintInfo`]; the levels configured for a session are stored in
+//! [`Session::lint_levels`](crate::Session::lint_levels), which override the lints' default
+//! levels. Levels can also be configured for the files matching a [`PathPattern`] only.
+//!
+//! The lint passes themselves are implemented in `solar_sema`, against its `Lint` trait.
+
+use solar_config::LintLevel;
+use std::{any::Any, fmt, path::Path, sync::Arc};
+
+pub mod builtin;
+
+mod pattern;
+pub use pattern::PathPattern;
+
+/// The metadata of a lint.
+#[derive(Debug)]
+pub struct LintInfo {
+    /// The name of the lint, in kebab-case, like `unused-payable`.
+    pub name: &'static str,
+    /// The level at which the lint is reported if no level is configured for it.
+    pub default_level: LintLevel,
+    /// A short description of what the lint reports, used as its diagnostic message.
+    pub message: &'static str,
+}
+
+impl PartialEq for LintInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for LintInfo {}
+
+/// The lint levels configured for a session.
+///
+/// Lints without a configured level are reported at their [default
+/// level](LintInfo::default_level). Levels configured for a [`PathPattern`] take precedence over
+/// the other levels in the matching files; if several patterns match, the last one wins.
+#[derive(Clone, Debug, Default)]
+pub struct LintLevels {
+    levels: Vec<(String, LintLevel)>,
+    path_levels: Vec<(PathPattern, String, LintLevel)>,
+}
+
+impl LintLevels {
+    /// Sets the level of the lint with the given name, replacing any previously set level.
+    pub fn set(&mut self, name: impl Into<String>, level: LintLevel) {
+        let name = name.into();
+        if let Some((_, l)) = self.levels.iter_mut().find(|(n, _)| *n == name) {
+            *l = level;
+        } else {
+            self.levels.push((name, level));
+        }
+    }
+
+    /// Sets the level of the lint with the given name in the files matching `pattern`.
+    pub fn set_for_paths(
+        &mut self,
+        pattern: PathPattern,
+        name: impl Into<String>,
+        level: LintLevel,
+    ) {
+        self.path_levels.push((pattern, name.into(), level));
+    }
+
+    /// Returns the level of the given lint, ignoring the levels configured for paths.
+    pub fn get(&self, lint: &LintInfo) -> LintLevel {
+        self.levels
+            .iter()
+            .find(|(name, _)| name == lint.name)
+            .map_or(lint.default_level, |&(_, level)| level)
+    }
+
+    /// Returns the level of the given lint in the file at `path`.
+    pub fn get_for_path(&self, lint: &LintInfo, path: &Path) -> LintLevel {
+        self.path_levels
+            .iter()
+            .rev()
+            .find(|(pattern, name, _)| name == lint.name && pattern.matches(path))
+            .map_or_else(|| self.get(lint), |&(_, _, level)| level)
+    }
+
+    /// Returns `true` if the given lint is not allowed in at least some files.
+    pub fn is_enabled(&self, lint: &LintInfo) -> bool {
+        let mut path_levels =
+            self.path_levels.iter().filter(|(_, name, _)| name == lint.name).map(|&(.., l)| l);
+        !self.get(lint).is_allow() || path_levels.any(|level| !level.is_allow())
+    }
+
+    /// Returns the names of the lints with a configured level.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        let path_names = self.path_levels.iter().map(|(_, name, _)| name);
+        self.levels.iter().map(|(name, _)| name).chain(path_names).map(String::as_str)
+    }
+}
+
+/// The custom lints registered on a session, which are run alongside the built-in lints.
+///
+/// `solar-interface` does not know about the lint trait, so the lints are stored type-erased.
+/// Register and retrieve them with the functions of `solar_sema::lint` instead.
+#[derive(Clone, Default)]
+pub struct LintRegistry {
+    lints: Vec<Arc<dyn Any + Send + Sync>>,
+}
+
+impl fmt::Debug for LintRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LintRegistry").field("len", &self.lints.len()).finish_non_exhaustive()
+    }
+}
+
+impl LintRegistry {
+    /// Registers a type-erased lint.
+    pub fn register_erased(&mut self, lint: Arc<dyn Any + Send + Sync>) {
+        self.lints.push(lint);
+    }
+
+    /// Returns the registered type-erased lints, in registration order.
+    pub fn iter_erased(&self) -> impl Iterator<Item = &(dyn Any + Send + Sync)> {
+        self.lints.iter().map(|lint| &**lint)
+    }
+
+    /// Returns the number of registered lints.
+    pub fn len(&self) -> usize {
+        self.lints.len()
+    }
+
+    /// Returns `true` if no lints are registered.
+    pub fn is_empty(&self) -> bool {
+        self.lints.is_empty()
+    }
+}
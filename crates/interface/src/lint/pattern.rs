@@ -0,0 +1,122 @@
+use std::{fmt, path::Path, str::FromStr};
+
+/// A glob pattern matched against the paths of source files, used to configure lint levels for
+/// some files only.
+///
+/// The pattern syntax is:
+/// - `?` matches any single character except `/`;
+/// - `*` matches any sequence of characters except `/`;
+/// - `**` matches any sequence of characters, including `/`; `**/` also matches no directory at
+///   all, so `**/test/**` matches both `test/a.sol` and `lib/test/a.sol`;
+/// - any other character matches itself.
+///
+/// A pattern without a `/`, like `*.t.sol`, is matched against the file name only. Other patterns
+/// are matched against the whole path, relative to the current directory for files inside of it.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PathPattern {
+    pattern: String,
+    chars: Vec<char>,
+}
+
+impl fmt::Debug for PathPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PathPattern").field(&self.pattern).finish()
+    }
+}
+
+impl fmt::Display for PathPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.pattern)
+    }
+}
+
+impl FromStr for PathPattern {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err("empty path pattern");
+        }
+        Ok(Self::new(s))
+    }
+}
+
+impl PathPattern {
+    /// Creates a new path pattern.
+    pub fn new(pattern: impl Into<String>) -> Self {
+        let pattern = pattern.into().replace('\\', "/");
+        let chars = pattern.strip_prefix("./").unwrap_or(&pattern).chars().collect();
+        Self { pattern, chars }
+    }
+
+    /// Returns the pattern as a string.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns `true` if the given path matches the pattern.
+    pub fn matches(&self, path: &Path) -> bool {
+        if !self.chars.contains(&'/') {
+            let Some(name) = path.file_name() else { return false };
+            let name = name.to_string_lossy().chars().collect::<Vec<_>>();
+            return matches(&self.chars, &name);
+        }
+
+        let cwd = std::env::current_dir().ok();
+        let path = cwd.as_deref().and_then(|cwd| path.strip_prefix(cwd).ok()).unwrap_or(path);
+        let path = path.to_string_lossy().replace('\\', "/");
+        let path = path.strip_prefix("./").unwrap_or(&path).chars().collect::<Vec<_>>();
+        matches(&self.chars, &path)
+    }
+}
+
+fn matches(pattern: &[char], s: &[char]) -> bool {
+    match pattern {
+        [] => s.is_empty(),
+        ['*', '*', '/', rest @ ..] => {
+            matches(rest, s)
+                || s.iter().enumerate().any(|(i, &c)| c == '/' && matches(rest, &s[i + 1..]))
+        }
+        ['*', '*', rest @ ..] => (0..=s.len()).any(|i| matches(rest, &s[i..])),
+        ['*', rest @ ..] => {
+            let end = s.iter().position(|&c| c == '/').unwrap_or(s.len());
+            (0..=end).any(|i| matches(rest, &s[i..]))
+        }
+        ['?', rest @ ..] => matches!(s, [c, ..] if *c != '/') && matches(rest, &s[1..]),
+        [p, rest @ ..] => s.first() == Some(p) && matches(rest, &s[1..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_patterns() {
+        let cases: &[(&str, &str, bool)] = &[
+            ("*.t.sol", "test/Counter.t.sol", true),
+            ("*.t.sol", "/abs/test/Counter.t.sol", true),
+            ("*.t.sol", "src/Counter.sol", false),
+            ("Counter.?.sol", "src/Counter.t.sol", true),
+            ("test/**", "test/Counter.t.sol", true),
+            ("test/**", "test/utils/Utils.sol", true),
+            ("test/**", "./test/Counter.t.sol", true),
+            ("./test/**", "test/Counter.t.sol", true),
+            ("test/**", "src/test/Counter.t.sol", false),
+            ("**/test/**", "src/test/Counter.t.sol", true),
+            ("**/test/**", "test/Counter.t.sol", true),
+            ("**/test/**", "/abs/test/Counter.t.sol", true),
+            ("**/test/**", "/abs/tests/Counter.t.sol", false),
+            ("src/*.sol", "src/Counter.sol", true),
+            ("src/*.sol", "src/utils/Math.sol", false),
+            ("src/**/*.sol", "src/Counter.sol", true),
+            ("src/**/*.sol", "src/utils/Math.sol", true),
+            ("src/**/*.sol", "test/Counter.t.sol", false),
+        ];
+        for &(pattern, path, expected) in cases {
+            let matched = PathPattern::new(pattern).matches(Path::new(path));
+            assert_eq!(matched, expected, "{pattern:?} on {path:?}");
+        }
+    }
+}
@@ -1,8 +1,15 @@
 use crate::{
-    diagnostics::{DiagCtxt, EmittedDiagnostics},
-    ColorChoice, SessionGlobals, SourceMap,
+    diagnostics::{
+        DiagCtxt, DiagnosticBuilder, DiagnosticMessage, EmittedDiagnostics, Level, MultiSpan,
+    },
+    lint::{LintInfo, LintLevels, LintRegistry},
+    source_map::FileName,
+    ColorChoice, SessionGlobals, SourceMap, Span,
+};
+use solar_config::{
+    CompilerOutput, CompilerStage, Dump, EvmVersion, FunctionSpecifier, ImportGraphFormat,
+    Language, LintLevel, SolcVersion, UnresolvedImports,
 };
-use solar_config::{CompilerOutput, CompilerStage, Dump, EvmVersion, Language};
 use std::{collections::BTreeSet, num::NonZeroUsize, path::PathBuf, sync::Arc};
 
 /// Information about the current compiler session.
@@ -25,18 +32,56 @@ pub struct Session {
     /// Source code language.
     #[builder(default)]
     pub language: Language,
+    /// The `solc` version whose language rules to emulate. See [`SolcVersion`].
+    #[builder(default)]
+    pub solc_compat: Option<SolcVersion>,
     /// Stop execution after the given compiler stage.
     #[builder(default)]
     pub stop_after: Option<CompilerStage>,
+    /// How to handle imports that cannot be resolved.
+    #[builder(default)]
+    pub unresolved_imports: UnresolvedImports,
+    /// The maximum number of nested imports through which a source can be reached from the loaded
+    /// sources. Deeper imports are reported as errors and not resolved.
+    #[builder(default = "Session::DEFAULT_MAX_IMPORT_DEPTH")]
+    pub max_import_depth: usize,
     /// Types of output to emit.
     #[builder(default)]
     pub emit: BTreeSet<CompilerOutput>,
     /// Output directory.
     #[builder(default)]
     pub out_dir: Option<PathBuf>,
+    /// Only run the analyses and emit the output of the contracts with the given names.
+    ///
+    /// All the sources are still parsed and resolved. If empty, all contracts are selected.
+    #[builder(default)]
+    pub contracts: Vec<String>,
     /// Internal state to dump to stdout.
     #[builder(default)]
     pub dump: Option<Dump>,
+    /// Emit the import graph of the sources in the given format after import resolution.
+    #[builder(default)]
+    pub import_graph: Option<ImportGraphFormat>,
+    /// Emit a JSON report of the symbols reported by the `unused-*` lints after analysis.
+    #[builder(default)]
+    pub unused_report: bool,
+    /// The levels of the lints, overriding their default levels.
+    #[builder(default)]
+    pub lint_levels: LintLevels,
+    /// The custom lints to run alongside the built-in lints.
+    #[builder(default)]
+    pub lints: LintRegistry,
+    /// The cyclomatic complexity above which the `cyclomatic-complexity` lint reports a function.
+    #[builder(default = "Session::DEFAULT_MAX_CYCLOMATIC_COMPLEXITY")]
+    pub max_cyclomatic_complexity: usize,
+    /// The canonical order of function specifiers. Specifiers that are not listed must come
+    /// after the listed ones.
+    #[builder(default = "FunctionSpecifier::DEFAULT_ORDER.to_vec()")]
+    pub specifier_order: Vec<FunctionSpecifier>,
+    /// Collect the span of every statement and expression of the parsed sources into a
+    /// `SpanTable`, for tools such as coverage reporters.
+    #[builder(default)]
+    pub collect_span_tables: bool,
     /// Pretty-print any JSON output.
     #[builder(default)]
     pub pretty_json: bool,
@@ -118,6 +163,12 @@ impl SessionBuilder {
 }
 
 impl Session {
+    /// The default value of [`Self::max_import_depth`].
+    pub const DEFAULT_MAX_IMPORT_DEPTH: usize = 256;
+
+    /// The default value of [`max_cyclomatic_complexity`](Self::max_cyclomatic_complexity).
+    pub const DEFAULT_MAX_CYCLOMATIC_COMPLEXITY: usize = 10;
+
     /// Creates a new session with the given diagnostics context and source map.
     pub fn new(dcx: DiagCtxt, source_map: Arc<SourceMap>) -> Self {
         Self::builder().dcx(dcx).source_map(source_map).build()
@@ -158,7 +209,7 @@ impl Session {
     /// Returns `true` if compilation should stop after the given stage.
     #[inline]
     pub fn stop_after(&self, stage: CompilerStage) -> bool {
-        self.stop_after >= Some(stage)
+        self.stop_after.is_some_and(|stop_after| stop_after <= stage)
     }
 
     /// Returns `true` if parallelism is not enabled.
@@ -179,6 +230,45 @@ impl Session {
         self.emit.contains(&output)
     }
 
+    /// Returns the level of the given lint at the given span.
+    ///
+    /// This takes into account the levels configured for the path of the span's file.
+    pub fn lint_level(&self, lint: &LintInfo, span: Span) -> LintLevel {
+        if !span.is_dummy() {
+            if let FileName::Real(path) = &self.source_map.lookup_source_file(span.lo()).name {
+                return self.lint_levels.get_for_path(lint, path);
+            }
+        }
+        self.lint_levels.get(lint)
+    }
+
+    /// Returns `true` if the given lint is not allowed, in at least some files.
+    #[inline]
+    pub fn is_lint_enabled(&self, lint: &LintInfo) -> bool {
+        self.lint_levels.is_enabled(lint)
+    }
+
+    /// Creates a diagnostic for the given lint at its level with the given span and message.
+    ///
+    /// The level is resolved from the file of the primary span. Returns `None` if the lint is
+    /// allowed there.
+    #[track_caller]
+    pub fn struct_lint(
+        &self,
+        lint: &LintInfo,
+        span: impl Into<MultiSpan>,
+        msg: impl Into<DiagnosticMessage>,
+    ) -> Option<DiagnosticBuilder<'_, ()>> {
+        let span = span.into();
+        let primary = span.primary_span().unwrap_or(Span::DUMMY);
+        let level = match self.lint_level(lint, primary) {
+            LintLevel::Allow => return None,
+            LintLevel::Warn => Level::Warning,
+            LintLevel::Deny => Level::Error,
+        };
+        Some(self.dcx.diag(level, msg).span(span))
+    }
+
     /// Spawns the given closure on the thread pool or executes it immediately if parallelism is not
     /// enabled.
     // NOTE: This only exists because on a `use_current_thread` thread pool `rayon::spawn` will
@@ -124,16 +124,28 @@ impl FileName {
     /// Displays the filename.
     #[inline]
     pub fn display(&self) -> FileNameDisplay<'_> {
-        FileNameDisplay { inner: self }
+        FileNameDisplay { inner: self, remapped: None }
     }
 }
 
 pub struct FileNameDisplay<'a> {
     inner: &'a FileName,
+    remapped: Option<PathBuf>,
+}
+
+impl FileNameDisplay<'_> {
+    /// Displays `path` instead of the real path of the file.
+    pub(super) fn with_path(mut self, path: PathBuf) -> Self {
+        self.remapped = Some(path);
+        self
+    }
 }
 
 impl fmt::Display for FileNameDisplay<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(path) = &self.remapped {
+            return path.display().fmt(f);
+        }
         match self.inner {
             FileName::Real(path) => path.display().fmt(f),
             FileName::Stdin => f.write_str("<stdin>"),
@@ -382,6 +394,40 @@ impl SourceFile {
         Some(get_until_newline(&self.src, start))
     }
 
+    /// Converts a byte column on the given 0-based line to a UTF-16 code unit column, as used by
+    /// the Language Server Protocol.
+    ///
+    /// Columns past the end of the line are clamped to the end of the line. A column in the
+    /// middle of a character is rounded down to the start of that character.
+    pub fn byte_to_utf16(&self, line: usize, byte_col: u32) -> u32 {
+        let Some(line) = self.get_line(line) else { return 0 };
+        let mut utf16_col = 0;
+        for (i, c) in line.char_indices() {
+            if i + c.len_utf8() > byte_col as usize {
+                break;
+            }
+            utf16_col += c.len_utf16() as u32;
+        }
+        utf16_col
+    }
+
+    /// Converts a UTF-16 code unit column on the given 0-based line, as used by the Language
+    /// Server Protocol, to a byte column.
+    ///
+    /// Columns past the end of the line are clamped to the end of the line. A column in the
+    /// middle of a surrogate pair is rounded down to the start of that character.
+    pub fn utf16_to_byte(&self, line: usize, utf16_col: u32) -> u32 {
+        let Some(line) = self.get_line(line) else { return 0 };
+        let mut utf16 = 0;
+        for (i, c) in line.char_indices() {
+            utf16 += c.len_utf16() as u32;
+            if utf16 > utf16_col {
+                return i as u32;
+            }
+        }
+        line.len() as u32
+    }
+
     /// Gets a slice of the source text between two lines, including the
     /// terminator of the second line (if any).
     pub fn get_lines(&self, range: RangeInclusive<usize>) -> Option<&str> {
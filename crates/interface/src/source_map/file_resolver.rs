@@ -24,17 +24,28 @@ pub enum ResolveError {
     NotFound(PathBuf),
     #[error("multiple files match {0}: {}", _1.iter().map(|f| f.name.display()).format(", "))]
     MultipleMatches(PathBuf, Vec<Arc<SourceFile>>),
+    #[error("refusing to resolve {0} through a symlink; following symlinks is disabled")]
+    Symlink(PathBuf),
+    #[error("refusing to resolve {0}: {1} is outside of the allowed paths")]
+    NotAllowed(PathBuf, PathBuf),
 }
 
 pub struct FileResolver<'a> {
     source_map: &'a SourceMap,
     import_paths: Vec<(Option<PathBuf>, PathBuf)>,
+    allowed_paths: Vec<PathBuf>,
+    follow_symlinks: bool,
 }
 
 impl<'a> FileResolver<'a> {
     /// Creates a new file resolver.
     pub fn new(source_map: &'a SourceMap) -> Self {
-        Self { source_map, import_paths: Vec::new() }
+        Self {
+            source_map,
+            import_paths: Vec::new(),
+            allowed_paths: Vec::new(),
+            follow_symlinks: true,
+        }
     }
 
     /// Returns the source map.
@@ -42,6 +53,35 @@ impl<'a> FileResolver<'a> {
         self.source_map
     }
 
+    /// Returns `true` if symlinks are followed when resolving files.
+    pub fn follow_symlinks(&self) -> bool {
+        self.follow_symlinks
+    }
+
+    /// Sets whether symlinks are followed when resolving files. Defaults to `true`.
+    ///
+    /// When disabled, resolving a path that goes through a symlink fails with
+    /// [`ResolveError::Symlink`].
+    pub fn set_follow_symlinks(&mut self, follow_symlinks: bool) {
+        self.follow_symlinks = follow_symlinks;
+    }
+
+    /// Adds a directory from which files may be loaded.
+    ///
+    /// Once an allowed path is added, files are only loaded if their canonical path, with all
+    /// symlinks resolved, is inside of an allowed path or an import path. Resolving any other file
+    /// fails with [`ResolveError::NotAllowed`].
+    pub fn add_allowed_path(&mut self, path: PathBuf) {
+        if !self.allowed_paths.contains(&path) {
+            self.allowed_paths.push(path);
+        }
+    }
+
+    /// Returns the allowed paths. See [`add_allowed_path`](Self::add_allowed_path).
+    pub fn allowed_paths(&self) -> &[PathBuf] {
+        &self.allowed_paths
+    }
+
     /// Adds an import path. Returns `true` if the path is newly inserted.
     pub fn add_import_path(&mut self, path: PathBuf) -> bool {
         let entry = (None, path);
@@ -92,7 +132,7 @@ impl<'a> FileResolver<'a> {
             if let Some(parent) = parent {
                 let base = parent.parent().unwrap_or(Path::new("."));
                 let path = base.join(path);
-                if let Some(file) = self.try_file(&path)? {
+                if let Some(file) = self.try_file_in(Some(base), &path)? {
                     // No ambiguity possible, so just return
                     return Ok(file);
                 }
@@ -118,7 +158,7 @@ impl<'a> FileResolver<'a> {
         for import in &self.import_paths {
             if let (None, import_path) = import {
                 let path = import_path.join(&path);
-                if let Some(file) = self.try_file(&path)? {
+                if let Some(file) = self.try_file_in(Some(import_path), &path)? {
                     result.push(file);
                 }
             }
@@ -161,8 +201,31 @@ impl<'a> FileResolver<'a> {
     }
 
     /// Loads `path` into the source map. Returns `None` if the file doesn't exist.
-    #[instrument(level = "debug", skip_all)]
     pub fn try_file(&self, path: &Path) -> Result<Option<Arc<SourceFile>>, ResolveError> {
+        self.try_file_in(None, path)
+    }
+
+    /// Loads `path`, which was found in `root`, into the source map. Returns `None` if the file
+    /// doesn't exist.
+    ///
+    /// Only the components of `path` under `root` are checked for symlinks.
+    #[instrument(level = "debug", skip_all)]
+    fn try_file_in(
+        &self,
+        root: Option<&Path>,
+        path: &Path,
+    ) -> Result<Option<Arc<SourceFile>>, ResolveError> {
+        if !self.follow_symlinks && is_symlinked(root, path) {
+            return Err(ResolveError::Symlink(path.into()));
+        }
+        if !self.allowed_paths.is_empty() {
+            if let Ok(target) = crate::canonicalize(path) {
+                if !self.is_allowed(&target) {
+                    return Err(ResolveError::NotAllowed(path.into(), target));
+                }
+            }
+        }
+
         let cache_path = path.normalize();
         if let Ok(file) = self.source_map().load_file(&cache_path) {
             trace!("loaded from cache");
@@ -189,4 +252,114 @@ impl<'a> FileResolver<'a> {
         trace!("not found");
         Ok(None)
     }
+
+    /// Returns `true` if the canonical path `target` is inside of an allowed path or an import
+    /// path.
+    fn is_allowed(&self, target: &Path) -> bool {
+        let import_paths = self.import_paths.iter().map(|(_, path)| path);
+        self.allowed_paths.iter().chain(import_paths).any(|allowed| {
+            crate::canonicalize(allowed).is_ok_and(|allowed| target.starts_with(allowed))
+        })
+    }
+}
+
+/// Returns `true` if `path` or any of its ancestors under `root` is a symlink.
+///
+/// Without a root, relative paths and absolute paths in the current directory are checked up to
+/// the current directory, and other absolute paths are only checked for the file itself.
+fn is_symlinked(root: Option<&Path>, path: &Path) -> bool {
+    let root = match root {
+        Some(root) => root.to_path_buf(),
+        None if path.is_relative() => PathBuf::new(),
+        None => match std::env::current_dir() {
+            Ok(cwd) if path.starts_with(&cwd) => cwd,
+            _ => path.parent().map(Path::to_path_buf).unwrap_or_default(),
+        },
+    };
+    path.ancestors()
+        .take_while(|&p| p != root && !p.as_os_str().is_empty())
+        .any(|p| p.symlink_metadata().is_ok_and(|m| m.file_type().is_symlink()))
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::{fs, os::unix::fs::symlink};
+
+    fn setup() -> (tempfile::TempDir, PathBuf) {
+        let dir = tempfile::tempdir().unwrap();
+        // The temporary directory itself may be behind a symlink, e.g. on macOS.
+        let root = dir.path().canonicalize().unwrap();
+        fs::create_dir(root.join("real")).unwrap();
+        fs::write(root.join("real/a.sol"), "contract A {}").unwrap();
+        symlink(root.join("real"), root.join("link")).unwrap();
+        (dir, root)
+    }
+
+    #[test]
+    fn follow_symlinks() {
+        let (_dir, root) = setup();
+        let sm = SourceMap::empty();
+        let mut resolver = FileResolver::new(&sm);
+        assert!(resolver.follow_symlinks());
+        resolver.add_import_path(root.clone());
+        let file = resolver.resolve_file(Path::new("link/a.sol"), Some(&root.join("main.sol")));
+        assert_eq!(&*file.unwrap().src, "contract A {}");
+    }
+
+    #[test]
+    fn dont_follow_symlinks() {
+        let (_dir, root) = setup();
+        let parent = root.join("main.sol");
+        let sm = SourceMap::empty();
+        let mut resolver = FileResolver::new(&sm);
+        resolver.set_follow_symlinks(false);
+        resolver.add_import_path(root.clone());
+
+        let file = resolver.resolve_file(Path::new("real/a.sol"), Some(&parent)).unwrap();
+        assert_eq!(&*file.src, "contract A {}");
+
+        let err = resolver.resolve_file(Path::new("link/a.sol"), Some(&parent)).unwrap_err();
+        assert!(matches!(err, ResolveError::Symlink(_)), "{err:?}");
+        let err = resolver.resolve_file(Path::new("./link/a.sol"), Some(&parent)).unwrap_err();
+        assert!(matches!(err, ResolveError::Symlink(_)), "{err:?}");
+    }
+
+    #[test]
+    fn dont_follow_symlinks_in_root() {
+        let (_dir, root) = setup();
+        symlink(&root, root.join("root_link")).unwrap();
+        let root_link = root.join("root_link");
+        let sm = SourceMap::empty();
+        let mut resolver = FileResolver::new(&sm);
+        resolver.set_follow_symlinks(false);
+        resolver.add_import_path(root_link.clone());
+
+        let parent = root_link.join("main.sol");
+        let file = resolver.resolve_file(Path::new("real/a.sol"), Some(&parent)).unwrap();
+        assert_eq!(&*file.src, "contract A {}");
+        let file = resolver.resolve_file(Path::new("./real/a.sol"), Some(&parent)).unwrap();
+        assert_eq!(&*file.src, "contract A {}");
+    }
+
+    #[test]
+    fn allowed_paths() {
+        let (_dir, root) = setup();
+        fs::create_dir(root.join("secret")).unwrap();
+        fs::write(root.join("secret/s.sol"), "contract S {}").unwrap();
+        symlink(root.join("secret"), root.join("real/secret")).unwrap();
+        let sm = SourceMap::empty();
+        let mut resolver = FileResolver::new(&sm);
+        resolver.add_allowed_path(root.join("real"));
+
+        let parent = root.join("real/main.sol");
+        let file = resolver.resolve_file(Path::new("./a.sol"), Some(&parent)).unwrap();
+        assert_eq!(&*file.src, "contract A {}");
+
+        let err = resolver.resolve_file(Path::new("./secret/s.sol"), Some(&parent)).unwrap_err();
+        assert!(
+            matches!(&err, ResolveError::NotAllowed(_, target) if *target == root.join("secret/s.sol")),
+            "{err:?}"
+        );
+    }
 }
@@ -19,6 +19,9 @@ pub use file::*;
 mod file_resolver;
 pub use file_resolver::{FileResolver, ResolveError};
 
+mod rewrite;
+pub use rewrite::RewriteError;
+
 #[cfg(test)]
 mod tests;
 
@@ -100,8 +103,14 @@ pub struct SourceMap {
     source_files: RwLock<Vec<Arc<SourceFile>>>,
     stable_id_to_source_file: scc::HashIndex<StableSourceFileId, Arc<SourceFile>, FxBuildHasher>,
     hash_kind: SourceFileHashAlgorithm,
+    path_remapper: RwLock<Option<PathRemapper>>,
 }
 
+/// A function that rewrites file paths for display.
+///
+/// See [`SourceMap::set_path_remapper`].
+pub type PathRemapper = Box<dyn Fn(&Path) -> PathBuf + Send + Sync>;
+
 impl Default for SourceMap {
     fn default() -> Self {
         Self::empty()
@@ -115,6 +124,7 @@ impl SourceMap {
             source_files: RwLock::new(Vec::new()),
             stable_id_to_source_file: Default::default(),
             hash_kind,
+            path_remapper: RwLock::new(None),
         }
     }
 
@@ -205,8 +215,23 @@ impl SourceMap {
         self.stable_id_to_source_file.get(&stable_id).as_deref().cloned()
     }
 
+    /// Sets the function used to rewrite real file paths when displaying them, such as in
+    /// diagnostics.
+    ///
+    /// This does not affect how files are loaded or identified.
+    pub fn set_path_remapper(&self, remapper: PathRemapper) {
+        *self.path_remapper.write() = Some(remapper);
+    }
+
+    /// Returns the display of the given file name, with the path remapper applied.
     pub fn filename_for_diagnostics<'a>(&self, filename: &'a FileName) -> FileNameDisplay<'a> {
-        filename.display()
+        let display = filename.display();
+        if let FileName::Real(path) = filename {
+            if let Some(remapper) = &*self.path_remapper.read() {
+                return display.with_path(remapper(path));
+            }
+        }
+        display
     }
 
     /// Returns `true` if the given span is multi-line.
@@ -375,7 +400,7 @@ impl SourceMap {
         let (source_file, lo_line, lo_col, hi_line, hi_col) = self.span_to_location_info(sp);
 
         let file_name = match source_file {
-            Some(sf) => sf.name.display().to_string(),
+            Some(sf) => self.filename_for_diagnostics(&sf.name).to_string(),
             None => return "no-location".to_string(),
         };
 
@@ -0,0 +1,63 @@
+//! Format-preserving rewriting of source files.
+
+use super::SourceFile;
+use crate::{Pos, Span};
+
+/// An error that occurred while rewriting a source file.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum RewriteError {
+    #[error("span {0:?} is not in the source file")]
+    InvalidSpan(Span),
+    #[error("edits at {0:?} and {1:?} overlap")]
+    Overlap(Span, Span),
+}
+
+impl SourceFile {
+    /// Applies the given `(span, replacement)` edits to the source code of this file, and returns
+    /// the rewritten source code.
+    ///
+    /// Each edit replaces the source code in its span, or inserts its replacement if the span is
+    /// empty. The source code outside of the edited spans is kept byte for byte, so this can be
+    /// used to refactor code without printing it again.
+    ///
+    /// Edits can be given in any order. Insertions at the same position are applied in the given
+    /// order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a span is not in this file, or if two edits overlap.
+    pub fn rewrite<S: AsRef<str>>(
+        &self,
+        edits: impl IntoIterator<Item = (Span, S)>,
+    ) -> Result<String, RewriteError> {
+        let mut edits = edits.into_iter().collect::<Vec<_>>();
+        edits.sort_by_key(|(span, _)| (span.lo(), span.hi()));
+
+        let src = self.src.as_str();
+        let mut rewritten = String::with_capacity(src.len());
+        let mut pos = 0;
+        let mut prev = None::<Span>;
+        for (span, replacement) in &edits {
+            let span = *span;
+            if !self.contains(span.lo()) || !self.contains(span.hi()) {
+                return Err(RewriteError::InvalidSpan(span));
+            }
+            let lo = self.relative_position(span.lo()).to_usize();
+            let hi = self.relative_position(span.hi()).to_usize();
+            if !src.is_char_boundary(lo) || !src.is_char_boundary(hi) {
+                return Err(RewriteError::InvalidSpan(span));
+            }
+            if let Some(prev) = prev {
+                if lo < pos {
+                    return Err(RewriteError::Overlap(prev, span));
+                }
+            }
+            rewritten.push_str(&src[pos..lo]);
+            rewritten.push_str(replacement.as_ref());
+            pos = hi;
+            prev = Some(span);
+        }
+        rewritten.push_str(&src[pos..]);
+        Ok(rewritten)
+    }
+}
@@ -1,5 +1,5 @@
 use super::*;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 fn init_source_map() -> SourceMap {
     let sm = SourceMap::empty();
@@ -148,6 +148,34 @@ fn t7() {
     assert_eq!(file_lines.lines[0].line_index, 1);
 }
 
+/// Tests `byte_to_utf16` and `utf16_to_byte` with multi-byte and astral-plane chars.
+#[test]
+fn utf16_columns() {
+    let sm = SourceMap::empty();
+    // "€" is three bytes and one UTF-16 code unit, "😀" is four bytes and a surrogate pair.
+    let file =
+        sm.new_dummy_source_file(PathBuf::from("blork.rs"), "a€b😀c\n// 😀 é".to_string()).unwrap();
+
+    let bytes_to_utf16 = [(0, 0), (1, 1), (4, 2), (5, 3), (9, 5), (10, 6)];
+    for (byte, utf16) in bytes_to_utf16 {
+        assert_eq!(file.byte_to_utf16(0, byte), utf16, "byte {byte}");
+        assert_eq!(file.utf16_to_byte(0, utf16), byte, "utf16 {utf16}");
+    }
+    assert_eq!(file.byte_to_utf16(1, 8), 6);
+    assert_eq!(file.byte_to_utf16(1, 10), 7);
+    assert_eq!(file.utf16_to_byte(1, 6), 8);
+    assert_eq!(file.utf16_to_byte(1, 7), 10);
+
+    // Positions inside a character round down to its start.
+    assert_eq!(file.byte_to_utf16(0, 7), 3);
+    assert_eq!(file.utf16_to_byte(0, 4), 5);
+
+    // Positions past the end of the line are clamped.
+    assert_eq!(file.byte_to_utf16(0, 100), 6);
+    assert_eq!(file.utf16_to_byte(0, 100), 10);
+    assert_eq!(file.byte_to_utf16(2, 0), 0);
+}
+
 /// Given a string like " ~~~~~~~~~~~~ ", produces a span
 /// converting that range. The idea is that the string has the same
 /// length as the input, and we uncover the byte positions. Note
@@ -598,6 +626,38 @@ fn test_next_point() {
     assert!(sm.span_to_snippet(span).is_err());
 }
 
+#[test]
+fn rewrite() {
+    let sm = init_source_map();
+    let src = "contract C {\n    uint x  =  1; // keep\n    function foo() public {}\n}\n";
+    let file = sm.new_dummy_source_file(PathBuf::from("rewrite.sol"), src.to_string()).unwrap();
+    let span = |needle: &str| {
+        let lo = file.start_pos + BytePos(src.find(needle).unwrap() as u32);
+        Span::new(lo, lo + BytePos(needle.len() as u32))
+    };
+    let insert = |needle: &str| span(needle).shrink_to_lo();
+
+    let edits = [
+        (span("foo"), "bar"),
+        (span("uint"), "uint256"),
+        (insert("contract"), "// SPDX-License-Identifier: MIT\n"),
+        (span("1"), "2"),
+        (span("public"), "external"),
+        (span("C"), "D"),
+    ];
+    assert_eq!(
+        file.rewrite(edits).unwrap(),
+        "// SPDX-License-Identifier: MIT\ncontract D {\n    uint256 x  =  2; // keep\n    \
+         function bar() external {}\n}\n"
+    );
+    assert_eq!(file.rewrite::<&str>([]).unwrap(), src);
+
+    let (fun, public) = (span("function foo()"), span("foo() public"));
+    assert_eq!(file.rewrite([(fun, ""), (public, "")]), Err(RewriteError::Overlap(fun, public)));
+    let outside = Span::new(file.end_position(), file.end_position() + BytePos(1));
+    assert_eq!(file.rewrite([(outside, "")]), Err(RewriteError::InvalidSpan(outside)));
+}
+
 #[cfg(any())]
 #[cfg(target_os = "linux")]
 #[test]
@@ -625,3 +685,22 @@ fn read_binary_file_handles_lying_stat() {
     let bin = RealFileLoader.read_binary_file(kernel_max).unwrap();
     assert_eq!(&real[..], &bin[..]);
 }
+
+#[test]
+fn path_remapper() {
+    use crate::diagnostics::test_utils::{span, TestDcx};
+
+    let t = TestDcx::human(std::convert::identity);
+    let file = t.file("/build/src/A.sol", "contract A {}");
+    t.sm.set_path_remapper(Box::new(|path| match path.strip_prefix("/build/src/") {
+        Ok(rest) => Path::new("./src").join(rest),
+        Err(_) => path.to_path_buf(),
+    }));
+
+    let span = span(&file, 0, 8);
+    assert_eq!(t.sm.span_to_string(span), "./src/A.sol:1:1: 1:9");
+    t.dcx.err("test").span(span).emit();
+    let err = t.emitted();
+    assert!(err.contains("./src/A.sol:1:1"), "{err}");
+    assert!(!err.contains("/build/src"), "{err}");
+}
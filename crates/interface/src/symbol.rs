@@ -123,6 +123,12 @@ impl Ident {
         self.name.is_weak_keyword()
     }
 
+    /// Returns `true` if the identifier is a soft keyword. See [`kw::SOFT_KEYWORDS`].
+    #[inline]
+    pub fn is_soft_keyword(self) -> bool {
+        self.name.is_soft_keyword()
+    }
+
     /// Returns `true` if the identifier is a keyword in a Yul context.
     #[inline]
     pub fn is_yul_keyword(self) -> bool {
@@ -254,6 +260,12 @@ impl Symbol {
         self >= kw::Leave && self <= kw::Builtin
     }
 
+    /// Returns `true` if the symbol is a soft keyword. See [`kw::SOFT_KEYWORDS`].
+    #[inline]
+    pub fn is_soft_keyword(self) -> bool {
+        kw::SOFT_KEYWORDS.contains(&self)
+    }
+
     /// Returns `true` if the symbol is a keyword in a Yul context. Excludes EVM builtins.
     #[inline]
     pub fn is_yul_keyword(self) -> bool {
@@ -432,11 +444,19 @@ unsafe impl lasso::Key for Symbol {
 /// Given that `kw` is imported, use them like `kw::keyword_name`.
 /// For example `kw::For` or `kw::Break`.
 pub mod kw {
-    use crate::Symbol;
+    use crate::{sym, Symbol};
 
     #[doc(inline)]
     pub use super::kw_generated::*;
 
+    /// Soft keywords, which only have a special meaning in specific positions, and are ordinary
+    /// identifiers everywhere else.
+    ///
+    /// The positions in which they are keywords are defined by the parser's
+    /// `check_soft_keyword`, which must be updated when adding a new soft keyword.
+    pub const SOFT_KEYWORDS: &[Symbol] =
+        &[sym::error, sym::from, sym::global, Revert, sym::transient];
+
     /// Returns the boolean keyword for the given value.
     #[inline]
     pub const fn boolean(b: bool) -> Symbol {
@@ -856,6 +876,8 @@ symbols! {
         concat,
         creationCode,
         data,
+        dataoffset,
+        datasize,
         decode,
         ecrecover,
         encode,
@@ -870,11 +892,14 @@ symbols! {
         global,
         interfaceId,
         length,
+        linkersymbol,
+        loadimmutable,
         max,
         min,
         msg,
         name,
         object,
+        offset,
         push,
         require,
         ripemd160,
@@ -882,8 +907,10 @@ symbols! {
         selector,
         send,
         sender,
+        setimmutable,
         sha256,
         sig,
+        slot,
         solidity,
         super_: "super",
         this,
@@ -930,4 +957,17 @@ mod tests {
             assert_eq!(Ident::DUMMY.to_string(), "");
         });
     }
+
+    #[test]
+    fn soft_keywords() {
+        crate::enter(|| {
+            for &symbol in kw::SOFT_KEYWORDS {
+                assert!(symbol.is_soft_keyword());
+                // Soft keywords must remain usable as identifiers.
+                assert!(symbol.is_non_reserved(false), "{symbol}");
+            }
+            assert!(!kw::Unchecked.is_soft_keyword());
+            assert!(!sym::x.is_soft_keyword());
+        });
+    }
 }
@@ -83,7 +83,9 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         with: Option<Box<'ast, Expr<'ast>>>,
     ) -> PResult<'sess, Box<'ast, Expr<'ast>>> {
         if with.is_none() && self.eat(&TokenKind::BinOp(BinOpToken::Plus)) {
-            self.dcx().err("unary plus is not supported").span(self.prev_token.span).emit();
+            self.emit_recovered(
+                self.dcx().err("unary plus is not supported").span(self.prev_token.span),
+            );
         }
 
         let lo = with.as_ref().map(|e| e.span).unwrap_or(self.token.span);
@@ -183,7 +185,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
             if let TypeKind::Elementary(ElementaryType::Address(payable)) = &mut ty.kind {
                 if *payable {
                     let msg = "`address payable` cannot be used in an expression";
-                    self.dcx().err(msg).span(ty.span).emit();
+                    self.emit_recovered(self.dcx().err(msg).span(ty.span));
                     *payable = false;
                 }
             }
@@ -1,8 +1,11 @@
 use super::{ExpectedToken, SeqSep};
-use crate::{PResult, Parser};
+use crate::{PErr, PResult, Parser};
 use itertools::Itertools;
 use solar_ast::{ast::*, token::*};
-use solar_interface::{error_code, kw, sym, Ident, Span};
+use solar_interface::{
+    config::FunctionSpecifier, diagnostics::Applicability, error_code, kw, lint::builtin, sym,
+    Ident, Span,
+};
 use std::num::IntErrorKind;
 
 impl<'sess, 'ast> Parser<'sess, 'ast> {
@@ -12,8 +15,30 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         self.parse_items(&TokenKind::Eof).map(SourceUnit::new)
     }
 
+    /// Parses a source unit, keeping the items that were parsed before an error that aborted
+    /// parsing.
+    ///
+    /// Returns the partial source unit alongside the error, if any.
+    #[instrument(level = "debug", skip_all)]
+    pub fn parse_file_partial(&mut self) -> (SourceUnit<'ast>, Option<PErr<'sess>>) {
+        let mut items = Vec::new();
+        let result = self.parse_items_into(&TokenKind::Eof, &mut items);
+        (SourceUnit::new(self.alloc_vec(items)), result.err())
+    }
+
     /// Parses a list of items until the given token is encountered.
     fn parse_items(&mut self, end: &TokenKind) -> PResult<'sess, Box<'ast, [Item<'ast>]>> {
+        let mut items = Vec::new();
+        self.parse_items_into(end, &mut items)?;
+        Ok(self.alloc_vec(items))
+    }
+
+    /// Parses a list of items until the given token is encountered, pushing them into `items`.
+    fn parse_items_into(
+        &mut self,
+        end: &TokenKind,
+        items: &mut Vec<Item<'ast>>,
+    ) -> PResult<'sess, ()> {
         let get_msg_note = |this: &mut Self| {
             let (prefix, list, link);
             if this.in_contract {
@@ -31,12 +56,12 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
             (msg, note)
         };
 
-        let mut items = Vec::new();
         while let Some(item) = self.parse_item()? {
             if self.in_contract && !item.is_allowed_in_contract() {
                 let msg = format!("{}s are not allowed in contracts", item.description());
                 let (_, note) = get_msg_note(self);
-                self.dcx().err(msg).span(item.span).note(note).emit();
+                let err = self.dcx().err(msg).span(item.span).note(note);
+                self.emit_recovered(err);
             } else {
                 items.push(item);
             }
@@ -45,7 +70,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
             let (msg, note) = get_msg_note(self);
             return Err(self.dcx().err(msg).span(self.token.span).note(note));
         }
-        Ok(self.alloc_vec(items))
+        Ok(())
     }
 
     /// Parses an item.
@@ -75,11 +100,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
             self.parse_import().map(ItemKind::Import)
         } else if self.eat_keyword(kw::Using) {
             self.parse_using().map(ItemKind::Using)
-        } else if self.check_keyword(sym::error)
-            && self.look_ahead(1).is_ident()
-            && self.look_ahead(2).is_open_delim(Delimiter::Parenthesis)
-        {
-            self.bump(); // `error`
+        } else if self.eat_soft_keyword(sym::error) {
             self.parse_error().map(ItemKind::Error)
         } else if self.is_variable_declaration() {
             let flags = if self.in_contract { VarFlags::STATE_VAR } else { VarFlags::CONSTANT_VAR };
@@ -150,7 +171,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
 
         if !self.in_contract && !kind.allowed_in_global() {
             let msg = format!("{kind}s are not allowed in the global scope");
-            self.dcx().err(msg).span(lo.to(self.prev_token.span)).emit();
+            self.emit_recovered(self.dcx().err(msg).span(lo.to(self.prev_token.span)));
         }
         // All function kinds are allowed in contracts.
 
@@ -190,7 +211,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
             header.name = Some(ident);
         } else if self.token.is_non_reserved_ident(false) {
             let msg = "function names are not allowed here";
-            self.dcx().err(msg).span(self.token.span).emit();
+            self.emit_recovered(self.dcx().err(msg).span(self.token.span));
             self.bump();
         }
 
@@ -202,8 +223,17 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
             header.parameters = self.parse_parameter_list(true, var_flags)?;
         }
 
+        // Attributes may be specified in any order. The spans of the first ones are recorded to
+        // point to them when diagnosing duplicates.
         let mut modifiers = Vec::new();
+        let mut visibility_span = Span::DUMMY;
+        let mut state_mutability_span = Span::DUMMY;
+        let mut virtual_span = Span::DUMMY;
+        let mut override_span = Span::DUMMY;
+        let mut returns_span = None;
+        let mut specifiers = Vec::new();
         loop {
+            let lo = self.token.span;
             // This is needed to skip parsing surrounding variable's visibility in function types.
             // E.g. in `function(uint) external internal e;` the `internal` is the surrounding
             // variable's visibility, not the function's.
@@ -214,62 +244,177 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
             if let Some(visibility) = vis_guard.and_then(|()| self.parse_visibility()) {
                 if !flags.contains(FunctionFlags::from_visibility(visibility)) {
                     let msg = visibility_error(visibility, flags.visibilities());
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
-                } else if header.visibility.is_some() {
-                    let msg = "visibility already specified";
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                    self.emit_recovered(self.dcx().err(msg).span(self.prev_token.span));
+                } else if let Some(prev) = header.visibility {
+                    let msg = if prev == visibility {
+                        "visibility already specified".to_string()
+                    } else {
+                        format!(
+                            "`{visibility}` conflicts with previously specified visibility \
+                             `{prev}`"
+                        )
+                    };
+                    self.emit_recovered(
+                        self.dcx()
+                            .err(msg)
+                            .span(self.prev_token.span)
+                            .span_note(visibility_span, "previously specified here"),
+                    );
                 } else {
                     header.visibility = Some(visibility);
+                    visibility_span = self.prev_token.span;
+                    specifiers.push((FunctionSpecifier::Visibility, visibility_span));
                 }
             } else if let Some(state_mutability) = self.parse_state_mutability() {
                 if !flags.contains(FunctionFlags::from_state_mutability(state_mutability)) {
                     let msg = state_mutability_error(state_mutability, flags.state_mutabilities());
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                    self.emit_recovered(self.dcx().err(msg).span(self.prev_token.span));
                 } else if !header.state_mutability.is_non_payable() {
-                    let msg = "state mutability already specified";
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                    let prev = header.state_mutability;
+                    let msg = if prev == state_mutability {
+                        "state mutability already specified".to_string()
+                    } else {
+                        format!(
+                            "`{state_mutability}` conflicts with previously specified state \
+                             mutability `{prev}`"
+                        )
+                    };
+                    self.emit_recovered(
+                        self.dcx()
+                            .err(msg)
+                            .span(self.prev_token.span)
+                            .span_note(state_mutability_span, "previously specified here"),
+                    );
                 } else {
                     header.state_mutability = state_mutability;
+                    state_mutability_span = self.prev_token.span;
+                    specifiers.push((FunctionSpecifier::Mutability, state_mutability_span));
                 }
             } else if self.eat_keyword(kw::Virtual) {
                 if !flags.contains(FunctionFlags::VIRTUAL) {
                     let msg = "`virtual` is not allowed here";
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                    self.emit_recovered(self.dcx().err(msg).span(self.prev_token.span));
                 } else if header.virtual_ {
                     let msg = "virtual already specified";
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                    self.emit_recovered(
+                        self.dcx()
+                            .err(msg)
+                            .span(self.prev_token.span)
+                            .span_note(virtual_span, "previously specified here"),
+                    );
                 } else {
                     header.virtual_ = true;
+                    virtual_span = self.prev_token.span;
+                    specifiers.push((FunctionSpecifier::Virtual, virtual_span));
                 }
             } else if self.eat_keyword(kw::Override) {
                 let o = self.parse_override()?;
                 if !flags.contains(FunctionFlags::OVERRIDE) {
                     let msg = "`override` is not allowed here";
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                    self.emit_recovered(self.dcx().err(msg).span(self.prev_token.span));
                 } else if header.override_.is_some() {
                     let msg = "override already specified";
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                    self.emit_recovered(
+                        self.dcx()
+                            .err(msg)
+                            .span(self.prev_token.span)
+                            .span_note(override_span, "previously specified here"),
+                    );
                 } else {
                     header.override_ = Some(o);
+                    override_span = lo.to(self.prev_token.span);
+                    specifiers.push((FunctionSpecifier::Override, override_span));
                 }
             } else if flags.contains(FunctionFlags::MODIFIERS)
                 && self.token.is_non_reserved_ident(false)
             {
                 modifiers.push(self.parse_modifier()?);
+                specifiers.push((FunctionSpecifier::Modifiers, lo.to(self.prev_token.span)));
+            } else if flags.contains(FunctionFlags::RETURNS)
+                && returns_span.is_none()
+                && self.eat_keyword(kw::Returns)
+            {
+                header.returns = self.parse_parameter_list(false, var_flags)?;
+                returns_span = Some(lo.to(self.prev_token.span));
+                // Anything after the returns of a function type belongs to the surrounding
+                // variable.
+                if flags == FunctionFlags::FUNCTION_TY {
+                    break;
+                }
+                continue;
             } else {
                 break;
             }
+
+            // Attributes after `returns` are not allowed, but are kept for recovery.
+            if let Some(returns_span) = returns_span {
+                let msg = "function attributes must be specified before `returns`";
+                let err = self
+                    .dcx()
+                    .err(msg)
+                    .span(lo.to(self.prev_token.span))
+                    .span_note(returns_span, "returns specified here");
+                self.emit_recovered(err);
+            }
         }
 
         header.modifiers = self.alloc_vec(modifiers);
 
-        if flags.contains(FunctionFlags::RETURNS) && self.eat_keyword(kw::Returns) {
-            header.returns = self.parse_parameter_list(false, var_flags)?;
+        // Attributes after `returns` are already diagnosed above.
+        if self.sess.is_lint_enabled(&builtin::SPECIFIER_ORDER)
+            && flags != FunctionFlags::FUNCTION_TY
+        {
+            if let Some(returns_span) = returns_span {
+                specifiers.retain(|&(_, span)| span.lo() < returns_span.lo());
+            }
+            self.check_specifier_order(&specifiers);
         }
 
         Ok(header)
     }
 
+    /// Warns if the given function specifiers are not in the order of
+    /// [`Session::specifier_order`](solar_interface::Session::specifier_order).
+    fn check_specifier_order(&self, specifiers: &[(FunctionSpecifier, Span)]) {
+        let order = &self.sess.specifier_order;
+        let rank = |kind: FunctionSpecifier| order.iter().position(|&k| k == kind);
+        let rank = |&(kind, _): &(FunctionSpecifier, Span)| rank(kind).unwrap_or(order.len());
+        if specifiers.iter().map(rank).is_sorted() {
+            return;
+        }
+        let (Some(&(_, first)), Some(&(_, last))) = (specifiers.first(), specifiers.last()) else {
+            return;
+        };
+        let span = first.to(last);
+
+        let sm = self.sess.source_map();
+        let Ok(snippets) = specifiers
+            .iter()
+            .sorted_by_key(|s| rank(s))
+            .map(|&(_, span)| sm.span_to_snippet(span))
+            .collect::<Result<Vec<_>, _>>()
+        else {
+            return;
+        };
+        // Comments in between the specifiers would be removed by the suggestion.
+        let applicability = match sm.span_to_snippet(span) {
+            Ok(original)
+                if original.split_whitespace().map(str::len).sum::<usize>()
+                    == snippets.iter().map(String::len).sum::<usize>() =>
+            {
+                Applicability::MachineApplicable
+            }
+            _ => Applicability::MaybeIncorrect,
+        };
+
+        let expected = order.iter().map(|kind| kind.to_str()).join(", ");
+        let lint = &builtin::SPECIFIER_ORDER;
+        let Some(diag) = self.sess.struct_lint(lint, span, lint.message) else { return };
+        diag.note(format!("the canonical order is: {expected}"))
+            .span_suggestion(span, "reorder the specifiers", snippets.join(" "), applicability)
+            .emit();
+    }
+
     /// Parses a struct definition.
     fn parse_struct(&mut self) -> PResult<'sess, ItemStruct<'ast>> {
         let name = self.parse_ident()?;
@@ -380,7 +525,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
             }
             if !self.token.is_eof() && tokens.is_empty() {
                 let msg = "expected at least one token in pragma directive";
-                self.dcx().err(msg).span(self.prev_token.span).emit();
+                self.emit_recovered(self.dcx().err(msg).span(self.prev_token.span));
             }
             PragmaTokens::Verbatim(self.alloc_vec(tokens))
         };
@@ -564,7 +709,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
             Err(e) => match e.kind() {
                 IntErrorKind::Empty => 0,
                 _ => {
-                    self.dcx().err(e.to_string()).span(span).emit();
+                    self.emit_recovered(self.dcx().err(e.to_string()).span(span));
                     u32::MAX
                 }
             },
@@ -577,7 +722,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         let items = if self.eat(&TokenKind::BinOp(BinOpToken::Star)) {
             // * as alias from ""
             let alias = self.parse_as_alias()?;
-            self.expect_keyword(sym::from)?;
+            self.expect_soft_keyword(sym::from)?;
             path = self.parse_str_lit()?;
             ImportItems::Glob(alias)
         } else if self.check(&TokenKind::OpenDelim(Delimiter::Brace)) {
@@ -587,7 +732,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
                 let alias = this.parse_as_alias()?;
                 Ok((name, alias))
             })?;
-            self.expect_keyword(sym::from)?;
+            self.expect_soft_keyword(sym::from)?;
             path = self.parse_str_lit()?;
             ImportItems::Aliases(list)
         } else {
@@ -598,7 +743,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         };
         if path.value.as_str().is_empty() {
             let msg = "import path cannot be empty";
-            self.dcx().err(msg).span(path.span).emit();
+            self.emit_recovered(self.dcx().err(msg).span(path.span));
         }
         self.expect_semi()?;
         Ok(ImportDirective { path, items })
@@ -622,7 +767,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         } else {
             Some(self.parse_type()?)
         };
-        let global = self.eat_keyword(sym::global);
+        let global = self.eat_soft_keyword(sym::global);
         self.expect_semi()?;
         Ok(UsingDirective { list, ty, global })
     }
@@ -721,23 +866,23 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
                 let transient_allowed = flags.contains(VarFlags::TRANSIENT);
                 if transient && !transient_allowed {
                     let msg = "`transient` data location is not allowed here";
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                    self.emit_recovered(self.dcx().err(msg).span(self.prev_token.span));
                 } else if !(transient || flags.contains(VarFlags::DATALOC)) {
                     let msg = "data locations are not allowed here";
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                    self.emit_recovered(self.dcx().err(msg).span(self.prev_token.span));
                 } else if data_location.is_some() {
                     let msg = "data location already specified";
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                    self.emit_recovered(self.dcx().err(msg).span(self.prev_token.span));
                 } else {
                     data_location = Some(s);
                 }
             } else if let Some(v) = self.parse_visibility() {
                 if !flags.contains(VarFlags::from_visibility(v)) {
                     let msg = visibility_error(v, flags.visibilities());
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                    self.emit_recovered(self.dcx().err(msg).span(self.prev_token.span));
                 } else if visibility.is_some() {
                     let msg = "visibility already specified";
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                    self.emit_recovered(self.dcx().err(msg).span(self.prev_token.span));
                 } else {
                     visibility = Some(v);
                 }
@@ -745,34 +890,34 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
                 // `CONSTANT_VAR` is special cased later.
                 if flags != VarFlags::CONSTANT_VAR && !flags.contains(VarFlags::from_varmut(m)) {
                     let msg = varmut_error(m, flags.varmuts());
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                    self.emit_recovered(self.dcx().err(msg).span(self.prev_token.span));
                 } else if mutability.is_some() {
                     let msg = "mutability already specified";
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                    self.emit_recovered(self.dcx().err(msg).span(self.prev_token.span));
                 } else {
                     mutability = Some(m);
                 }
             } else if self.eat_keyword(kw::Indexed) {
                 if !flags.contains(VarFlags::INDEXED) {
                     let msg = "`indexed` is not allowed here";
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                    self.emit_recovered(self.dcx().err(msg).span(self.prev_token.span));
                 } else if indexed {
                     let msg = "`indexed` already specified";
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                    self.emit_recovered(self.dcx().err(msg).span(self.prev_token.span));
                 } else {
                     indexed = true;
                 }
             } else if self.eat_keyword(kw::Virtual) {
                 let msg = "`virtual` is not allowed here";
-                self.dcx().err(msg).span(self.prev_token.span).emit();
+                self.emit_recovered(self.dcx().err(msg).span(self.prev_token.span));
             } else if self.eat_keyword(kw::Override) {
                 let o = self.parse_override()?;
                 if !flags.contains(VarFlags::OVERRIDE) {
                     let msg = "`override` is not allowed here";
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                    self.emit_recovered(self.dcx().err(msg).span(self.prev_token.span));
                 } else if override_.is_some() {
                     let msg = "override already specified";
-                    self.dcx().err(msg).span(self.prev_token.span).emit();
+                    self.emit_recovered(self.dcx().err(msg).span(self.prev_token.span));
                 } else {
                     override_ = Some(o);
                 }
@@ -808,11 +953,11 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
 
         if mutability == Some(VarMut::Constant) && initializer.is_none() {
             let msg = "constant variable must be initialized";
-            self.dcx().err(msg).span(span).emit();
+            self.emit_recovered(self.dcx().err(msg).span(span));
         }
         if flags == VarFlags::CONSTANT_VAR && mutability != Some(VarMut::Constant) {
             let msg = "only constant variables are allowed at file level";
-            self.dcx().err(msg).span(span).emit();
+            self.emit_recovered(self.dcx().err(msg).span(span));
         }
 
         Ok(VariableDefinition {
@@ -915,13 +1060,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
             Some(DataLocation::Memory)
         } else if self.eat_keyword(kw::Calldata) {
             Some(DataLocation::Calldata)
-        } else if self.check_keyword(sym::transient)
-            && !matches!(
-                self.look_ahead(1).kind,
-                TokenKind::Eq | TokenKind::Semi | TokenKind::CloseDelim(_) | TokenKind::Comma
-            )
-        {
-            self.bump(); // `transient`
+        } else if self.eat_soft_keyword(sym::transient) {
             Some(DataLocation::Transient)
         } else {
             None
@@ -1269,6 +1408,55 @@ mod tests {
         .unwrap();
     }
 
+    fn assert_version_intersects(tests: &[(&str, &str, bool)]) {
+        let sess = Session::builder().with_test_emitter().build();
+        sess.enter(|| -> Result {
+            for (i, &(a_s, b_s, res)) in tests.iter().enumerate() {
+                let arena = Arena::new();
+                let parse = |name: String, src: &str| -> Result<_> {
+                    let mut parser = Parser::from_source_code(
+                        &sess,
+                        &arena,
+                        FileName::Custom(name),
+                        src.to_string(),
+                    )?;
+                    Ok(parser.parse_semver_req().map_err(|e| e.emit()).unwrap())
+                };
+                let a = parse(format!("{i}a"), a_s)?;
+                let b = parse(format!("{i}b"), b_s)?;
+                sess.dcx.has_errors().unwrap();
+                assert_eq!(a.intersects(&b), res, "a={a_s:?}, b={b_s:?}");
+                assert_eq!(b.intersects(&a), res, "a={b_s:?}, b={a_s:?}");
+            }
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn semver_intersects() {
+        assert_version_intersects(&[
+            ("0.8.1", "0.8.1", true),
+            ("0.8.1", "0.8.2", false),
+            ("0.8.1", "=0.8", true),
+            ("^0.8.0", "^0.8.20", true),
+            ("^0.8.0", "^0.7.0", false),
+            ("^0.8.0", ">=0.8.5", true),
+            ("^0.8.0", "<0.8.0", false),
+            (">0.8.0 <0.9.0", "<0.8.5", true),
+            (">0.8.0", "<0.8.1", false),
+            (">0.8", "<0.9.1", true),
+            ("<0.8", ">=0.8.0", false),
+            ("~0.8.1", "0.8.9", true),
+            ("~0.8.1", "0.9.0", false),
+            ("0.4 - 0.5", "0.5.16", true),
+            ("0.4 - 0.5", "0.6.0", false),
+            ("0.6.0 || 0.8.1", "^0.8.0", true),
+            ("0.6.0 || 0.7.1", "^0.8.0", false),
+            ("*", "0.8.1", true),
+        ]);
+    }
+
     #[test]
     fn semver_matches() {
         assert_version_matches(&[
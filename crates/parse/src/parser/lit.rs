@@ -40,7 +40,9 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
                 _ => {
                     *opt = None;
                     let msg = "sub-denominations are only allowed on number and rational literals";
-                    self.dcx().err(msg).span(lit.span.to(self.prev_token.span)).emit();
+                    self.emit_recovered(
+                        self.dcx().err(msg).span(lit.span.to(self.prev_token.span)),
+                    );
                 }
             }
         }
@@ -77,7 +79,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
     pub(super) fn expect_no_subdenomination(&mut self) {
         if let Some(_sub) = self.parse_subdenomination() {
             let span = self.prev_token.span;
-            self.dcx().err("subdenominations aren't allowed here").span(span).emit();
+            self.emit_recovered(self.dcx().err("subdenominations aren't allowed here").span(span));
         }
     }
 
@@ -6,9 +6,10 @@ use solar_ast::{
 };
 use solar_data_structures::BumpExt;
 use solar_interface::{
-    diagnostics::DiagCtxt,
+    diagnostics::{DiagCtxt, DiagnosticBuilder, EmissionGuarantee},
+    kw,
     source_map::{FileName, SourceFile},
-    Ident, Result, Session, Span, Symbol,
+    sym, Ident, Result, Session, Span, Symbol,
 };
 use std::{
     fmt::{self, Write},
@@ -37,11 +38,17 @@ pub struct Parser<'sess, 'ast> {
     expected_tokens: Vec<ExpectedToken>,
     /// The span of the last unexpected token.
     last_unexpected_token_span: Option<Span>,
+    /// The spans at which the parser emitted an error and recovered. See
+    /// [`recovery_points`](Self::recovery_points).
+    recovery_points: Vec<Span>,
 
     /// Whether the parser is in Yul mode.
     ///
     /// Currently, this can only happen when parsing a Yul "assembly" block.
     in_yul: bool,
+    /// Whether the parser is in a Yul object, where the object builtins like `datasize` are
+    /// available.
+    in_yul_object: bool,
     /// Whether the parser is currently parsing a contract block.
     in_contract: bool,
 
@@ -137,7 +144,9 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
             prev_token: Token::DUMMY,
             expected_tokens: Vec::with_capacity(8),
             last_unexpected_token_span: None,
+            recovery_points: Vec::new(),
             in_yul: false,
+            in_yul_object: false,
             in_contract: false,
             tokens: tokens.into_iter(),
         };
@@ -196,6 +205,39 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         &self.sess.dcx
     }
 
+    /// Returns the spans at which the parser emitted an error and continued parsing, in the order
+    /// in which they were encountered.
+    ///
+    /// Errors that abort parsing are returned as [`PErr`] instead, and are not included.
+    #[inline]
+    pub fn recovery_points(&self) -> &[Span] {
+        &self.recovery_points
+    }
+
+    /// Takes the recovery points out of the parser. See
+    /// [`recovery_points`](Self::recovery_points).
+    #[inline]
+    pub fn take_recovery_points(&mut self) -> Vec<Span> {
+        std::mem::take(&mut self.recovery_points)
+    }
+
+    /// Emits the given diagnostic and, if it is an error, records its primary span as a recovery
+    /// point.
+    ///
+    /// All errors after which parsing continues must be emitted through this function.
+    #[track_caller]
+    fn emit_recovered<G: EmissionGuarantee>(
+        &mut self,
+        diag: DiagnosticBuilder<'sess, G>,
+    ) -> G::EmitResult {
+        if diag.is_error() {
+            if let Some(span) = diag.span.primary_span() {
+                self.recovery_points.push(span);
+            }
+        }
+        diag.emit()
+    }
+
     /// Allocates an object on the AST arena.
     pub fn alloc<T>(&self, value: T) -> Box<'ast, T> {
         self.arena.alloc(value)
@@ -471,6 +513,51 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         }
     }
 
+    /// If the next token is the given soft keyword in a position where it is a keyword, rather
+    /// than an identifier, returns `true` without eating it.
+    /// An expectation is also added for diagnostics purposes.
+    ///
+    /// See [`kw::SOFT_KEYWORDS`].
+    #[must_use]
+    fn check_soft_keyword(&mut self, kw: Symbol) -> bool {
+        debug_assert!(kw.is_soft_keyword(), "`{kw}` is not a soft keyword");
+        if !self.check_keyword(kw) {
+            return false;
+        }
+        let next = self.look_ahead(1);
+        match kw {
+            // `error E(...);`
+            sym::error => {
+                next.is_ident() && self.look_ahead(2).is_open_delim(Delimiter::Parenthesis)
+            }
+            // `revert E(...);`
+            kw::Revert => next.is_ident(),
+            // `uint256 transient x;`, but not the variable `uint256 transient;`.
+            sym::transient => !matches!(
+                next.kind,
+                TokenKind::Eq | TokenKind::Semi | TokenKind::CloseDelim(_) | TokenKind::Comma
+            ),
+            // Only checked after a complete `import ... from` or `using ... for` prefix.
+            sym::from | sym::global => true,
+            _ => unreachable!("missing position for soft keyword `{kw}`"),
+        }
+    }
+
+    /// If the next token is the given soft keyword in a position where it is a keyword, eats it
+    /// and returns `true`. Otherwise, returns `false`.
+    /// An expectation is also added for diagnostics purposes.
+    ///
+    /// See [`check_soft_keyword`](Self::check_soft_keyword).
+    #[must_use]
+    fn eat_soft_keyword(&mut self, kw: Symbol) -> bool {
+        if self.check_soft_keyword(kw) {
+            self.bump();
+            true
+        } else {
+            false
+        }
+    }
+
     /// If the given word is not a keyword, signals an error.
     /// If the next token is not the given word, signals an error.
     /// Otherwise, eats it.
@@ -483,6 +570,19 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         }
     }
 
+    /// If the next token is not the given soft keyword in a position where it is a keyword,
+    /// signals an error. Otherwise, eats it.
+    ///
+    /// See [`check_soft_keyword`](Self::check_soft_keyword).
+    #[track_caller]
+    fn expect_soft_keyword(&mut self, kw: Symbol) -> PResult<'sess, ()> {
+        if !self.eat_soft_keyword(kw) {
+            self.unexpected()
+        } else {
+            Ok(())
+        }
+    }
+
     #[must_use]
     fn check_ident(&mut self) -> bool {
         self.check_or_expected(self.token.is_ident(), ExpectedToken::Ident)
@@ -699,12 +799,13 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         if let Some(sep_kind) = &sep.sep {
             if sep.trailing_sep_required && !trailing {
                 if let Err(e) = self.expect(sep_kind) {
-                    e.emit();
+                    self.emit_recovered(e);
                 }
             }
             if !sep.trailing_sep_allowed && trailing {
                 let msg = format!("trailing `{sep_kind}` separator is not allowed");
-                self.dcx().err(msg).span(self.prev_token.span).emit();
+                let err = self.dcx().err(msg).span(self.prev_token.span);
+                self.emit_recovered(err);
             }
         }
 
@@ -773,6 +874,14 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         self.in_yul = old;
         res
     }
+
+    /// Runs `f` with the parser in a Yul object context.
+    fn in_yul_object<R>(&mut self, f: impl FnOnce(&mut Self) -> R) -> R {
+        let old = std::mem::replace(&mut self.in_yul_object, true);
+        let res = f(self);
+        self.in_yul_object = old;
+        res
+    }
 }
 
 /// Common parsing methods.
@@ -860,7 +969,8 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
     fn parse_yul_path_ident(&mut self) -> PResult<'sess, Ident> {
         let ident = self.ident_or_err(true)?;
         if !ident.is_yul_evm_builtin() && ident.is_reserved(true) {
-            self.expected_ident_found_err().emit();
+            let err = self.expected_ident_found_err();
+            self.emit_recovered(err);
         }
         self.bump();
         Ok(ident)
@@ -922,7 +1032,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         if ident.is_reserved(self.in_yul) {
             let err = self.expected_ident_found_err();
             if recover {
-                err.emit();
+                self.emit_recovered(err);
             } else {
                 return Err(err);
             }
@@ -964,7 +1074,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
 
         if recover {
             if let Some(ident) = recovered_ident {
-                err.emit();
+                self.emit_recovered(err);
                 return Ok(ident);
             }
         }
@@ -999,6 +1109,7 @@ fn or_list<T: fmt::Display>(list: &[T]) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use solar_interface::{BytePos, ColorChoice};
 
     #[test]
     fn test_or_list() {
@@ -1013,4 +1124,209 @@ mod tests {
             assert_eq!(or_list(tokens), expected, "{tokens:?}");
         }
     }
+
+    #[test]
+    fn recovery_points() {
+        let src = "contract C {\npragma solidity ^0.8.0;\n}\nenum E { A, B, }\nenum F { A, for }\n";
+        let sess = Session::builder().with_buffer_emitter(ColorChoice::Never).build();
+        sess.enter(|| {
+            let arena = ast::Arena::new();
+            let file =
+                sess.source_map().new_dummy_source_file("test.sol".into(), src.into()).unwrap();
+            let mut parser = Parser::from_source_file(&sess, &arena, &file);
+            let unit = parser.parse_file().map_err(|e| e.emit()).unwrap();
+            assert_eq!(unit.items.len(), 3);
+            assert!(sess.dcx.has_errors().is_err());
+
+            let span = |offset: usize, len: usize| {
+                let lo = file.start_pos + BytePos(offset as u32);
+                Span::new(lo, lo + BytePos(len as u32))
+            };
+            let pragma = "pragma solidity ^0.8.0;";
+            let expected = [
+                span(src.find(pragma).unwrap(), pragma.len()),
+                span(src.find("B, }").unwrap() + 1, 1),
+                span(src.find("for").unwrap(), 3),
+            ];
+            assert_eq!(parser.recovery_points(), expected);
+        });
+    }
+
+    #[test]
+    fn recovery_points_nested() {
+        let src = "contract C {\n    function f() public public {\n        byte b;\n        \
+                   assembly { a.b() }\n    }\n    mapping(uint[] => uint) m;\n}\n";
+        let sess = Session::builder().with_buffer_emitter(ColorChoice::Never).build();
+        sess.enter(|| {
+            let arena = ast::Arena::new();
+            let file =
+                sess.source_map().new_dummy_source_file("test.sol".into(), src.into()).unwrap();
+            let mut parser = Parser::from_source_file(&sess, &arena, &file);
+            let unit = parser.parse_file().map_err(|e| e.emit()).unwrap();
+            assert_eq!(unit.items.len(), 1);
+            assert!(sess.dcx.has_errors().is_err());
+
+            let span = |offset: usize, len: usize| {
+                let lo = file.start_pos + BytePos(offset as u32);
+                Span::new(lo, lo + BytePos(len as u32))
+            };
+            // The `byte` deprecation warning is not a recovery point.
+            let expected = [
+                span(src.find("public public").unwrap() + 7, 6),
+                span(src.find("a.b").unwrap(), 3),
+                span(src.find("uint[]").unwrap(), 6),
+            ];
+            assert_eq!(parser.recovery_points(), expected);
+        });
+    }
+
+    #[test]
+    fn parse_file_partial() {
+        let src = "contract A {}\nenum E { X }\n\
+                   contract B { function f(uint x uint y) {} }\ncontract C {}\n";
+        let sess = Session::builder().with_buffer_emitter(ColorChoice::Never).build();
+        sess.enter(|| {
+            let arena = ast::Arena::new();
+            let file =
+                sess.source_map().new_dummy_source_file("test.sol".into(), src.into()).unwrap();
+            let mut parser = Parser::from_source_file(&sess, &arena, &file);
+            let (unit, err) = parser.parse_file_partial();
+            let names = unit.items.iter().map(|item| item.name().unwrap().to_string());
+            assert_eq!(names.collect::<Vec<_>>(), ["A", "E"]);
+            err.expect("expected a parse error").emit();
+            assert!(sess.dcx.has_errors().is_err());
+
+            let mut parser = Parser::from_source_file(&sess, &arena, &file);
+            assert!(parser.parse_file().map_err(|e| e.emit()).is_err());
+        });
+    }
+
+    #[test]
+    fn span_table() {
+        let src = "contract C { function f(uint x) public { \
+                   uint y = x + 1; if (y > 2) { return; } } }";
+        let sess = Session::builder().with_buffer_emitter(ColorChoice::Never).build();
+        sess.enter(|| {
+            let arena = ast::Arena::new();
+            let file =
+                sess.source_map().new_dummy_source_file("test.sol".into(), src.into()).unwrap();
+            let mut parser = Parser::from_source_file(&sess, &arena, &file);
+            let unit = parser.parse_file().map_err(|e| e.emit()).unwrap();
+
+            let table = ast::SpanTable::new(&unit);
+            let snippet = |span| sess.source_map().span_to_snippet(span).unwrap();
+            let stmts = table.stmts().map(|(_, span)| snippet(span)).collect::<Vec<_>>();
+            let expected = ["uint y = x + 1;", "if (y > 2) { return; }", "{ return; }", "return;"];
+            assert_eq!(stmts, expected);
+            let exprs = table.exprs().map(|(_, span)| snippet(span)).collect::<Vec<_>>();
+            assert_eq!(exprs, ["x + 1", "x", "1", "y > 2", "y", "2"]);
+
+            let (first, entry) = table.iter().next().unwrap();
+            assert_eq!(table.get(first), Some(entry));
+            assert_eq!(entry.kind, ast::NodeKind::Stmt);
+            assert_eq!(table.len(), stmts.len() + exprs.len());
+        });
+    }
+
+    #[test]
+    fn span_table_overlapping() {
+        let src = "contract C { function f(uint x) public returns (uint) { \
+                   uint y = x + 1; if (y > 2) { return y * (x - 1); } while (x < y) { x++; } \
+                   return f(y) + g[x][y]; } }";
+        let sess = Session::builder().with_buffer_emitter(ColorChoice::Never).build();
+        sess.enter(|| {
+            let arena = ast::Arena::new();
+            let file =
+                sess.source_map().new_dummy_source_file("test.sol".into(), src.into()).unwrap();
+            let mut parser = Parser::from_source_file(&sess, &arena, &file);
+            let unit = parser.parse_file().map_err(|e| e.emit()).unwrap();
+
+            let table = ast::SpanTable::new(&unit);
+            assert!(table.len() > 20);
+            let len = src.len() as u32;
+            let pos = |i| file.start_pos + BytePos(i);
+            for lo in 0..=len {
+                for hi in lo..=len {
+                    let span = Span::new(pos(lo), pos(hi));
+                    let mut expected = table
+                        .iter()
+                        .filter(|(_, entry)| entry.span.overlaps(span))
+                        .map(|(id, _)| id)
+                        .collect::<Vec<_>>();
+                    expected.sort_by_key(|&id| table.get(id).unwrap().span.lo());
+                    assert_eq!(table.overlapping(span), expected, "{lo}..{hi}");
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn ast_eq() {
+        let srcs = [
+            "contract C { function f(uint x) public returns (uint) { return x + 1; } }",
+            "// comment\ncontract C {\n    /// docs\n    function f(uint x)\n        public\n        \
+             returns (uint)\n    {\n        return x+1;\n    }\n}\n",
+            "contract C { function f(uint y) public returns (uint) { return y + 1; } }",
+        ];
+        let sess = Session::builder().with_buffer_emitter(ColorChoice::Never).build();
+        sess.enter(|| {
+            let arena = ast::Arena::new();
+            let units = srcs.map(|src| {
+                let file =
+                    sess.source_map().new_dummy_source_file("test.sol".into(), src.into()).unwrap();
+                let mut parser = Parser::from_source_file(&sess, &arena, &file);
+                parser.parse_file().map_err(|e| e.emit()).unwrap()
+            });
+            assert!(ast::ast_eq(&units[0], &units[1]));
+            assert!(!ast::ast_eq(&units[0], &units[2]));
+            let renames = [(Symbol::intern("x"), Symbol::intern("y"))];
+            assert!(ast::ast_eq_renamed(&units[0], &units[2], &renames));
+            assert!(!ast::ast_eq_renamed(&units[0], &units[1], &renames));
+        });
+    }
+
+    #[test]
+    fn ast_builder() {
+        let src = "contract C {
+            function clamp(uint256 x, uint256 max) public pure returns (uint256) {
+                if (x > max) {
+                    x = max;
+                }
+                return x;
+            }
+        }";
+        let sess = Session::builder().with_buffer_emitter(ColorChoice::Never).build();
+        sess.enter(|| {
+            let arena = ast::Arena::new();
+            let b = ast::AstBuilder::new(&arena);
+            let uint256 =
+                || b.elementary_ty(ast::ElementaryType::UInt(ast::TypeSize::new_int_bits(256)));
+            let header = ast::FunctionHeader {
+                name: Some(b.ident("clamp")),
+                parameters: b
+                    .alloc_vec(vec![b.var(uint256(), Some("x")), b.var(uint256(), Some("max"))]),
+                visibility: Some(ast::Visibility::Public),
+                state_mutability: ast::StateMutability::Pure,
+                returns: b.alloc_vec(vec![b.var(uint256(), None)]),
+                ..Default::default()
+            };
+            let body = vec![
+                b.stmt_if(
+                    b.expr_binary(b.expr_ident("x"), ast::BinOpKind::Gt, b.expr_ident("max")),
+                    vec![b.stmt_expr(b.expr_assign(b.expr_ident("x"), None, b.expr_ident("max")))],
+                    None,
+                ),
+                b.stmt_return(Some(b.expr_ident("x"))),
+            ];
+            let function = b.item_function(ast::FunctionKind::Function, header, Some(body));
+            let contract = b.item_contract(ast::ContractKind::Contract, "C", vec![function]);
+            let built = b.source_unit(vec![contract]);
+
+            let file =
+                sess.source_map().new_dummy_source_file("test.sol".into(), src.into()).unwrap();
+            let mut parser = Parser::from_source_file(&sess, &arena, &file);
+            let parsed = parser.parse_file().map_err(|e| e.emit()).unwrap();
+            assert!(ast::ast_eq(&built, &parsed), "{built:#?}\n{parsed:#?}");
+        });
+    }
 }
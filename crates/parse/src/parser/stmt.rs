@@ -56,8 +56,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
             self.parse_stmt_assembly().map(StmtKind::Assembly)
         } else if self.eat_keyword(kw::Emit) {
             self.parse_path_call().map(|(path, params)| StmtKind::Emit(path, params))
-        } else if self.check_keyword(kw::Revert) && self.look_ahead(1).is_ident() {
-            self.bump(); // `revert`
+        } else if self.eat_soft_keyword(kw::Revert) {
             self.parse_path_call().map(|(path, params)| StmtKind::Revert(path, params))
         } else if self.check_keyword(sym::underscore) && self.look_ahead(1).kind == TokenKind::Semi
         {
@@ -142,6 +141,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         let mut catch = SmallVec::<[_; 4]>::new();
         self.expect_keyword(kw::Catch)?;
         loop {
+            let lo = self.prev_token.span;
             let name = self.parse_ident_opt()?;
             let args = if self.check(&TokenKind::OpenDelim(Delimiter::Parenthesis)) {
                 self.parse_parameter_list(false, VarFlags::FUNCTION)?
@@ -149,7 +149,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
                 Default::default()
             };
             let block = self.parse_block()?;
-            catch.push(CatchClause { name, args, block });
+            catch.push(CatchClause { span: lo.to(self.prev_token.span), name, args, block });
             if !self.eat_keyword(kw::Catch) {
                 break;
             }
@@ -340,7 +340,8 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
             while self.eat(&TokenKind::Dot) {
                 let id = self.ident_or_err(true)?;
                 if id.name != kw::Address && id.is_reserved(self.in_yul) {
-                    self.expected_ident_found_err().emit();
+                    let err = self.expected_ident_found_err();
+                    self.emit_recovered(err);
                 }
                 self.bump(); // `id`
                 path.push(IapKind::Member(id));
@@ -407,7 +408,7 @@ impl<'ast> IndexAccessedPath<'ast> {
                 })
                 .take(self.n_idents);
             let path = PathSlice::from_mut_slice(parser.arena.alloc_from_iter(path));
-            Type { span: path.span(), kind: TypeKind::Custom(path) }
+            Type { span: path.span(), kind: parser.path_ty_kind(path) }
         };
 
         for index in path.skip(self.n_idents - 1) {
@@ -416,7 +417,7 @@ impl<'ast> IndexAccessedPath<'ast> {
                 IndexKind::Index(expr) => expr,
                 IndexKind::Range(l, r) => {
                     let msg = "expected array length, got range expression";
-                    parser.dcx().err(msg).span(span).emit();
+                    parser.emit_recovered(parser.dcx().err(msg).span(span));
                     l.or(r)
                 }
             };
@@ -1,7 +1,10 @@
 use super::item::FunctionFlags;
 use crate::{PResult, Parser};
 use solar_ast::{ast::*, token::*};
-use solar_interface::kw;
+use solar_interface::{
+    diagnostics::{Applicability, Level},
+    kw,
+};
 use std::{fmt, ops::RangeInclusive};
 
 impl<'sess, 'ast> Parser<'sess, 'ast> {
@@ -55,18 +58,49 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         } else if self.eat_keyword(kw::Mapping) {
             self.parse_mapping_type().map(|x| TypeKind::Mapping(self.alloc(x)))
         } else if self.check_path() {
-            self.parse_path().map(TypeKind::Custom)
+            self.parse_path().map(|path| self.path_ty_kind(path))
         } else {
             self.unexpected()
         }
     }
 
+    /// Returns the kind of a type referred to by the given path.
+    ///
+    /// Fixed-size byte arrays with an invalid size, like `bytes33`, are lexed as identifiers, so
+    /// they are reported here instead of failing to resolve later.
+    pub(super) fn path_ty_kind(&mut self, path: AstPath<'ast>) -> TypeKind<'ast> {
+        let Some(&ident) = path.get_ident() else { return TypeKind::Custom(path) };
+        let Some(size) = ident.as_str().strip_prefix("bytes") else {
+            return TypeKind::Custom(path);
+        };
+        // Leading zeros make the name a plain identifier, like `bytes01`.
+        let is_size = !size.is_empty()
+            && size.bytes().all(|b| b.is_ascii_digit())
+            && (size == "0" || !size.starts_with('0'));
+        if !is_size || parse_fb_size(size).is_ok() {
+            return TypeKind::Custom(path);
+        }
+
+        let mut diag = self
+            .dcx()
+            .err(format!("invalid fixed-size byte array type `{ident}`"))
+            .span(ident.span)
+            .note("fixed-size byte arrays range from `bytes1` to `bytes32`");
+        if size != "0" {
+            diag = diag.help("use `bytes` for byte arrays longer than 32 bytes");
+        }
+        self.emit_recovered(diag);
+        // Recover with the closest valid size.
+        let bytes = if size == "0" { 1 } else { 32 };
+        TypeKind::Elementary(ElementaryType::FixedBytes(TypeSize::new_fb_bytes(bytes)))
+    }
+
     /// Parses an elementary type.
     ///
     /// Must be used after checking that the next token is an elementary type.
     pub(super) fn parse_elementary_type(&mut self) -> PResult<'sess, ElementaryType> {
         let id = self.parse_ident_any()?;
-        debug_assert!(id.is_elementary_type());
+        debug_assert!(id.is_elementary_type() || id.name == kw::Byte);
         let mut ty = match id.name {
             kw::Address => ElementaryType::Address(false),
             kw::Bool => ElementaryType::Bool,
@@ -88,6 +122,32 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
                 let bytes = s.as_u32() - kw::Bytes1.as_u32() + 1;
                 ElementaryType::FixedBytes(TypeSize::new(bytes as u8).unwrap())
             }
+            kw::Byte => {
+                // Only reject `byte` when the rules of solc 0.8.0 or later are explicitly asked for.
+                let (level, msg, note) =
+                    if self.sess.solc_compat.is_some_and(|v| !v.has_byte_alias()) {
+                        (
+                            Level::Error,
+                            "`byte` has been removed in solc 0.8.0",
+                            "`byte` was a deprecated alias for `bytes1`",
+                        )
+                    } else {
+                        (
+                            Level::Warning,
+                            "`byte` is a deprecated alias for `bytes1`",
+                            "`byte` has been removed in solc 0.8.0",
+                        )
+                    };
+                let diag =
+                    self.dcx().diag::<()>(level, msg).span(id.span).note(note).span_suggestion(
+                        id.span,
+                        "use `bytes1` instead",
+                        "bytes1",
+                        Applicability::MachineApplicable,
+                    );
+                self.emit_recovered(diag);
+                ElementaryType::FixedBytes(TypeSize::new_fb_bytes(1))
+            }
             s => unreachable!("unexpected elementary type: {s}"),
         };
 
@@ -101,7 +161,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
                 } else {
                     "only address types can have state mutability"
                 };
-                self.dcx().err(msg).span(id.span.to(self.prev_token.span)).emit();
+                self.emit_recovered(self.dcx().err(msg).span(id.span.to(self.prev_token.span)));
             }
         }
 
@@ -122,7 +182,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         if !key.is_elementary() && !key.is_custom() {
             let msg =
                 "only elementary types or used-defined types can be used as key types in mappings";
-            self.dcx().err(msg).span(key.span).emit();
+            self.emit_recovered(self.dcx().err(msg).span(key.span));
         }
         let key_name = self.parse_ident_opt()?;
 
@@ -181,7 +241,6 @@ fn parse_fixed_type(original: &str) -> Result<Option<ElementaryType>, ParseTySiz
     Ok(None)
 }
 
-#[allow(dead_code)]
 fn parse_fb_size(s: &str) -> Result<TypeSize, ParseTySizeError> {
     parse_ty_size_u8(s, 1..=32, false).map(|x| TypeSize::new(x).unwrap())
 }
@@ -19,7 +19,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
             self.parse_yul_object(docs)
         } else {
             let lo = self.token.span;
-            self.parse_yul_block().map(|code| {
+            self.in_yul_object(Self::parse_yul_block).map(|code| {
                 let span = lo.to(self.prev_token.span);
                 let name = StrLit { span, value: sym::object };
                 let code = CodeBlock { span, code };
@@ -34,6 +34,10 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
     ///
     /// Reference: <https://docs.soliditylang.org/en/latest/yul.html#specification-of-yul-object>
     pub fn parse_yul_object(&mut self, docs: DocComments<'ast>) -> PResult<'sess, Object<'ast>> {
+        self.in_yul_object(|this| this.parse_yul_object_inner(docs))
+    }
+
+    fn parse_yul_object_inner(&mut self, docs: DocComments<'ast>) -> PResult<'sess, Object<'ast>> {
         let lo = self.token.span;
         self.expect_keyword(sym::object)?;
         let name = self.parse_str_lit()?;
@@ -214,7 +218,7 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
         if branches.is_empty() {
             let span = lo.to(self.prev_token.span);
             if default_case.is_none() {
-                self.dcx().err("`switch` statement has no cases").span(span).emit();
+                self.emit_recovered(self.dcx().err("`switch` statement has no cases").span(span));
             } else {
                 self.dcx()
                     .warn("`switch` statement has only a default case")
@@ -264,17 +268,31 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
     /// Parses a Yul function call expression with the given name.
     fn parse_yul_expr_call_with(&mut self, name: Ident) -> PResult<'sess, ExprCall<'ast>> {
         if !name.is_yul_evm_builtin() && name.is_reserved(true) {
-            self.expected_ident_found_other(name.into(), false).unwrap_err().emit();
+            let err = self.expected_ident_found_other(name.into(), false).unwrap_err();
+            self.emit_recovered(err);
         }
         let arguments = self.parse_paren_comma_seq(true, Self::parse_yul_expr)?;
-        Ok(ExprCall { name, arguments })
+        let call = ExprCall { name, arguments };
+        // Outside of Yul objects, like in inline assembly, these are regular identifiers.
+        if let Some(builtin) = call.object_builtin().filter(|_| self.in_yul_object) {
+            let (expected, found) = (builtin.arity(), call.arguments.len());
+            if expected != found {
+                let s = if expected == 1 { "" } else { "s" };
+                let msg = format!("`{name}` expects {expected} argument{s}, found {found}");
+                let span = name.span.to(self.prev_token.span);
+                self.emit_recovered(self.dcx().err(msg).span(span));
+            }
+        }
+        Ok(call)
     }
 
     /// Expects a single identifier path and returns the identifier.
     #[track_caller]
     fn expect_single_ident_path(&mut self, path: AstPath<'_>) -> Ident {
         if path.segments().len() > 1 {
-            self.dcx().err("fully-qualified paths aren't allowed here").span(path.span()).emit();
+            self.emit_recovered(
+                self.dcx().err("fully-qualified paths aren't allowed here").span(path.span()),
+            );
         }
         *path.last()
     }
@@ -284,11 +302,13 @@ impl<'sess, 'ast> Parser<'sess, 'ast> {
     fn check_valid_path(&mut self, path: &PathSlice) {
         let first = path.first();
         if first.is_reserved(true) {
-            self.expected_ident_found_other((*first).into(), false).unwrap_err().emit();
+            let err = self.expected_ident_found_other((*first).into(), false).unwrap_err();
+            self.emit_recovered(err);
         }
         for ident in &path.segments()[1..] {
             if !ident.is_yul_evm_builtin() && ident.is_reserved(true) {
-                self.expected_ident_found_other((*ident).into(), false).unwrap_err().emit();
+                let err = self.expected_ident_found_other((*ident).into(), false).unwrap_err();
+                self.emit_recovered(err);
             }
         }
     }
@@ -204,3 +204,49 @@ impl ContractLinearizer {
 // type List<T> = std::collections::LinkedList<T>;
 // #[cfg(not(feature = "nightly"))]
 type List<T> = std::collections::VecDeque<T>;
+
+#[cfg(test)]
+mod tests {
+    use crate::{hir, ParsingContext};
+    use solar_interface::Session;
+    use std::path::PathBuf;
+    use thread_local::ThreadLocal;
+
+    #[test]
+    fn linearization_order() {
+        let sess = Session::builder().with_test_emitter().build();
+        sess.enter(|| {
+            let src = "
+                contract A {}
+                contract B is A {}
+                contract C is A {}
+                contract D is B, C {}
+                contract E is D {}
+            ";
+            let file = sess
+                .source_map()
+                .new_dummy_source_file(PathBuf::from("test.sol"), src.to_string())
+                .unwrap();
+            let mut pcx = ParsingContext::new(&sess);
+            pcx.add_file(file);
+            let ast_arenas = ThreadLocal::new();
+            let mut sources = pcx.parse(&ast_arenas);
+            sources.topo_sort();
+            let arena = hir::Arena::new();
+            let (hir, _) = crate::lower(&sess, &sources, &arena).unwrap();
+
+            let names = |ids: &[hir::ContractId]| {
+                ids.iter().map(|&id| hir.contract(id).name.as_str().to_string()).collect::<Vec<_>>()
+            };
+            let id = |name: &str| {
+                hir.contract_ids().find(|&id| hir.contract(id).name.as_str() == name).unwrap()
+            };
+            assert_eq!(names(hir.contract_bases(id("A"))), Vec::<String>::new());
+            assert_eq!(names(hir.contract_bases(id("D"))), ["B", "C"]);
+            assert_eq!(names(hir.contract_linearization(id("A"))), ["A"]);
+            assert_eq!(names(hir.contract_linearization(id("B"))), ["B", "A"]);
+            assert_eq!(names(hir.contract_linearization(id("D"))), ["D", "C", "B", "A"]);
+            assert_eq!(names(hir.contract_linearization(id("E"))), ["E", "D", "C", "B", "A"]);
+        });
+    }
+}
@@ -16,15 +16,16 @@ impl<'ast> super::LoweringContext<'_, 'ast, '_> {
                 file: source.file.clone(),
                 imports: self.arena.alloc_slice_copy(&source.imports),
                 items: &[],
+                usings: &[],
             };
             if let Some(ast) = &source.ast {
                 let mut items = SmallVec::<[_; 16]>::new();
                 self.current_source_id = id;
                 for item in ast.items.iter() {
                     match &item.kind {
-                        ast::ItemKind::Pragma(_)
-                        | ast::ItemKind::Import(_)
-                        | ast::ItemKind::Using(_) => {}
+                        ast::ItemKind::Pragma(_) | ast::ItemKind::Import(_) => {}
+                        // Resolved later.
+                        ast::ItemKind::Using(_) => self.source_usings.push((id, item)),
                         ast::ItemKind::Contract(_)
                         | ast::ItemKind::Function(_)
                         | ast::ItemKind::Variable(_)
@@ -55,6 +56,7 @@ impl<'ast> super::LoweringContext<'_, 'ast, '_> {
 
             // Set later.
             bases: &[],
+            bases_args: &[],
             linearized_bases: &[],
 
             ctor: None,
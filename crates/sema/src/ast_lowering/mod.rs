@@ -55,6 +55,8 @@ struct LoweringContext<'sess, 'ast, 'hir> {
     hir: Hir<'hir>,
     /// Mapping from Hir ItemId to AST Item. Does not include function parameters or bodies.
     hir_to_ast: FxHashMap<hir::ItemId, &'ast ast::Item<'ast>>,
+    /// The file-level `using` directives, resolved in `resolve_symbols`.
+    source_usings: Vec<(hir::SourceId, &'ast ast::Item<'ast>)>,
 
     /// Current source being lowered.
     current_source_id: hir::SourceId,
@@ -73,6 +75,7 @@ impl<'sess, 'hir> LoweringContext<'sess, '_, 'hir> {
             current_source_id: hir::SourceId::MAX,
             current_contract_id: None,
             hir_to_ast: FxHashMap::default(),
+            source_usings: Vec::new(),
             resolver: SymbolResolver::new(&sess.dcx),
         }
     }
@@ -1,13 +1,13 @@
 use crate::{builtins::Builtin, hir, ParsedSources};
-use solar_ast::ast;
+use solar_ast::{ast, visit::Visit};
 use solar_data_structures::{
     index::{Idx, IndexVec},
-    map::{FxIndexMap, IndexEntry},
+    map::{FxHashSet, FxIndexMap, IndexEntry},
     smallvec::SmallVec,
     BumpExt,
 };
 use solar_interface::{
-    diagnostics::{DiagCtxt, ErrorGuaranteed},
+    diagnostics::{DeclaredHere, DiagCtxt, DiagnosticBuilder, ErrorGuaranteed},
     sym, Ident, Session, Span, Symbol,
 };
 use std::{fmt, sync::atomic::AtomicUsize};
@@ -102,6 +102,26 @@ impl super::LoweringContext<'_, '_, '_> {
                     }
                 }
             }
+
+            // Declare the names imported from unresolved imports as errors, so that using them
+            // does not emit further diagnostics.
+            let parsed = &sources[source_id];
+            for &(item_id, guar) in &parsed.unresolved_imports {
+                let import_item = &parsed.ast.as_ref().unwrap().items[item_id];
+                let ast::ItemKind::Import(import) = &import_item.kind else { unreachable!() };
+                let source_scope = &mut self.resolver.source_scopes[source_id];
+                let names = match import.items {
+                    ast::ImportItems::Plain(alias) | ast::ImportItems::Glob(alias) => {
+                        alias.into_iter().collect::<Vec<_>>()
+                    }
+                    ast::ImportItems::Aliases(ref aliases) => {
+                        aliases.iter().map(|&(import, alias)| alias.unwrap_or(import)).collect()
+                    }
+                };
+                for name in names {
+                    let _ = source_scope.declare_res(self.sess, &self.hir, name, Res::Err(guar));
+                }
+            }
         }
     }
 
@@ -181,7 +201,7 @@ impl super::LoweringContext<'_, '_, '_> {
                 let name = &base.name;
                 let Ok(base_id) = self
                     .resolver
-                    .resolve_path_as::<hir::ContractId>(base.name, &scopes, "contract")
+                    .resolve_path_as::<hir::ContractId>(base.name, &scopes, &self.hir, "contract")
                 else {
                     continue;
                 };
@@ -290,6 +310,85 @@ impl super::LoweringContext<'_, '_, '_> {
             self.hir.events[id].parameters = cx.lower_variables(ast_event.parameters);
         }
 
+        let mut usings: IndexVec<hir::SourceId, Vec<_>> =
+            self.hir.sources.iter().map(|_| Vec::new()).collect();
+        for (source_id, ast_item) in std::mem::take(&mut self.source_usings) {
+            let ast::ItemKind::Using(using) = &ast_item.kind else { unreachable!() };
+            let scopes = SymbolResolverScopes::new_in(source_id, None);
+            let list = match using.list {
+                ast::UsingList::Single(path) => {
+                    let Ok(id) = self.resolver.resolve_path_as(path, &scopes, &self.hir, "library")
+                    else {
+                        continue;
+                    };
+                    hir::UsingList::Library(id)
+                }
+                ast::UsingList::Multiple(list) => {
+                    let mut functions = SmallVec::<[_; 8]>::new();
+                    for &(path, operator) in list.iter() {
+                        let Ok(id) = self
+                            .resolver
+                            .resolve_path_as::<hir::ItemId>(path, &scopes, &self.hir, "function")
+                        else {
+                            continue;
+                        };
+                        let hir::ItemId::Function(id) = id else {
+                            let found = self.hir.item(id).description();
+                            self.resolver.report_expected("function", found, path.span());
+                            continue;
+                        };
+                        functions.push(hir::UsingFunction { span: path.span(), id, operator });
+                    }
+                    hir::UsingList::Functions(self.arena.alloc_smallvec(functions))
+                }
+            };
+            let mut cx = mk_resolver!(@scopes scopes);
+            let ty = using.ty.as_ref().map(|ty| cx.lower_type(ty));
+            usings[source_id].push(hir::UsingDirective {
+                span: ast_item.span,
+                list,
+                ty,
+                global: using.global,
+            });
+        }
+        for (source_id, usings) in usings.into_iter_enumerated() {
+            if !usings.is_empty() {
+                self.hir.sources[source_id].usings = self.arena.alloc_vec(usings);
+            }
+        }
+
+        for id in self.hir.contract_ids() {
+            let ast_item = self.hir_to_ast[&hir::ItemId::Contract(id)];
+            let ast::ItemKind::Contract(ast_contract) = &ast_item.kind else { unreachable!() };
+            if ast_contract.bases.is_empty() {
+                continue;
+            }
+
+            // Unresolved bases were already reported in `resolve_base_contracts`.
+            let contract = self.hir.contract(id);
+            let mut scopes = SymbolResolverScopes::new();
+            scopes.source = Some(contract.source);
+            let mut bases = SmallVec::<[_; 8]>::new();
+            for base in ast_contract.bases.iter() {
+                let Ok(decl) = self.resolver.resolve_path(base.name, &scopes) else { continue };
+                let Res::Item(item @ hir::ItemId::Contract(base_id)) = decl.res else { continue };
+                if contract.bases.contains(&base_id) {
+                    bases.push((base, item));
+                }
+            }
+
+            let scopes = SymbolResolverScopes::new_in(contract.source, Some(id));
+            let mut cx = mk_resolver!(@scopes scopes);
+            cx.hir.contracts[id].bases_args =
+                cx.arena.alloc_slice_fill_iter(bases.into_iter().map(|(base, item)| {
+                    hir::Modifier {
+                        span: base.name.span(),
+                        id: item,
+                        args: cx.lower_call_args(&base.arguments),
+                    }
+                }));
+        }
+
         // Resolve constants and state variables.
         let normal_vars = self.hir.variables.len();
         for id in self.hir.variable_ids() {
@@ -311,7 +410,8 @@ impl super::LoweringContext<'_, '_, '_> {
 
             let scopes = SymbolResolverScopes::new_in(func.source, func.contract);
 
-            self.hir.functions[id].modifiers = {
+            // The arguments are lowered later, in the scope of the function parameters.
+            let modifiers = {
                 let mut modifiers = SmallVec::<[_; 8]>::new();
                 for modifier in ast_func.header.modifiers.iter() {
                     let expected = if func.kind.is_constructor() {
@@ -319,7 +419,8 @@ impl super::LoweringContext<'_, '_, '_> {
                     } else {
                         "modifier"
                     };
-                    let Ok(id) = self.resolver.resolve_path_as(modifier.name, &scopes, expected)
+                    let Ok(id) =
+                        self.resolver.resolve_path_as(modifier.name, &scopes, &self.hir, expected)
                     else {
                         continue;
                     };
@@ -339,9 +440,9 @@ impl super::LoweringContext<'_, '_, '_> {
                             continue;
                         }
                     }
-                    modifiers.push(id);
+                    modifiers.push((modifier, id));
                 }
-                self.arena.alloc_smallvec(modifiers)
+                modifiers
             };
 
             let func = self.hir.function(id);
@@ -349,7 +450,8 @@ impl super::LoweringContext<'_, '_, '_> {
                 let mut overrides = SmallVec::<[_; 8]>::new();
                 if let Some(ov) = &ast_func.header.override_ {
                     for path in ov.paths.iter() {
-                        let Ok(id) = self.resolver.resolve_path_as(path, &scopes, "contract")
+                        let Ok(id) =
+                            self.resolver.resolve_path_as(path, &scopes, &self.hir, "contract")
                         else {
                             continue;
                         };
@@ -375,6 +477,14 @@ impl super::LoweringContext<'_, '_, '_> {
             cx.hir.functions[id].returns = cx.arena.alloc_slice_fill_iter(
                 ast_func.header.returns.iter().map(|ret| cx.lower_variable(ret).0),
             );
+            cx.hir.functions[id].modifiers =
+                cx.arena.alloc_slice_fill_iter(modifiers.into_iter().map(|(modifier, item)| {
+                    hir::Modifier {
+                        span: modifier.name.span(),
+                        id: item,
+                        args: cx.lower_call_args(&modifier.arguments),
+                    }
+                }));
             if let Some(body) = &ast_func.body {
                 cx.hir.functions[id].body = Some(cx.lower_stmts(body));
             }
@@ -593,6 +703,9 @@ struct ResolveContext<'sess, 'hir, 'a> {
     resolver: &'a SymbolResolver<'sess>,
     scopes: SymbolResolverScopes,
     next_id: &'a AtomicUsize,
+    /// The variables of the `try` statement clauses whose scope has been exited, along with a
+    /// description of the clause. Used to diagnose references that escape their scope.
+    out_of_scope: Vec<(Ident, &'static str)>,
 }
 
 impl<'sess, 'hir, 'a> ResolveContext<'sess, 'hir, 'a> {
@@ -608,6 +721,7 @@ impl<'sess, 'hir, 'a> ResolveContext<'sess, 'hir, 'a> {
             resolver: &lcx.resolver,
             scopes,
             next_id,
+            out_of_scope: Vec::new(),
         }
     }
 
@@ -630,7 +744,19 @@ impl<'sess, 'hir, 'a> ResolveContext<'sess, 'hir, 'a> {
         &'a self,
         path: &ast::PathSlice,
     ) -> Result<&'a [Declaration], ErrorGuaranteed> {
-        self.resolver.resolve_paths(path, &self.scopes).map_err(self.resolver.emit_resolver_error())
+        self.resolver.resolve_paths(path, &self.scopes).map_err(|e| self.emit_resolver_error(e))
+    }
+
+    fn emit_resolver_error(&self, e: ResolverError) -> ErrorGuaranteed {
+        let mut err = e.to_diag(self.resolver.dcx, self.hir, &self.scopes);
+        if let ResolverErrorKind::Unresolved = e.kind {
+            let var = self.out_of_scope.iter().rev().find(|(var, _)| var.name == e.name.name);
+            if let Some(&(var, clause)) = var {
+                let note = format!("`{var}` is declared here, but is only in scope in {clause}");
+                err = err.span_note(var.span, note);
+            }
+        }
+        err.emit()
     }
 
     fn resolve_path(&self, path: &ast::PathSlice) -> Result<&'hir [Res], ErrorGuaranteed> {
@@ -643,7 +769,7 @@ impl<'sess, 'hir, 'a> ResolveContext<'sess, 'hir, 'a> {
         path: &ast::PathSlice,
         description: &str,
     ) -> Result<T, ErrorGuaranteed> {
-        self.resolver.resolve_path_as(path, &self.scopes, description)
+        self.resolver.resolve_path_as(path, &self.scopes, self.hir, description)
     }
 
     /// Lowers the given statements by first entering a new scope.
@@ -672,10 +798,13 @@ impl<'sess, 'hir, 'a> ResolveContext<'sess, 'hir, 'a> {
                 ),
                 self.lower_expr(expr),
             ),
-            ast::StmtKind::Assembly(_) => hir::StmtKind::Err(
-                // self.dcx().err("assembly is not yet implemented").span(stmt.span).emit(),
-                ErrorGuaranteed::new_unchecked(),
-            ),
+            ast::StmtKind::Assembly(asm) => {
+                self.check_assembly(asm);
+                hir::StmtKind::Err(
+                    // self.dcx().err("assembly is not yet implemented").span(stmt.span).emit(),
+                    ErrorGuaranteed::new_unchecked(),
+                )
+            }
             ast::StmtKind::Block(stmts) => hir::StmtKind::Block(self.lower_block(stmts)),
             ast::StmtKind::UncheckedBlock(stmts) => {
                 hir::StmtKind::UncheckedBlock(self.lower_block(stmts))
@@ -703,24 +832,38 @@ impl<'sess, 'hir, 'a> ResolveContext<'sess, 'hir, 'a> {
                 else_.as_deref().map(|stmt| self.lower_stmt(stmt)),
             ),
             ast::StmtKind::Try(ast::StmtTry { expr, returns, block, catch }) => {
-                hir::StmtKind::Try(self.arena.alloc(hir::StmtTry {
-                    expr: self.lower_expr_full(expr),
-                    returns: self.lower_variables(returns),
-                    block: self.lower_block(block),
-                    catch: self.arena.alloc_slice_fill_iter(catch.iter().map(|catch| {
-                        hir::CatchClause {
-                            name: catch.name,
-                            args: self.lower_variables(catch.args),
-                            block: self.lower_block(catch.block),
-                        }
-                    })),
-                }))
+                let expr = self.lower_expr_full(expr);
+                // The return variables are only in scope in the success block, and the
+                // parameters of a `catch` clause are only in scope in its block.
+                let (returns, block) = self.in_try_clause(returns, "the success block", |this| {
+                    (this.lower_variables(returns), this.lower_block(block))
+                });
+                let catch = self.arena.alloc_slice_fill_iter(catch.iter().map(|catch| {
+                    self.in_try_clause(catch.args, "its `catch` clause", |this| hir::CatchClause {
+                        name: catch.name,
+                        args: this.lower_variables(catch.args),
+                        block: this.lower_block(catch.block),
+                    })
+                }));
+                hir::StmtKind::Try(self.arena.alloc(hir::StmtTry { expr, returns, block, catch }))
             }
             ast::StmtKind::Placeholder => hir::StmtKind::Placeholder,
         };
         hir::Stmt { span: stmt.span, kind }
     }
 
+    /// Lowers a clause of a `try` statement in a new scope, in which `vars` are declared by `f`.
+    fn in_try_clause<T>(
+        &mut self,
+        vars: &[ast::VariableDefinition<'_>],
+        clause: &'static str,
+        f: impl FnOnce(&mut Self) -> T,
+    ) -> T {
+        let t = self.in_scope(f);
+        self.out_of_scope.extend(vars.iter().filter_map(|var| var.name).map(|name| (name, clause)));
+        t
+    }
+
     fn lower_variables(&mut self, vars: &[ast::VariableDefinition<'_>]) -> &'hir [hir::VariableId] {
         self.arena.alloc_slice_fill_iter(vars.iter().map(|var| self.lower_variable(var).0))
     }
@@ -974,6 +1117,191 @@ impl<'sess, 'hir, 'a> ResolveContext<'sess, 'hir, 'a> {
     fn next_id<I: Idx>(&self) -> I {
         I::from_usize(self.next_id.fetch_add(1, std::sync::atomic::Ordering::Relaxed))
     }
+
+    /// Checks the accesses to Solidity variables in an inline assembly block.
+    fn check_assembly(&self, asm: &ast::StmtAssembly<'_>) {
+        let mut yul_decls = YulDeclarations::default();
+        yul_decls.visit_stmt_assembly(asm);
+        AssemblyChecker { cx: self, yul_decls: yul_decls.0 }.visit_stmt_assembly(asm);
+    }
+
+    /// Checks an access to a Solidity variable in inline assembly, with an optional `.slot`,
+    /// `.offset` or `.length` suffix.
+    fn check_assembly_variable(
+        &self,
+        path: &ast::PathSlice,
+        id: hir::VariableId,
+        suffix: Option<Ident>,
+        is_assignment: bool,
+    ) {
+        let var = self.hir.variable(id);
+        match var.mutability {
+            Some(hir::VarMut::Immutable) => {
+                let msg = "assembly access to immutable variables is not supported";
+                self.sess
+                    .dcx
+                    .err(msg)
+                    .span(path.span())
+                    .subdiagnostic(DeclaredHere::new("variable", var.span))
+                    .emit();
+            }
+            Some(hir::VarMut::Constant) if suffix.is_none() => {
+                self.check_assembly_constant(path, id, is_assignment)
+            }
+            _ => {
+                if let Some(suffix) = suffix {
+                    self.check_assembly_suffix(path, var, suffix);
+                }
+            }
+        }
+    }
+
+    /// Checks a read of a constant in inline assembly, which is inlined, so it must be a number
+    /// constant or a reference to one.
+    fn check_assembly_constant(&self, path: &ast::PathSlice, id: hir::VariableId, assign: bool) {
+        let var = self.hir.variable(id);
+        let msg = if assign {
+            "constant variables cannot be assigned to"
+        } else {
+            // Follow references to other constants, e.g. `uint constant B = A;`.
+            let mut root = var;
+            let mut seen = FxHashSet::from_iter([id]);
+            while let Some(&hir::Expr {
+                kind: hir::ExprKind::Ident(&[Res::Item(hir::ItemId::Variable(next))]),
+                ..
+            }) = root.initializer.map(hir::Expr::peel_parens)
+            {
+                let next_var = self.hir.variable(next);
+                if next_var.mutability != Some(hir::VarMut::Constant) || !seen.insert(next) {
+                    break;
+                }
+                root = next_var;
+            }
+            let is_value_type = !matches!(
+                root.ty.kind,
+                hir::TypeKind::Elementary(hir::ElementaryType::String | hir::ElementaryType::Bytes)
+            );
+            match root.initializer {
+                Some(init) if is_value_type && is_number_constant(init) => return,
+                // Uninitialized constants are diagnosed separately.
+                None => return,
+                _ => {
+                    "only direct number constants and references to such constants are supported \
+                     by inline assembly"
+                }
+            }
+        };
+        self.sess
+            .dcx
+            .err(msg)
+            .span(path.span())
+            .subdiagnostic(DeclaredHere::new("variable", var.span))
+            .emit();
+    }
+
+    /// Checks a `.slot`, `.offset` or `.length` suffix access on a Solidity variable in inline
+    /// assembly.
+    fn check_assembly_suffix(&self, path: &ast::PathSlice, var: &hir::Variable<'_>, suffix: Ident) {
+        let is_storage = (var.is_state_variable && var.mutability.is_none())
+            || var.data_location == Some(hir::DataLocation::Storage);
+        let is_calldata = var.data_location == Some(hir::DataLocation::Calldata);
+        let msg = match suffix.name {
+            sym::slot if !is_storage => "the `.slot` suffix can only be used on storage variables",
+            sym::offset if !is_storage && !is_calldata => {
+                "the `.offset` suffix can only be used on storage or calldata variables"
+            }
+            sym::length if !is_calldata => {
+                "the `.length` suffix can only be used on calldata variables"
+            }
+            _ => return,
+        };
+        self.sess
+            .dcx
+            .err(msg)
+            .span(path.span())
+            .subdiagnostic(DeclaredHere::new("variable", var.span))
+            .emit();
+    }
+}
+
+/// Collects the names declared in an inline assembly block, which shadow Solidity declarations.
+#[derive(Default)]
+struct YulDeclarations(FxHashSet<Symbol>);
+
+impl<'ast> Visit<'ast> for YulDeclarations {
+    fn visit_yul_stmt(&mut self, stmt: &'ast ast::yul::Stmt<'ast>) {
+        if let ast::yul::StmtKind::VarDecl(idents, _) = &stmt.kind {
+            self.0.extend(idents.iter().map(|ident| ident.name));
+        }
+        self.walk_yul_stmt(stmt);
+    }
+
+    fn visit_yul_function(&mut self, function: &'ast ast::yul::Function<'ast>) {
+        let ast::yul::Function { name, parameters, returns, body: _ } = function;
+        self.0.insert(name.name);
+        self.0.extend(parameters.iter().chain(returns.iter()).map(|ident| ident.name));
+        self.walk_yul_function(function);
+    }
+}
+
+/// Checks the accesses to Solidity variables in an inline assembly block.
+struct AssemblyChecker<'a, 'sess, 'hir, 'b> {
+    cx: &'a ResolveContext<'sess, 'hir, 'b>,
+    yul_decls: FxHashSet<Symbol>,
+}
+
+impl AssemblyChecker<'_, '_, '_, '_> {
+    fn check_path(&self, path: &ast::PathSlice, is_assignment: bool) {
+        let (base, suffix) = match *path.segments() {
+            [base] => (base, None),
+            [base, suffix] => (base, Some(suffix)),
+            _ => return,
+        };
+        if self.yul_decls.contains(&base.name) {
+            return;
+        }
+        let Some(&[decl]) = self.cx.resolver.resolve_name_raw(base, &self.cx.scopes) else {
+            return;
+        };
+        if let Res::Item(hir::ItemId::Variable(id)) = decl.res {
+            self.cx.check_assembly_variable(path, id, suffix, is_assignment);
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for AssemblyChecker<'_, '_, '_, '_> {
+    fn visit_yul_stmt(&mut self, stmt: &'ast ast::yul::Stmt<'ast>) {
+        match &stmt.kind {
+            ast::yul::StmtKind::AssignSingle(path, expr) => {
+                self.check_path(path, true);
+                self.visit_yul_expr(expr);
+            }
+            ast::yul::StmtKind::AssignMulti(paths, call) => {
+                for path in paths.iter() {
+                    self.check_path(path, true);
+                }
+                self.visit_yul_expr_call(call);
+            }
+            _ => self.walk_yul_stmt(stmt),
+        }
+    }
+
+    fn visit_path(&mut self, path: &'ast ast::PathSlice) {
+        self.check_path(path, false);
+    }
+}
+
+/// Returns `true` if the constant initializer is a number literal, or an arithmetic expression of
+/// number literals.
+fn is_number_constant(expr: &hir::Expr<'_>) -> bool {
+    match expr.peel_parens().kind {
+        hir::ExprKind::Lit(lit) => {
+            !matches!(lit.kind, ast::LitKind::Str(..) | ast::LitKind::Err(_))
+        }
+        hir::ExprKind::Unary(_, operand) => is_number_constant(operand),
+        hir::ExprKind::Binary(lhs, _, rhs) => is_number_constant(lhs) && is_number_constant(rhs),
+        _ => false,
+    }
 }
 
 struct ResolverError {
@@ -984,7 +1312,8 @@ struct ResolverError {
 enum ResolverErrorKind {
     Unresolved,
     NotAScope(Res),
-    MultipleDeclarations,
+    /// Contains the candidate declarations.
+    MultipleDeclarations(Vec<Declaration>),
 }
 
 impl ResolverError {
@@ -1002,7 +1331,7 @@ impl ResolverError {
 
     fn format(&self) -> String {
         let name = self.name;
-        match self.kind {
+        match &self.kind {
             ResolverErrorKind::Unresolved => format!("unresolved symbol `{name}`"),
             ResolverErrorKind::NotAScope(kind) => {
                 format!(
@@ -1010,11 +1339,54 @@ impl ResolverError {
                     kind.description()
                 )
             }
-            ResolverErrorKind::MultipleDeclarations => {
+            ResolverErrorKind::MultipleDeclarations(_) => {
                 format!("symbol `{name}` resolved to multiple declarations")
             }
         }
     }
+
+    /// Creates the diagnostic for this error.
+    ///
+    /// Ambiguous references list the candidate declarations, and suggest qualifying the name if
+    /// the candidates are declared in different contracts.
+    fn to_diag<'a>(
+        &self,
+        dcx: &'a DiagCtxt,
+        hir: &hir::Hir<'_>,
+        scopes: &SymbolResolverScopes,
+    ) -> DiagnosticBuilder<'a, ErrorGuaranteed> {
+        let mut err = dcx.err(self.format()).span(self.span());
+        let ResolverErrorKind::MultipleDeclarations(candidates) = &self.kind else { return err };
+
+        let mut contracts = SmallVec::<[hir::ContractId; 4]>::new();
+        for candidate in candidates {
+            if !candidate.span.is_dummy() {
+                err = err.subdiagnostic(DeclaredHere::new(candidate.description(), candidate.span));
+            }
+            if let Res::Item(id) = candidate.res {
+                if let Some(c) = hir.item(id).contract() {
+                    if !contracts.contains(&c) {
+                        contracts.push(c);
+                    }
+                }
+            }
+        }
+        if contracts.len() < 2 {
+            return err;
+        }
+
+        let name = self.name;
+        let qualified = contracts
+            .iter()
+            .map(|&c| format!("`{}.{name}`", hir.contract(c).name))
+            .collect::<Vec<_>>()
+            .join(" or ");
+        err = err.help(format!("qualify the name with the contract that declares it: {qualified}"));
+        if scopes.contract.is_some_and(|c| contracts.contains(&c)) {
+            err = err.help(format!("use `super.{name}` to refer to the inherited declaration"));
+        }
+        err
+    }
 }
 
 pub(crate) struct SymbolResolver<'sess> {
@@ -1041,9 +1413,11 @@ impl<'sess> SymbolResolver<'sess> {
         &self,
         path: &ast::PathSlice,
         scopes: &SymbolResolverScopes,
+        hir: &hir::Hir<'_>,
         description: &str,
     ) -> Result<T, ErrorGuaranteed> {
-        let decl = self.resolve_path(path, scopes).map_err(self.emit_resolver_error())?;
+        let decl =
+            self.resolve_path(path, scopes).map_err(|e| e.to_diag(self.dcx, hir, scopes).emit())?;
         if let Res::Err(guar) = decl.res {
             return Err(guar);
         }
@@ -1051,10 +1425,6 @@ impl<'sess> SymbolResolver<'sess> {
             .map_err(|_| self.report_expected(description, decl.description(), path.span()))
     }
 
-    fn emit_resolver_error(&self) -> impl Fn(ResolverError) -> ErrorGuaranteed + '_ {
-        move |e| self.dcx.err(e.format()).span(e.span()).emit()
-    }
-
     fn resolve_path(
         &self,
         path: &ast::PathSlice,
@@ -1064,7 +1434,8 @@ impl<'sess> SymbolResolver<'sess> {
         if let [decl] = decls {
             Ok(*decl)
         } else {
-            Err(ResolverError::new(*path.last(), ResolverErrorKind::MultipleDeclarations))
+            let kind = ResolverErrorKind::MultipleDeclarations(decls.to_vec());
+            Err(ResolverError::new(*path.last(), kind))
         }
     }
 
@@ -1083,7 +1454,7 @@ impl<'sess> SymbolResolver<'sess> {
                 return Err(ResolverError::from_path(
                     path,
                     prev_i,
-                    ResolverErrorKind::MultipleDeclarations,
+                    ResolverErrorKind::MultipleDeclarations(decls.to_vec()),
                 ));
             };
             if decl.res.is_err() {
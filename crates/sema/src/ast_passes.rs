@@ -5,41 +5,278 @@ use solar_ast::{
     ast::{Stmt, StmtKind},
     visit::Visit,
 };
-use solar_interface::{diagnostics::DiagCtxt, sym, Session, Span};
+use solar_interface::{
+    diagnostics::DiagCtxt, lint::builtin, source_map::SourceFile, sym, BytePos, Session, Span,
+};
+use solar_parse::lexer::{token::RawTokenKind, Cursor};
 
 #[instrument(name = "ast_passes", level = "debug", skip_all)]
-pub(crate) fn run(sess: &Session, ast: &ast::SourceUnit<'_>) {
+pub(crate) fn run(sess: &Session, file: &SourceFile, ast: &ast::SourceUnit<'_>) {
     validate(sess, ast);
+    check_license_identifiers(sess, file);
 }
 
 /// Performs AST validation.
 #[instrument(name = "validate", level = "debug", skip_all)]
 pub fn validate(sess: &Session, ast: &ast::SourceUnit<'_>) {
     let mut validator = AstValidator::new(sess);
+    validator.check_pragmas(ast);
+    if sess.is_lint_enabled(&builtin::DUPLICATE_FUNCTION_BODIES) {
+        validator.check_duplicate_function_bodies(&ast.items.raw);
+    }
     validator.visit_source_unit(ast);
 }
 
+/// The comment marker of the license identifier of a source file.
+const SPDX_MARKER: &str = "SPDX-License-Identifier:";
+
+/// Checks that the given source file contains at most one `SPDX-License-Identifier` comment.
+///
+/// Like in solc, the identifier is the text following the marker, made of alphanumeric characters,
+/// spaces, parentheses, `+`, `.` and `-`.
+fn check_license_identifiers(sess: &Session, file: &SourceFile) {
+    let mut first = None::<(Span, &str)>;
+    let mut pos = 0;
+    for token in Cursor::new(&file.src) {
+        let start = pos;
+        pos += token.len as usize;
+        if !matches!(
+            token.kind,
+            RawTokenKind::LineComment { .. } | RawTokenKind::BlockComment { .. }
+        ) {
+            continue;
+        }
+        let comment = &file.src[start..pos];
+        let Some(marker) = comment.find(SPDX_MARKER) else { continue };
+        let rest = &comment[marker + SPDX_MARKER.len()..];
+        let value_start = rest.len() - rest.trim_start().len();
+        let value_len = rest[value_start..]
+            .find(|c: char| !(c.is_ascii_alphanumeric() || " ()+.-".contains(c)))
+            .unwrap_or(rest.len() - value_start);
+        let value = rest[value_start..value_start + value_len].trim_end();
+        let lo = file.start_pos + BytePos((start + marker) as u32);
+        let hi = lo + BytePos((SPDX_MARKER.len() + value_start + value.len()) as u32);
+        let span = Span::new(lo, hi);
+
+        let Some((first_span, first_value)) = first else {
+            first = Some((span, value));
+            continue;
+        };
+        if value == first_value {
+            sess.dcx
+                .warn("duplicate SPDX license identifier")
+                .span(span)
+                .span_note(first_span, "previously specified here")
+                .help("remove the duplicate comment")
+                .emit();
+        } else {
+            sess.dcx
+                .err("conflicting SPDX license identifiers")
+                .span(span)
+                .span_note(first_span, "previously specified here")
+                .help(format!(
+                    "use a single identifier combining the licenses, like `{first_value} OR {value}`"
+                ))
+                .emit();
+        }
+    }
+}
+
 /// AST validator.
 struct AstValidator<'sess> {
     span: Span,
-    dcx: &'sess DiagCtxt,
+    sess: &'sess Session,
     in_loop_depth: u64,
+    contract_kind: Option<ast::ContractKind>,
 }
 
 impl<'sess> AstValidator<'sess> {
     fn new(sess: &'sess Session) -> Self {
-        Self { span: Span::DUMMY, dcx: &sess.dcx, in_loop_depth: 0 }
+        Self { span: Span::DUMMY, sess, in_loop_depth: 0, contract_kind: None }
     }
 
     /// Returns the diagnostics context.
     #[inline]
     fn dcx(&self) -> &'sess DiagCtxt {
-        self.dcx
+        &self.sess.dcx
     }
 
     fn in_loop(&self) -> bool {
         self.in_loop_depth != 0
     }
+
+    /// Checks for duplicate and conflicting pragma directives in the given source unit.
+    ///
+    /// Version pragmas conflict if no compiler version satisfies both, and other pragmas if they
+    /// select different values for the same setting, like `abicoder`.
+    fn check_pragmas(&self, ast: &ast::SourceUnit<'_>) {
+        let mut versions = Vec::<(Span, &ast::SemverReq<'_>)>::new();
+        let mut abicoder = None::<(Span, &str)>;
+        let mut experimental = Vec::<(Span, &str)>::new();
+        for item in ast.items.iter() {
+            let ast::ItemKind::Pragma(pragma) = &item.kind else { continue };
+            let span = item.span;
+            match &pragma.tokens {
+                ast::PragmaTokens::Version(name, req) if name.name == sym::solidity => {
+                    let req_s = req.to_string();
+                    let duplicate = versions.iter().find(|(_, r)| r.to_string() == req_s);
+                    let conflict = versions.iter().find(|(_, r)| !r.intersects(req));
+                    if let Some(&(prev, _)) = duplicate {
+                        self.report_duplicate_pragma("solidity", span, prev);
+                    } else if let Some(&(prev, prev_req)) = conflict {
+                        let note =
+                            format!("no compiler version satisfies both `{prev_req}` and `{req}`");
+                        self.dcx()
+                            .err("conflicting `pragma solidity` directives")
+                            .span(span)
+                            .span_note(prev, "previously specified here")
+                            .note(note)
+                            .emit();
+                    }
+                    versions.push((span, req));
+                }
+                ast::PragmaTokens::Custom(name, Some(value)) => match name.as_str() {
+                    "abicoder" => match abicoder {
+                        Some((prev, prev_value)) if prev_value == value.as_str() => {
+                            self.report_duplicate_pragma("abicoder", span, prev);
+                        }
+                        Some((prev, _)) => {
+                            self.dcx()
+                                .err("conflicting `pragma abicoder` directives")
+                                .span(span)
+                                .span_note(prev, "previously specified here")
+                                .note("the ABI coder can only be selected once per source file")
+                                .emit();
+                        }
+                        None => abicoder = Some((span, value.as_str())),
+                    },
+                    "experimental" => {
+                        let feature = value.as_str();
+                        if let Some(&(prev, _)) = experimental.iter().find(|(_, f)| *f == feature) {
+                            self.report_duplicate_pragma("experimental", span, prev);
+                        } else {
+                            experimental.push((span, feature));
+                        }
+                    }
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+    }
+
+    fn report_duplicate_pragma(&self, name: &str, span: Span, prev: Span) {
+        self.dcx()
+            .warn(format!("duplicate `pragma {name}` directive"))
+            .span(span)
+            .span_note(prev, "previously specified here")
+            .help("remove the duplicate directive")
+            .emit();
+    }
+
+    /// Warns about the `catch` clauses of the given `try` statement that have an empty block, and
+    /// thus silently ignore the failure of the call.
+    fn check_empty_catch(&self, try_: &ast::StmtTry<'_>) {
+        for catch in try_.catch.iter() {
+            if !is_empty_block(&catch.block) {
+                continue;
+            }
+            let Some(diag) = self.sess.struct_lint(
+                &builtin::EMPTY_CATCH,
+                catch.span,
+                "empty `catch` clause swallows the error",
+            ) else {
+                return;
+            };
+            diag.note("the failure of the call is silently ignored")
+                .help("handle the error, or revert with `revert()` to propagate it")
+                .emit();
+        }
+    }
+
+    /// Warns about the functions among the given items whose body is identical to the body of a
+    /// previous function, up to the names of their parameters and return variables.
+    fn check_duplicate_function_bodies(&self, items: &[ast::Item<'_>]) {
+        let functions = items
+            .iter()
+            .filter_map(|item| match &item.kind {
+                ast::ItemKind::Function(f) if f.kind.is_function() => {
+                    let name = f.header.name?;
+                    let body = f.body.as_deref().filter(|body| !is_empty_block(body))?;
+                    Some((name, f, body))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        for (i, &(name, f, body)) in functions.iter().enumerate() {
+            let original = functions[..i].iter().find(|&&(_, prev_f, prev_body)| {
+                let params = prev_f.header.parameters.iter().chain(prev_f.header.returns.iter());
+                let f_params = f.header.parameters.iter().chain(f.header.returns.iter());
+                let renames = params
+                    .zip(f_params)
+                    .filter_map(|(a, b)| Some((a.name?.name, b.name?.name)))
+                    .collect::<Vec<_>>();
+                ast::ast_eq_renamed(prev_body, body, &renames)
+            });
+            let Some(&(prev_name, ..)) = original else { continue };
+            let msg = format!("function `{name}` has the same body as `{prev_name}`");
+            let lint = &builtin::DUPLICATE_FUNCTION_BODIES;
+            let Some(diag) = self.sess.struct_lint(lint, name.span, msg) else { return };
+            diag.span_note(prev_name.span, "function with the same body")
+                .help("extract the common code into an internal function")
+                .emit();
+        }
+    }
+
+    /// Reports the deprecated `pragma experimental SMTChecker;` at the level of the
+    /// [`smtchecker-pragma`](builtin::SMTCHECKER_PRAGMA) lint.
+    fn check_smtchecker_pragma(&self) {
+        let lint = &builtin::SMTCHECKER_PRAGMA;
+        let Some(diag) = self.sess.struct_lint(lint, self.span, lint.message) else { return };
+        diag.note("the SMTChecker is enabled with the `modelChecker` settings instead")
+            .help("remove the pragma and select an engine with `settings.modelChecker.engine`")
+            .emit();
+    }
+
+    /// Checks the specifiers of a function in an interface. Interface functions are implicitly
+    /// `virtual`, must be `external`, and cannot have an implementation.
+    fn check_interface_function(&self, function: &ast::ItemFunction<'_>) {
+        match function.kind {
+            ast::FunctionKind::Constructor => {
+                self.dcx().err("interfaces cannot have constructors").span(self.span).emit();
+                return;
+            }
+            ast::FunctionKind::Modifier => return,
+            ast::FunctionKind::Function
+            | ast::FunctionKind::Fallback
+            | ast::FunctionKind::Receive => {}
+        }
+        let span = function.header.name.map_or(self.span, |name| name.span);
+        if function.body.is_some() {
+            self.dcx()
+                .err("functions in interfaces cannot have an implementation")
+                .span(span)
+                .help("remove the body and end the declaration with `;`")
+                .emit();
+        }
+        if let Some(visibility) = function.header.visibility {
+            if visibility != ast::Visibility::External
+                && self.sess.solc_compat.is_none_or(|v| v.requires_external_interface_functions())
+            {
+                let msg = format!(
+                    "functions in interfaces must be declared `external`, found `{visibility}`"
+                );
+                self.dcx().err(msg).span(span).help("use `external` instead").emit();
+            }
+        }
+        if function.header.virtual_ {
+            self.dcx()
+                .warn("interface functions are implicitly `virtual`")
+                .span(span)
+                .help("remove the `virtual` specifier")
+                .emit();
+        }
+    }
 }
 
 impl<'ast> Visit<'ast> for AstValidator<'_> {
@@ -48,6 +285,46 @@ impl<'ast> Visit<'ast> for AstValidator<'_> {
         self.walk_item(item);
     }
 
+    fn visit_item_contract(&mut self, contract: &'ast ast::ItemContract<'ast>) {
+        if self.sess.is_lint_enabled(&builtin::DUPLICATE_FUNCTION_BODIES) {
+            self.check_duplicate_function_bodies(&contract.body);
+        }
+        let prev = self.contract_kind.replace(contract.kind);
+        self.walk_item_contract(contract);
+        self.contract_kind = prev;
+    }
+
+    fn visit_item_function(&mut self, function: &'ast ast::ItemFunction<'ast>) {
+        if self.contract_kind == Some(ast::ContractKind::Library) {
+            let msg = match function.kind {
+                ast::FunctionKind::Constructor => Some("libraries cannot have constructors"),
+                ast::FunctionKind::Fallback => Some("libraries cannot have fallback functions"),
+                ast::FunctionKind::Receive => Some("libraries cannot have receive functions"),
+                ast::FunctionKind::Function | ast::FunctionKind::Modifier => None,
+            };
+            if let Some(msg) = msg {
+                let note = "libraries cannot have state and cannot receive Ether";
+                self.dcx().err(msg).span(self.span).note(note).emit();
+            }
+        }
+        if self.contract_kind == Some(ast::ContractKind::Interface) {
+            self.check_interface_function(function);
+        }
+        if function.kind.is_function()
+            && self.contract_kind.is_some()
+            && function.header.visibility.is_none()
+            && self.sess.solc_compat.is_some_and(|v| v.requires_function_visibility())
+        {
+            let span = function.header.name.map_or(self.span, |name| name.span);
+            self.dcx()
+                .err("no visibility specified")
+                .span(span)
+                .help("add one of `public`, `external`, `internal` or `private`")
+                .emit();
+        }
+        self.walk_item_function(function);
+    }
+
     fn visit_pragma_directive(&mut self, pragma: &'ast ast::PragmaDirective<'ast>) {
         match &pragma.tokens {
             ast::PragmaTokens::Version(name, _version) => {
@@ -62,7 +339,7 @@ impl<'ast> Visit<'ast> for AstValidator<'_> {
                 match (name, value) {
                     ("abicoder", Some("v1" | "v2")) => {}
                     ("experimental", Some("ABIEncoderV2")) => {}
-                    ("experimental", Some("SMTChecker")) => {}
+                    ("experimental", Some("SMTChecker")) => self.check_smtchecker_pragma(),
                     ("experimental", Some("solidity")) => {
                         let msg = "experimental solidity features are not supported";
                         self.dcx().err(msg).span(self.span).emit();
@@ -105,7 +382,13 @@ impl<'ast> Visit<'ast> for AstValidator<'_> {
                         .emit();
                 }
             }
-            _ => {}
+            StmtKind::Try(try_) => {
+                if self.sess.is_lint_enabled(&builtin::EMPTY_CATCH) {
+                    self.check_empty_catch(try_);
+                }
+                self.walk_stmt(stmt);
+            }
+            _ => self.walk_stmt(stmt),
         }
     }
 
@@ -115,3 +398,11 @@ impl<'ast> Visit<'ast> for AstValidator<'_> {
 
     fn visit_ty(&mut self, _ty: &'ast ast::Type<'ast>) {}
 }
+
+/// Returns `true` if the block has no statements other than empty blocks.
+fn is_empty_block(block: &[Stmt<'_>]) -> bool {
+    block.iter().all(|stmt| match &stmt.kind {
+        StmtKind::Block(block) | StmtKind::UncheckedBlock(block) => is_empty_block(block),
+        _ => false,
+    })
+}
@@ -4,7 +4,7 @@ use crate::{
     ty::{Gcx, Ty},
 };
 use solar_ast::ast::StateMutability as SM;
-use solar_interface::{kw, sym, Span, Symbol};
+use solar_interface::{config::EvmVersion, kw, sym, Span, Symbol};
 
 pub(crate) mod members;
 pub use members::{Member, MemberList};
@@ -90,13 +90,16 @@ declare_builtins! {
                            => gcx.mk_builtin_fn(&[gcx.types.uint(256), gcx.types.uint(256), gcx.types.uint(256)], SM::Pure, &[gcx.types.uint(256)]);
 
     Keccak256              => kw::Keccak256
-                           => gcx.mk_builtin_fn(&[gcx.types.bytes_ref.memory], SM::View, &[gcx.types.fixed_bytes(32)]);
+                           => gcx.mk_builtin_fn(&[gcx.types.bytes_ref.memory], SM::Pure, &[gcx.types.fixed_bytes(32)]);
     Sha256                 => sym::sha256
-                           => gcx.mk_builtin_fn(&[gcx.types.bytes_ref.memory], SM::View, &[gcx.types.fixed_bytes(32)]);
+                           => gcx.mk_builtin_fn(&[gcx.types.bytes_ref.memory], SM::Pure, &[gcx.types.fixed_bytes(32)]);
     Ripemd160              => sym::ripemd160
-                           => gcx.mk_builtin_fn(&[gcx.types.bytes_ref.memory], SM::View, &[gcx.types.fixed_bytes(20)]);
+                           => gcx.mk_builtin_fn(&[gcx.types.bytes_ref.memory], SM::Pure, &[gcx.types.fixed_bytes(20)]);
     EcRecover              => sym::ecrecover
-                           => gcx.mk_builtin_fn(&[gcx.types.fixed_bytes(32), gcx.types.uint(8), gcx.types.fixed_bytes(32), gcx.types.fixed_bytes(32)], SM::View, &[gcx.types.address]);
+                           => gcx.mk_builtin_fn(&[gcx.types.fixed_bytes(32), gcx.types.uint(8), gcx.types.fixed_bytes(32), gcx.types.fixed_bytes(32)], SM::Pure, &[gcx.types.address]);
+
+    Selfdestruct           => kw::Selfdestruct
+                           => gcx.mk_builtin_fn(&[gcx.types.address_payable], SM::NonPayable, &[]);
 
     Block                  => sym::block
                            => gcx.mk_builtin_mod(Self::Block);
@@ -290,6 +293,17 @@ impl Builtin {
         builtin_range_slice!(Self::FIRST_GLOBAL, Self::LAST_GLOBAL)
     }
 
+    /// Returns the minimum EVM version required to use the builtin, if any.
+    pub fn min_evm_version(self) -> Option<EvmVersion> {
+        use Builtin::*;
+        Some(match self {
+            Blobhash | BlockBlobbasefee => EvmVersion::Cancun,
+            BlockBasefee => EvmVersion::London,
+            BlockChainid => EvmVersion::Istanbul,
+            _ => return None,
+        })
+    }
+
     /// Returns the builtin's members.
     pub fn members(self) -> Option<&'static [Self]> {
         use Builtin::*;
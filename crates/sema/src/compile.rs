@@ -0,0 +1,125 @@
+use crate::ParsingContext;
+use solar_interface::{diagnostics::ErrorGuaranteed, Session};
+use std::{fmt, path::Path};
+
+/// The stage at which [`compile`] could not proceed.
+///
+/// The errors themselves are emitted as diagnostics in the session's diagnostics context, this
+/// only describes the kind of failure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CompileError {
+    /// An input file could not be read.
+    IoError(ErrorGuaranteed),
+    /// The sources could not be parsed.
+    ParseFailed(ErrorGuaranteed),
+    /// Imports or names could not be resolved.
+    ResolutionFailed(ErrorGuaranteed),
+    /// Semantic analysis, including the checks run on the AST, failed.
+    AnalysisFailed(ErrorGuaranteed),
+    /// The session or the inputs are not configured correctly, e.g. no input files were given.
+    InvalidConfig(ErrorGuaranteed),
+}
+
+impl fmt::Display for CompileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::IoError(_) => "could not read the input files",
+            Self::ParseFailed(_) => "could not parse the sources",
+            Self::ResolutionFailed(_) => "could not resolve the sources",
+            Self::AnalysisFailed(_) => "semantic analysis failed",
+            Self::InvalidConfig(_) => "invalid configuration",
+        })
+    }
+}
+
+impl std::error::Error for CompileError {}
+
+impl From<CompileError> for ErrorGuaranteed {
+    fn from(err: CompileError) -> Self {
+        err.guarantee()
+    }
+}
+
+impl CompileError {
+    /// Returns the proof that the errors were emitted as diagnostics.
+    pub fn guarantee(self) -> ErrorGuaranteed {
+        match self {
+            Self::IoError(guar)
+            | Self::ParseFailed(guar)
+            | Self::ResolutionFailed(guar)
+            | Self::AnalysisFailed(guar)
+            | Self::InvalidConfig(guar) => guar,
+        }
+    }
+}
+
+/// Loads, parses and semantically analyzes the given files, recursing into imports.
+///
+/// Returns an error if the compilation pipeline cannot proceed. Issues that do not stop the
+/// pipeline, like warnings, are only emitted as diagnostics in the session.
+pub fn compile(
+    sess: &Session,
+    paths: impl IntoIterator<Item = impl AsRef<Path>>,
+) -> Result<(), CompileError> {
+    sess.enter(|| {
+        let mut pcx = ParsingContext::new(sess);
+        pcx.load_files(paths).map_err(CompileError::IoError)?;
+        crate::run(pcx)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solar_interface::ColorChoice;
+
+    #[test]
+    fn compile_file() {
+        let sess = Session::builder().with_buffer_emitter(ColorChoice::Never).build();
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../testdata/Counter.sol");
+        assert_eq!(compile(&sess, [path]), Ok(()));
+        assert!(sess.emitted_diagnostics().unwrap().is_ok());
+    }
+
+    fn compile_src(sess: &Session, src: &str) -> Result<(), CompileError> {
+        sess.enter(|| {
+            let file =
+                sess.source_map().new_dummy_source_file("test.sol".into(), src.into()).unwrap();
+            let mut pcx = ParsingContext::new(sess);
+            pcx.add_file(file);
+            crate::run(pcx)
+        })
+    }
+
+    #[test]
+    fn compile_parse_error() {
+        let sess = Session::builder().with_buffer_emitter(ColorChoice::Never).build();
+        let result = compile_src(&sess, "contract C { function f( }");
+        assert!(matches!(result, Err(CompileError::ParseFailed(_))), "{result:?}");
+
+        let mut sess = Session::builder().with_buffer_emitter(ColorChoice::Never).build();
+        sess.stop_after = Some(solar_interface::config::CompilerStage::Parsed);
+        let result = compile_src(&sess, "contract C { function f( }");
+        assert!(matches!(result, Err(CompileError::ParseFailed(_))), "{result:?}");
+        let result = compile_src(&sess, "contract C {}");
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn compile_ast_pass_error() {
+        let sess = Session::builder().with_buffer_emitter(ColorChoice::Never).build();
+        let result = compile_src(&sess, "interface I { constructor() {} }");
+        assert!(matches!(result, Err(CompileError::AnalysisFailed(_))), "{result:?}");
+        let err = sess.emitted_diagnostics().unwrap().unwrap_err().to_string();
+        assert!(err.contains("interfaces cannot have constructors"), "{err}");
+    }
+
+    #[test]
+    fn compile_unreadable_file() {
+        let sess = Session::builder().with_buffer_emitter(ColorChoice::Never).build();
+        let result = compile(&sess, ["does/not/exist.sol"]);
+        assert!(matches!(result, Err(CompileError::IoError(_))), "{result:?}");
+        let err = sess.emitted_diagnostics().unwrap().unwrap_err().to_string();
+        assert!(err.contains("does/not/exist.sol"), "{err}");
+    }
+}
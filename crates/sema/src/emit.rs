@@ -7,13 +7,37 @@ use std::{
     path::Path,
 };
 
+/// The revision of the schema of the combined JSON output of `--emit`.
+///
+/// The output has a top-level `schema_version` field set to this value, next to the `version` of
+/// the compiler that emitted it.
+///
+/// # Compatibility
+///
+/// The schema is versioned independently of the compiler. This is bumped on every change that can
+/// break a consumer: removing or renaming a field, or changing the representation of a value.
+/// Fields may be added without bumping it, so consumers should ignore the fields they do not know,
+/// and check `schema_version` to reject the revisions they do not support.
+pub const AST_JSON_SCHEMA_VERSION: u32 = 1;
+
 #[derive(Default, Serialize)]
 struct CombinedJson {
     #[serde(skip_serializing_if = "BTreeMap::is_empty")]
     contracts: BTreeMap<String, CombinedJsonContract>,
+    schema_version: u32,
     version: &'static str,
 }
 
+impl CombinedJson {
+    fn new() -> Self {
+        Self {
+            contracts: Default::default(),
+            schema_version: AST_JSON_SCHEMA_VERSION,
+            version: solar_interface::VERSION,
+        }
+    }
+}
+
 #[derive(Default, Serialize)]
 struct CombinedJsonContract {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -26,9 +50,8 @@ type Abi = Vec<alloy_json_abi::AbiItem<'static>>;
 type Hashes = BTreeMap<String, String>;
 
 pub(crate) fn emit(gcx: Gcx<'_>) {
-    let mut output =
-        CombinedJson { contracts: Default::default(), version: solar_interface::VERSION };
-    for id in gcx.hir.contract_ids() {
+    let mut output = CombinedJson::new();
+    for id in gcx.hir.contract_ids().filter(|&id| gcx.is_contract_selected(id)) {
         let name = gcx.contract_fully_qualified_name(id).to_string();
         let contract_output = output.contracts.entry(name).or_default();
         for &emit in &gcx.sess.emit {
@@ -57,7 +80,7 @@ pub(crate) fn emit(gcx: Gcx<'_>) {
     .map_err(|e| gcx.dcx().err(format!("failed to write to output: {e}")).emit());
 }
 
-fn out_writer(path: Option<&Path>) -> io::Result<impl io::Write> {
+pub(crate) fn out_writer(path: Option<&Path>) -> io::Result<impl io::Write> {
     let out: Box<dyn io::Write> = if let Some(path) = path {
         Box::new(std::fs::File::create(path)?)
     } else {
@@ -77,3 +100,15 @@ fn to_json<W: io::Write, T: Serialize>(
         serde_json::to_writer(writer, value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schema_version() {
+        let json = serde_json::to_value(CombinedJson::new()).unwrap();
+        assert_eq!(json["schema_version"], AST_JSON_SCHEMA_VERSION);
+        assert_eq!(json["version"], solar_interface::VERSION);
+    }
+}
@@ -155,7 +155,8 @@ impl IntScalar {
             | hir::UnOpKind::PreDec
             | hir::UnOpKind::PostInc
             | hir::UnOpKind::PostDec => return Err(EE::UnsupportedUnaryOp),
-            hir::UnOpKind::Not | hir::UnOpKind::BitNot => Self::new(!self.data),
+            hir::UnOpKind::Not => Self::from_bool(!self.to_bool()),
+            hir::UnOpKind::BitNot => Self::new(!self.data),
             hir::UnOpKind::Neg => Self::new(self.data.wrapping_neg()),
         })
     }
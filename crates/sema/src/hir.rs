@@ -17,6 +17,9 @@ pub use ast::{
     StateMutability, UnOp, UnOpKind, VarMut, Visibility,
 };
 
+mod visit;
+pub use visit::Visit;
+
 /// HIR arena allocator.
 pub struct Arena {
     pub bump: bumpalo::Bump,
@@ -207,6 +210,21 @@ impl<'hir> Hir<'hir> {
             .chain(self.par_event_ids().map(ItemId::Event))
     }
 
+    /// Returns the direct base contracts of the given contract, in declaration order.
+    #[inline]
+    pub fn contract_bases(&self, id: ContractId) -> &'hir [ContractId] {
+        self.contract(id).bases
+    }
+
+    /// Returns the C3 linearization of the given contract, from the contract itself to the most
+    /// base contract.
+    ///
+    /// Empty if the linearization failed.
+    #[inline]
+    pub fn contract_linearization(&self, id: ContractId) -> &'hir [ContractId] {
+        self.contract(id).linearized_bases
+    }
+
     /// Returns an iterator over all item IDs in a contract, including inheritance.
     pub fn contract_item_ids(
         &self,
@@ -265,6 +283,8 @@ pub struct Source<'hir> {
     pub imports: &'hir [(ast::ItemId, SourceId)],
     /// The source items.
     pub items: &'hir [ItemId],
+    /// The file-level `using` directives.
+    pub usings: &'hir [UsingDirective<'hir>],
 }
 
 impl fmt::Debug for Source<'_> {
@@ -273,10 +293,56 @@ impl fmt::Debug for Source<'_> {
             .field("file", &self.file.name)
             .field("imports", &self.imports)
             .field("items", &self.items)
+            .field("usings", &self.usings)
             .finish()
     }
 }
 
+/// A `using for` directive: `using { f, g as + } for T global;`.
+#[derive(Debug)]
+pub struct UsingDirective<'hir> {
+    /// The directive span.
+    pub span: Span,
+    /// The library or functions attached to the type.
+    pub list: UsingList<'hir>,
+    /// The type the functions are attached to, or `None` for `*`.
+    pub ty: Option<Type<'hir>>,
+    /// Whether the directive is `global`.
+    pub global: bool,
+}
+
+/// The library or functions of a [`UsingDirective`].
+#[derive(Clone, Copy, Debug)]
+pub enum UsingList<'hir> {
+    /// `using L for T;`
+    Library(ContractId),
+    /// `using { f, g as + } for T;`
+    ///
+    /// Functions that could not be resolved are not included.
+    Functions(&'hir [UsingFunction]),
+}
+
+impl<'hir> UsingList<'hir> {
+    /// Returns the functions listed in braces, or an empty slice for a library.
+    pub fn functions(&self) -> &'hir [UsingFunction] {
+        match *self {
+            Self::Library(_) => &[],
+            Self::Functions(functions) => functions,
+        }
+    }
+}
+
+/// A function of a [`UsingList`], optionally bound to an operator: `f` or `f as +`.
+#[derive(Clone, Copy, Debug)]
+pub struct UsingFunction {
+    /// The span of the function path.
+    pub span: Span,
+    /// The function.
+    pub id: FunctionId,
+    /// The operator the function is bound to, if any.
+    pub operator: Option<ast::UserDefinableOperator>,
+}
+
 #[derive(Clone, Copy, Debug, EnumIs)]
 pub enum Item<'a, 'hir> {
     Contract(&'a Contract<'hir>),
@@ -490,6 +556,8 @@ pub struct Contract<'hir> {
     pub kind: ContractKind,
     /// The contract bases.
     pub bases: &'hir [ContractId],
+    /// The base constructor calls in the inheritance specifiers: `is B(1)`.
+    pub bases_args: &'hir [Modifier<'hir>],
     /// The linearized contract bases.
     pub linearized_bases: &'hir [ContractId],
     /// The constructor function.
@@ -505,7 +573,7 @@ pub struct Contract<'hir> {
     pub items: &'hir [ItemId],
 }
 
-impl Contract<'_> {
+impl<'hir> Contract<'hir> {
     /// Returns an iterator over functions declared in the contract.
     ///
     /// Note that this does not include the constructor and fallback functions, as they are stored
@@ -524,6 +592,25 @@ impl Contract<'_> {
         self.items.iter().filter_map(ItemId::as_variable)
     }
 
+    /// Returns an iterator over the items declared in the contract, sorted by their position in
+    /// the source code.
+    ///
+    /// Unlike [`items`](Self::items), this does not include the getters of public state variables,
+    /// as they are not declared in the source code.
+    pub fn members_in_source_order<'a>(
+        &'a self,
+        hir: &'a Hir<'hir>,
+    ) -> impl Iterator<Item = Item<'a, 'hir>> + Clone + use<'a, 'hir> {
+        let mut members = self
+            .items
+            .iter()
+            .map(|&id| hir.item(id))
+            .filter(|item| !matches!(item, Item::Function(f) if f.is_getter()))
+            .collect::<Vec<_>>();
+        members.sort_by_key(|item| item.span().lo());
+        members.into_iter()
+    }
+
     /// Returns `true` if the contract can be deployed.
     pub fn can_be_deployed(&self) -> bool {
         matches!(self.kind, ContractKind::Contract | ContractKind::Library)
@@ -553,8 +640,8 @@ pub struct Function<'hir> {
     pub visibility: Visibility,
     /// The state mutability of the function.
     pub state_mutability: StateMutability,
-    /// Modifiers, or base classes if this is a constructor.
-    pub modifiers: &'hir [ItemId],
+    /// Modifiers, or base constructor calls if this is a constructor.
+    pub modifiers: &'hir [Modifier<'hir>],
     /// Whether this function is marked with the `virtual` keyword.
     pub marked_virtual: bool,
     /// Whether this function is marked with the `virtual` keyword or is defined in an interface.
@@ -597,6 +684,24 @@ impl Function<'_> {
     }
 }
 
+/// A modifier invocation, or a base constructor call: `m(1)`, `B(1)`.
+#[derive(Debug)]
+pub struct Modifier<'hir> {
+    /// The span of the modifier name.
+    pub span: Span,
+    /// The modifier function, or the base contract.
+    pub id: ItemId,
+    /// The arguments.
+    pub args: CallArgs<'hir>,
+}
+
+impl Modifier<'_> {
+    /// Returns the base contract if this is a base constructor call.
+    pub fn base_contract(&self) -> Option<ContractId> {
+        self.id.as_contract()
+    }
+}
+
 /// A struct.
 #[derive(Debug)]
 pub struct Struct<'hir> {
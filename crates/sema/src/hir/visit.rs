@@ -0,0 +1,273 @@
+//! HIR visitor.
+
+use super::*;
+
+/// HIR traversal.
+///
+/// Items are referenced by ID in the HIR, and are looked up and visited through the
+/// `visit_nested_*` methods.
+///
+/// The `walk_*` methods visit the children of a node, and can be called from overridden `visit_*`
+/// methods to continue the traversal.
+pub trait Visit<'hir> {
+    /// The value returned when breaking from the traversal.
+    type BreakValue;
+
+    /// Returns the HIR map.
+    fn hir(&self) -> &'hir Hir<'hir>;
+
+    fn visit_nested_source(&mut self, id: SourceId) -> ControlFlow<Self::BreakValue> {
+        for &item in self.hir().source(id).items {
+            self.visit_nested_item(item)?;
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_nested_item(&mut self, id: ItemId) -> ControlFlow<Self::BreakValue> {
+        match id {
+            ItemId::Contract(id) => self.visit_nested_contract(id),
+            ItemId::Function(id) => self.visit_nested_function(id),
+            ItemId::Variable(id) => self.visit_nested_var(id),
+            ItemId::Struct(id) => self.visit_nested_struct(id),
+            ItemId::Enum(_) | ItemId::Udvt(_) | ItemId::Error(_) | ItemId::Event(_) => {
+                ControlFlow::Continue(())
+            }
+        }
+    }
+
+    fn visit_nested_contract(&mut self, id: ContractId) -> ControlFlow<Self::BreakValue> {
+        self.visit_contract(self.hir().contract(id))
+    }
+
+    fn visit_contract(&mut self, contract: &'hir Contract<'hir>) -> ControlFlow<Self::BreakValue> {
+        self.walk_contract(contract)
+    }
+
+    fn walk_contract(&mut self, contract: &'hir Contract<'hir>) -> ControlFlow<Self::BreakValue> {
+        for modifier in contract.bases_args {
+            self.visit_modifier(modifier)?;
+        }
+        for &item in contract.items {
+            self.visit_nested_item(item)?;
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_nested_function(&mut self, id: FunctionId) -> ControlFlow<Self::BreakValue> {
+        self.visit_function(self.hir().function(id))
+    }
+
+    fn visit_function(&mut self, func: &'hir Function<'hir>) -> ControlFlow<Self::BreakValue> {
+        self.walk_function(func)
+    }
+
+    fn walk_function(&mut self, func: &'hir Function<'hir>) -> ControlFlow<Self::BreakValue> {
+        for &param in func.parameters {
+            self.visit_nested_var(param)?;
+        }
+        for &ret in func.returns {
+            self.visit_nested_var(ret)?;
+        }
+        for modifier in func.modifiers {
+            self.visit_modifier(modifier)?;
+        }
+        if let Some(body) = func.body {
+            for stmt in body {
+                self.visit_stmt(stmt)?;
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_modifier(&mut self, modifier: &'hir Modifier<'hir>) -> ControlFlow<Self::BreakValue> {
+        self.walk_modifier(modifier)
+    }
+
+    fn walk_modifier(&mut self, modifier: &'hir Modifier<'hir>) -> ControlFlow<Self::BreakValue> {
+        self.visit_call_args(&modifier.args)
+    }
+
+    fn visit_nested_struct(&mut self, id: StructId) -> ControlFlow<Self::BreakValue> {
+        for &field in self.hir().strukt(id).fields {
+            self.visit_nested_var(field)?;
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_nested_var(&mut self, id: VariableId) -> ControlFlow<Self::BreakValue> {
+        self.visit_var(self.hir().variable(id))
+    }
+
+    fn visit_var(&mut self, var: &'hir Variable<'hir>) -> ControlFlow<Self::BreakValue> {
+        self.walk_var(var)
+    }
+
+    fn walk_var(&mut self, var: &'hir Variable<'hir>) -> ControlFlow<Self::BreakValue> {
+        self.visit_ty(&var.ty)?;
+        if let Some(init) = var.initializer {
+            self.visit_expr(init)?;
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_stmt(&mut self, stmt: &'hir Stmt<'hir>) -> ControlFlow<Self::BreakValue> {
+        self.walk_stmt(stmt)
+    }
+
+    fn walk_stmt(&mut self, stmt: &'hir Stmt<'hir>) -> ControlFlow<Self::BreakValue> {
+        match stmt.kind {
+            StmtKind::DeclSingle(var) => self.visit_nested_var(var)?,
+            StmtKind::DeclMulti(vars, expr) => {
+                for &var in vars.iter().flatten() {
+                    self.visit_nested_var(var)?;
+                }
+                self.visit_expr(expr)?;
+            }
+            StmtKind::Block(block) | StmtKind::UncheckedBlock(block) | StmtKind::Loop(block, _) => {
+                for stmt in block {
+                    self.visit_stmt(stmt)?;
+                }
+            }
+            StmtKind::Emit(_, ref args) | StmtKind::Revert(_, ref args) => {
+                self.visit_call_args(args)?;
+            }
+            StmtKind::Return(expr) => {
+                if let Some(expr) = expr {
+                    self.visit_expr(expr)?;
+                }
+            }
+            StmtKind::If(cond, then, else_) => {
+                self.visit_expr(cond)?;
+                self.visit_stmt(then)?;
+                if let Some(else_) = else_ {
+                    self.visit_stmt(else_)?;
+                }
+            }
+            StmtKind::Try(try_) => {
+                self.visit_expr(&try_.expr)?;
+                for &var in try_.returns {
+                    self.visit_nested_var(var)?;
+                }
+                for stmt in try_.block {
+                    self.visit_stmt(stmt)?;
+                }
+                for catch in try_.catch {
+                    for &var in catch.args {
+                        self.visit_nested_var(var)?;
+                    }
+                    for stmt in catch.block {
+                        self.visit_stmt(stmt)?;
+                    }
+                }
+            }
+            StmtKind::Expr(expr) => self.visit_expr(expr)?,
+            StmtKind::Break | StmtKind::Continue | StmtKind::Placeholder | StmtKind::Err(_) => {}
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_expr(&mut self, expr: &'hir Expr<'hir>) -> ControlFlow<Self::BreakValue> {
+        self.walk_expr(expr)
+    }
+
+    fn walk_expr(&mut self, expr: &'hir Expr<'hir>) -> ControlFlow<Self::BreakValue> {
+        match expr.kind {
+            ExprKind::Array(exprs) => {
+                for expr in exprs {
+                    self.visit_expr(expr)?;
+                }
+            }
+            ExprKind::Assign(lhs, _, rhs) | ExprKind::Binary(lhs, _, rhs) => {
+                self.visit_expr(lhs)?;
+                self.visit_expr(rhs)?;
+            }
+            ExprKind::Call(callee, ref args) => {
+                self.visit_expr(callee)?;
+                self.visit_call_args(args)?;
+            }
+            ExprKind::CallOptions(callee, options) => {
+                self.visit_expr(callee)?;
+                for option in options {
+                    self.visit_expr(&option.value)?;
+                }
+            }
+            ExprKind::Delete(expr)
+            | ExprKind::Member(expr, _)
+            | ExprKind::Payable(expr)
+            | ExprKind::Unary(_, expr) => self.visit_expr(expr)?,
+            ExprKind::Index(expr, index) => {
+                self.visit_expr(expr)?;
+                if let Some(index) = index {
+                    self.visit_expr(index)?;
+                }
+            }
+            ExprKind::Slice(expr, start, end) => {
+                self.visit_expr(expr)?;
+                for expr in [start, end].into_iter().flatten() {
+                    self.visit_expr(expr)?;
+                }
+            }
+            ExprKind::Ternary(cond, true_, false_) => {
+                self.visit_expr(cond)?;
+                self.visit_expr(true_)?;
+                self.visit_expr(false_)?;
+            }
+            ExprKind::Tuple(exprs) => {
+                for expr in exprs.iter().copied().flatten() {
+                    self.visit_expr(expr)?;
+                }
+            }
+            ExprKind::New(ref ty) | ExprKind::TypeCall(ref ty) | ExprKind::Type(ref ty) => {
+                self.visit_ty(ty)?;
+            }
+            ExprKind::Ident(_) | ExprKind::Lit(_) | ExprKind::Err(_) => {}
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_call_args(&mut self, args: &'hir CallArgs<'hir>) -> ControlFlow<Self::BreakValue> {
+        self.walk_call_args(args)
+    }
+
+    fn walk_call_args(&mut self, args: &'hir CallArgs<'hir>) -> ControlFlow<Self::BreakValue> {
+        match *args {
+            CallArgs::Unnamed(exprs) => {
+                for expr in exprs {
+                    self.visit_expr(expr)?;
+                }
+            }
+            CallArgs::Named(args) => {
+                for arg in args {
+                    self.visit_expr(&arg.value)?;
+                }
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_ty(&mut self, ty: &'hir Type<'hir>) -> ControlFlow<Self::BreakValue> {
+        self.walk_ty(ty)
+    }
+
+    fn walk_ty(&mut self, ty: &'hir Type<'hir>) -> ControlFlow<Self::BreakValue> {
+        match ty.kind {
+            TypeKind::Elementary(_) | TypeKind::Custom(_) | TypeKind::Err(_) => {}
+            TypeKind::Array(array) => {
+                self.visit_ty(&array.element)?;
+                if let Some(size) = array.size {
+                    self.visit_expr(size)?;
+                }
+            }
+            TypeKind::Function(func) => {
+                for ty in func.parameters.iter().chain(func.returns) {
+                    self.visit_ty(ty)?;
+                }
+            }
+            TypeKind::Mapping(mapping) => {
+                self.visit_ty(&mapping.key)?;
+                self.visit_ty(&mapping.value)?;
+            }
+        }
+        ControlFlow::Continue(())
+    }
+}
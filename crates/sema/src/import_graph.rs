@@ -0,0 +1,214 @@
+//! The file-level import graph of the parsed sources.
+
+use crate::{ast, ParsedSources};
+use serde::Serialize;
+use solar_interface::{config::ImportGraphFormat, Result, Session};
+use std::{
+    fmt::{self, Write as _},
+    io::{self, Write},
+};
+
+/// The file-level import graph of the parsed sources.
+///
+/// The graph may contain cycles, since Solidity allows source files to import each other.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct ImportGraph {
+    /// The names of the source files, in loading order.
+    pub nodes: Vec<String>,
+    /// The resolved imports, in source order. Unresolved imports are not included.
+    pub edges: Vec<ImportEdge>,
+}
+
+/// An import of a source file by another. See [`ImportGraph`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+pub struct ImportEdge {
+    /// The index of the importing file in [`ImportGraph::nodes`].
+    pub from: usize,
+    /// The index of the imported file in [`ImportGraph::nodes`].
+    pub to: usize,
+    /// The kind of the import directive.
+    pub kind: ImportKind,
+}
+
+/// The kind of an import directive. See [`ast::ImportItems`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportKind {
+    /// `import "path";` or `import "path" as alias;`
+    Plain,
+    /// `import { a, b as c } from "path";`
+    Aliases,
+    /// `import * as alias from "path";`
+    Glob,
+}
+
+impl ImportKind {
+    /// Returns the string representation of the import kind.
+    pub const fn to_str(self) -> &'static str {
+        match self {
+            Self::Plain => "plain",
+            Self::Aliases => "aliases",
+            Self::Glob => "glob",
+        }
+    }
+}
+
+impl From<&ast::ImportItems<'_>> for ImportKind {
+    fn from(items: &ast::ImportItems<'_>) -> Self {
+        match items {
+            ast::ImportItems::Plain(_) => Self::Plain,
+            ast::ImportItems::Aliases(_) => Self::Aliases,
+            ast::ImportItems::Glob(_) => Self::Glob,
+        }
+    }
+}
+
+impl fmt::Display for ImportKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.to_str())
+    }
+}
+
+impl ImportGraph {
+    /// Builds the import graph of the given sources.
+    ///
+    /// Must be called after import resolution, i.e. after [`ParsingContext::parse`].
+    ///
+    /// [`ParsingContext::parse`]: crate::ParsingContext::parse
+    pub fn new(sess: &Session, sources: &ParsedSources<'_>) -> Self {
+        let nodes = sources
+            .iter()
+            .map(|source| sess.source_map().filename_for_diagnostics(&source.file.name).to_string())
+            .collect();
+        let edges = sources
+            .iter_enumerated()
+            .flat_map(|(from, source)| {
+                source.imports.iter().map(move |&(item_id, to)| {
+                    let kind = match source.ast.as_ref().map(|ast| &ast.items[item_id].kind) {
+                        Some(ast::ItemKind::Import(import)) => ImportKind::from(&import.items),
+                        _ => ImportKind::Plain,
+                    };
+                    ImportEdge { from: from.index(), to: to.index(), kind }
+                })
+            })
+            .collect();
+        Self { nodes, edges }
+    }
+
+    /// Renders the graph in the Graphviz DOT format.
+    pub fn to_dot(&self) -> String {
+        let mut s = String::from("digraph imports {\n");
+        for (i, node) in self.nodes.iter().enumerate() {
+            let _ = writeln!(s, "    {i} [label={}];", DotStr(node));
+        }
+        for edge in &self.edges {
+            let _ = writeln!(
+                s,
+                "    {} -> {} [label={}];",
+                edge.from,
+                edge.to,
+                DotStr(edge.kind.to_str())
+            );
+        }
+        s.push_str("}\n");
+        s
+    }
+
+    /// Renders the graph as JSON.
+    pub fn to_json(&self, pretty: bool) -> String {
+        let json =
+            if pretty { serde_json::to_string_pretty(self) } else { serde_json::to_string(self) };
+        json.expect("failed to serialize import graph")
+    }
+
+    /// Renders the graph in the given format.
+    pub fn render(&self, format: ImportGraphFormat, pretty_json: bool) -> String {
+        match format {
+            ImportGraphFormat::Dot => self.to_dot(),
+            ImportGraphFormat::Json => self.to_json(pretty_json),
+        }
+    }
+}
+
+/// Formats a string as a quoted DOT identifier.
+struct DotStr<'a>(&'a str);
+
+impl fmt::Display for DotStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('"')?;
+        for c in self.0.chars() {
+            if matches!(c, '"' | '\\') {
+                f.write_char('\\')?;
+            }
+            f.write_char(c)?;
+        }
+        f.write_char('"')
+    }
+}
+
+/// Emits the import graph of the given sources if requested with [`Session::import_graph`].
+pub(crate) fn emit(sess: &Session, sources: &ParsedSources<'_>) -> Result<()> {
+    let Some(format) = sess.import_graph else { return Ok(()) };
+    let graph = ImportGraph::new(sess, sources);
+    let mut rendered = graph.render(format, sess.pretty_json);
+    if !rendered.ends_with('\n') {
+        rendered.push('\n');
+    }
+    (|| {
+        let out_path = sess.out_dir.as_deref().map(|dir| {
+            let extension = match format {
+                ImportGraphFormat::Dot => "dot",
+                ImportGraphFormat::Json => "json",
+            };
+            dir.join(format!("import_graph.{extension}"))
+        });
+        let mut writer = crate::emit::out_writer(out_path.as_deref())?;
+        writer.write_all(rendered.as_bytes())?;
+        writer.flush()
+    })()
+    .map_err(|e: io::Error| sess.dcx.err(format!("failed to write to output: {e}")).emit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParsingContext;
+    use solar_interface::ColorChoice;
+    use std::path::Path;
+    use thread_local::ThreadLocal;
+
+    #[test]
+    fn three_files_with_cycle() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../tests/ui/resolve");
+        let sess = Session::builder().with_buffer_emitter(ColorChoice::Never).build();
+        sess.enter(|| {
+            let mut pcx = ParsingContext::new(&sess);
+            pcx.load_files([dir.join("import_graph.sol")]).unwrap();
+            let arenas = ThreadLocal::<ast::Arena>::new();
+            let sources = pcx.parse(&arenas);
+            let graph = ImportGraph::new(&sess, &sources);
+
+            let names = graph
+                .nodes
+                .iter()
+                .map(|node| Path::new(node).file_name().unwrap().to_str().unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(names, ["import_graph.sol", "import_graph_a.sol", "import_graph_b.sol"]);
+            let edges = graph.edges.iter().map(|e| (e.from, e.to, e.kind)).collect::<Vec<_>>();
+            assert_eq!(
+                edges,
+                [
+                    (0, 1, ImportKind::Plain),
+                    (0, 2, ImportKind::Aliases),
+                    (1, 2, ImportKind::Glob),
+                    (2, 0, ImportKind::Plain),
+                ]
+            );
+
+            let dot = graph.to_dot();
+            assert!(dot.starts_with("digraph imports {\n"), "{dot}");
+            assert!(dot.contains("    2 -> 0 [label=\"plain\"];\n"), "{dot}");
+        });
+        assert!(sess.emitted_diagnostics().unwrap().is_ok());
+    }
+}
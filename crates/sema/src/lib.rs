@@ -10,7 +10,7 @@
 extern crate tracing;
 
 use rayon::prelude::*;
-use solar_data_structures::{trustme, OnDrop};
+use solar_data_structures::{map::FxHashSet, trustme, OnDrop};
 use solar_interface::{config::CompilerStage, Result, Session};
 use thread_local::ThreadLocal;
 use ty::Gcx;
@@ -30,35 +30,68 @@ pub use parse::{ParsedSource, ParsedSources, ParsingContext};
 pub mod builtins;
 pub mod eval;
 pub mod hir;
+pub mod lint;
 pub mod ty;
 
 mod typeck;
 
 mod emit;
+pub use emit::AST_JSON_SCHEMA_VERSION;
+
+mod import_graph;
+pub use import_graph::{ImportEdge, ImportGraph, ImportKind};
+
+mod unused;
+pub use unused::{UnusedKind, UnusedReport, UnusedSymbol};
+
+mod compile;
+pub use compile::{compile, CompileError};
 
 /// Parses and semantically analyzes all the loaded sources, recursing into imports.
 pub fn parse_and_resolve(pcx: ParsingContext<'_>) -> Result<()> {
+    run(pcx).map_err(Into::into)
+}
+
+/// Runs the compilation pipeline on the loaded sources, reporting the stage that failed.
+fn run(pcx: ParsingContext<'_>) -> Result<(), CompileError> {
     let sess = pcx.sess;
 
     if pcx.sources.is_empty() {
         let msg = "no files found";
         let note = "if you wish to use the standard input, please specify `-` explicitly";
-        return Err(sess.dcx.err(msg).note(note).emit());
+        return Err(CompileError::InvalidConfig(sess.dcx.err(msg).note(note).emit()));
     }
 
+    lint::check_lint_levels(sess);
+
     let ast_arenas = OnDrop::new(ThreadLocal::<ast::Arena>::new(), |mut arenas| {
         debug!(asts_allocated = arenas.iter_mut().map(|a| a.allocated_bytes()).sum::<usize>());
         debug_span!("dropping_ast_arenas").in_scope(|| drop(arenas));
     });
     let mut sources = pcx.parse(&ast_arenas);
 
+    if sess.unresolved_imports.is_fatal() {
+        if let Some(guar) = sources.unresolved_imports().next() {
+            return Err(CompileError::ResolutionFailed(guar));
+        }
+    }
+
     if let Some(dump) = &sess.dump {
         if dump.kind.is_ast() {
-            dump_ast(sess, &sources, dump.paths.as_deref())?;
+            dump_ast(sess, &sources, dump.paths.as_deref()).map_err(CompileError::InvalidConfig)?;
         }
     }
 
+    import_graph::emit(sess, &sources).map_err(CompileError::InvalidConfig)?;
+
     if sess.language.is_yul() || sess.stop_after(CompilerStage::Parsed) {
+        if let Err(guar) = sess.dcx.has_errors() {
+            return Err(if sess.dcx.err_count() > sources.unresolved_imports().count() {
+                CompileError::ParseFailed(guar)
+            } else {
+                CompileError::ResolutionFailed(guar)
+            });
+        }
         return Ok(());
     }
 
@@ -82,10 +115,11 @@ pub fn parse_and_resolve(pcx: ParsingContext<'_>) -> Result<()> {
         }
     });
 
-    let global_context =
-        OnDrop::new(ty::GlobalCtxt::new(sess, &hir_arena, hir, symbol_resolver), |gcx| {
-            debug_span!("drop_gcx").in_scope(|| drop(gcx));
-        });
+    let selected_contracts = select_contracts(sess, &hir).map_err(CompileError::InvalidConfig)?;
+    let global_context = OnDrop::new(
+        ty::GlobalCtxt::new(sess, &hir_arena, hir, symbol_resolver, selected_contracts),
+        |gcx| debug_span!("drop_gcx").in_scope(|| drop(gcx)),
+    );
     let gcx = ty::Gcx::new(unsafe { trustme::decouple_lt(&global_context) });
     analysis(gcx)?;
 
@@ -93,27 +127,76 @@ pub fn parse_and_resolve(pcx: ParsingContext<'_>) -> Result<()> {
 }
 
 /// Lowers the parsed ASTs into the HIR.
+///
+/// Fails with [`CompileError::ParseFailed`] if the sources could not be parsed, and with
+/// [`CompileError::AnalysisFailed`] if only the AST passes emitted errors.
 fn lower<'sess, 'hir>(
     sess: &'sess Session,
     sources: &ParsedSources<'_>,
     arena: &'hir hir::Arena,
-) -> Result<(hir::Hir<'hir>, ast_lowering::SymbolResolver<'sess>)> {
+) -> Result<(hir::Hir<'hir>, ast_lowering::SymbolResolver<'sess>), CompileError> {
+    // Unresolved imports are lowered as placeholders, so they alone do not prevent lowering.
+    let unresolved_imports = sources.unresolved_imports().count();
+    let parse_failed = sess.dcx.err_count() > unresolved_imports;
+
     debug_span!("all_ast_passes").in_scope(|| {
-        sources.par_asts().for_each(|ast| {
-            ast_passes::run(sess, ast);
+        sources.as_raw_slice().par_iter().for_each(|source| {
+            if let Some(ast) = &source.ast {
+                ast_passes::run(sess, &source.file, ast);
+            }
         });
     });
 
-    sess.dcx.has_errors()?;
+    if sess.dcx.err_count() > unresolved_imports {
+        if let Err(guar) = sess.dcx.has_errors() {
+            return Err(if parse_failed {
+                CompileError::ParseFailed(guar)
+            } else {
+                CompileError::AnalysisFailed(guar)
+            });
+        }
+    }
 
     Ok(ast_lowering::lower(sess, sources, arena))
 }
 
+/// Returns the contracts named in [`Session::contracts`], or `None` if no names were given.
+///
+/// All the names are checked before returning an error for the ones that match no contract.
+fn select_contracts(
+    sess: &Session,
+    hir: &hir::Hir<'_>,
+) -> Result<Option<FxHashSet<hir::ContractId>>> {
+    if sess.contracts.is_empty() {
+        return Ok(None);
+    }
+
+    let mut selected = FxHashSet::default();
+    let mut guar = None;
+    for name in &sess.contracts {
+        let mut matches =
+            hir.contracts_enumerated().filter(|(_, c)| c.name.as_str() == name).peekable();
+        if matches.peek().is_none() {
+            let msg = format!("`--contract {name}` did not match any contract");
+            let mut names = hir.contracts().map(|c| c.name.as_str()).collect::<Vec<_>>();
+            names.sort_unstable();
+            names.dedup();
+            let note = format!("available contracts: {}", names.join(", "));
+            guar = Some(sess.dcx.err(msg).note(note).emit());
+        }
+        selected.extend(matches.map(|(id, _)| id));
+    }
+    match guar {
+        Some(guar) => Err(guar),
+        None => Ok(Some(selected)),
+    }
+}
+
 #[instrument(level = "debug", skip_all)]
-fn analysis(gcx: Gcx<'_>) -> Result<()> {
+fn analysis(gcx: Gcx<'_>) -> Result<(), CompileError> {
     if let Some(dump) = &gcx.sess.dump {
         if dump.kind.is_hir() {
-            dump_hir(gcx, dump.paths.as_deref())?;
+            dump_hir(gcx, dump.paths.as_deref()).map_err(CompileError::InvalidConfig)?;
         }
     }
 
@@ -124,19 +207,27 @@ fn analysis(gcx: Gcx<'_>) -> Result<()> {
             let _ = gcx.struct_field_types(id);
         }
     });
-    gcx.sess.dcx.has_errors()?;
+    // Lowering does not stop on name resolution errors, so they are reported here.
+    gcx.sess.dcx.has_errors().map_err(CompileError::ResolutionFailed)?;
 
     gcx.hir.par_contract_ids().for_each(|id| {
-        let _ = gcx.interface_functions(id);
+        if gcx.is_contract_analyzed(Some(id)) {
+            let _ = gcx.interface_functions(id);
+        }
     });
-    gcx.sess.dcx.has_errors()?;
+    gcx.sess.dcx.has_errors().map_err(CompileError::AnalysisFailed)?;
 
     typeck::check(gcx);
-    gcx.sess.dcx.has_errors()?;
+    unused::emit(gcx).map_err(CompileError::AnalysisFailed)?;
+    gcx.sess.dcx.has_errors().map_err(CompileError::AnalysisFailed)?;
+
+    if gcx.sess.stop_after(CompilerStage::Analyzed) {
+        return Ok(());
+    }
 
     if !gcx.sess.emit.is_empty() {
         emit::emit(gcx);
-        gcx.sess.dcx.has_errors()?;
+        gcx.sess.dcx.has_errors().map_err(CompileError::AnalysisFailed)?;
     }
 
     Ok(())
@@ -153,7 +244,9 @@ fn dump_ast(sess: &Session, sources: &ParsedSources<'_>, paths: Option<&[String]
                     "available source files: {}",
                     sources
                         .iter()
-                        .map(|s| s.file.name.display().to_string())
+                        .map(|s| {
+                            sess.source_map().filename_for_diagnostics(&s.file.name).to_string()
+                        })
                         .collect::<Vec<_>>()
                         .join(", ")
                 );
@@ -0,0 +1,202 @@
+//! Lint passes.
+//!
+//! A lint implements the [`Lint`] trait, and is run on the analyzed sources after type checking
+//! if it is not [allowed](solar_interface::config::LintLevel::Allow). Custom lints are registered
+//! on the session with [`register`], and run alongside the built-in lints.
+//!
+//! See [`solar_interface::lint`] for the lint metadata and the lint levels.
+
+use crate::{hir, ty::Gcx, typeck};
+use solar_interface::{
+    diagnostics::{DiagnosticBuilder, DiagnosticMessage, MultiSpan},
+    Session,
+};
+use std::sync::Arc;
+
+pub use solar_interface::lint::{builtin, LintInfo, LintLevels};
+
+/// A lint pass.
+///
+/// The `check_*` methods are called once for each item of the analyzed sources, possibly in
+/// parallel. They report their findings with [`LintContext::struct_lint`] and
+/// [`LintContext::struct_lint_with`], which respect the level configured for the lint.
+pub trait Lint: Send + Sync {
+    /// Returns the metadata of the lint: its name, default level and message.
+    fn info(&self) -> &'static LintInfo;
+
+    /// Checks a source.
+    fn check_source(&self, _cx: &LintContext<'_>, _id: hir::SourceId) {}
+
+    /// Checks an analyzed contract.
+    fn check_contract(&self, _cx: &LintContext<'_>, _id: hir::ContractId) {}
+
+    /// Checks a function of an analyzed contract or a free function. This includes modifiers.
+    fn check_function(&self, _cx: &LintContext<'_>, _id: hir::FunctionId) {}
+}
+
+/// The context in which a [`Lint`] is run.
+#[derive(Clone, Copy)]
+pub struct LintContext<'gcx> {
+    gcx: Gcx<'gcx>,
+    lint: &'static LintInfo,
+}
+
+impl<'gcx> LintContext<'gcx> {
+    pub(crate) fn new(gcx: Gcx<'gcx>, lint: &'static LintInfo) -> Self {
+        Self { gcx, lint }
+    }
+
+    /// Returns the global context.
+    #[inline]
+    pub fn gcx(&self) -> Gcx<'gcx> {
+        self.gcx
+    }
+
+    /// Returns the metadata of the lint being run.
+    #[inline]
+    pub fn lint(&self) -> &'static LintInfo {
+        self.lint
+    }
+
+    /// Creates a diagnostic for the lint with its [message](LintInfo::message).
+    ///
+    /// Returns `None` if the lint is allowed.
+    #[track_caller]
+    pub fn struct_lint(&self, span: impl Into<MultiSpan>) -> Option<DiagnosticBuilder<'gcx, ()>> {
+        self.struct_lint_with(span, self.lint.message)
+    }
+
+    /// Creates a diagnostic for the lint with the given message.
+    ///
+    /// Returns `None` if the lint is allowed.
+    #[track_caller]
+    pub fn struct_lint_with(
+        &self,
+        span: impl Into<MultiSpan>,
+        msg: impl Into<DiagnosticMessage>,
+    ) -> Option<DiagnosticBuilder<'gcx, ()>> {
+        self.gcx.sess.struct_lint(self.lint, span, msg)
+    }
+}
+
+/// Registers a custom lint on the session.
+///
+/// The lint is run alongside the built-in lints, at the level configured for it in
+/// [`Session::lint_levels`], or at its default level.
+pub fn register(sess: &mut Session, lint: impl Lint + 'static) {
+    let lint: Box<dyn Lint> = Box::new(lint);
+    sess.lints.register_erased(Arc::new(lint));
+}
+
+/// Returns the custom lints registered on the session with [`register`].
+pub fn registered(sess: &Session) -> impl Iterator<Item = &dyn Lint> {
+    sess.lints.iter_erased().filter_map(|lint| lint.downcast_ref::<Box<dyn Lint>>()).map(|l| &**l)
+}
+
+/// The built-in lint passes.
+static BUILTIN_PASSES: &[&dyn Lint] = &[
+    &typeck::UnusedImports,
+    &typeck::UnusedFunctions,
+    &typeck::UnusedVariables,
+    &typeck::UnusedErrors,
+    &typeck::UnusedEvents,
+    &typeck::UnusedPayable,
+    &typeck::MsgDataInInternal,
+    &typeck::UnchangedLoopCondition,
+    &typeck::CyclomaticComplexity,
+    &typeck::AssertFalse,
+    &typeck::ManualDispatch,
+    &typeck::DelegatecallStorage,
+];
+
+/// Returns the built-in and custom lint passes that are not allowed.
+pub(crate) fn enabled_lints(sess: &Session) -> Vec<&dyn Lint> {
+    BUILTIN_PASSES
+        .iter()
+        .copied()
+        .chain(registered(sess))
+        .filter(|lint| sess.is_lint_enabled(lint.info()))
+        .collect()
+}
+
+/// Warns about configured lint levels that do not match any built-in or custom lint.
+pub(crate) fn check_lint_levels(sess: &Session) {
+    for name in sess.lint_levels.names() {
+        if builtin::find(name).is_none() && !registered(sess).any(|lint| lint.info().name == name) {
+            sess.dcx.warn(format!("unknown lint `{name}`")).emit();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParsingContext;
+    use solar_interface::{config::LintLevel, ColorChoice};
+
+    static CONTRACT_NAMES: LintInfo =
+        LintInfo { name: "contract-names", default_level: LintLevel::Allow, message: "contract" };
+
+    struct ContractNames;
+
+    impl Lint for ContractNames {
+        fn info(&self) -> &'static LintInfo {
+            &CONTRACT_NAMES
+        }
+
+        fn check_contract(&self, cx: &LintContext<'_>, id: hir::ContractId) {
+            let contract = cx.gcx().hir.contract(id);
+            let msg = format!("contract `{}`", contract.name);
+            if let Some(diag) = cx.struct_lint_with(contract.name.span, msg) {
+                diag.emit();
+            }
+        }
+    }
+
+    fn lint_src(sess: &Session, src: &str) -> String {
+        let _ = sess.enter(|| {
+            let file =
+                sess.source_map().new_dummy_source_file("test.sol".into(), src.into()).unwrap();
+            let mut pcx = ParsingContext::new(sess);
+            pcx.add_file(file);
+            crate::parse_and_resolve(pcx)
+        });
+        match sess.emitted_diagnostics().unwrap() {
+            Ok(()) => String::new(),
+            Err(diags) => diags.to_string(),
+        }
+    }
+
+    #[test]
+    fn custom_lint() {
+        let src = "contract A {} contract B {}";
+
+        let mut sess = Session::builder().with_buffer_emitter(ColorChoice::Never).build();
+        register(&mut sess, ContractNames);
+        assert_eq!(registered(&sess).count(), 1);
+        assert_eq!(lint_src(&sess, src), "");
+
+        let mut sess = Session::builder().with_buffer_emitter(ColorChoice::Never).build();
+        register(&mut sess, ContractNames);
+        sess.lint_levels.set(CONTRACT_NAMES.name, LintLevel::Deny);
+        sess.lint_levels.set("no-such-lint", LintLevel::Warn);
+        let diags = lint_src(&sess, src);
+        assert!(diags.contains("error: contract `A`"), "{diags}");
+        assert!(diags.contains("error: contract `B`"), "{diags}");
+        assert!(diags.contains("warning: unknown lint `no-such-lint`"), "{diags}");
+    }
+
+    #[test]
+    fn builtin_lint_levels() {
+        let src = "contract C { event E(); }";
+
+        let sess = Session::builder().with_buffer_emitter(ColorChoice::Never).build();
+        assert!(!sess.is_lint_enabled(&builtin::UNUSED_EVENTS));
+        assert_eq!(lint_src(&sess, src), "");
+
+        let mut sess = Session::builder().with_buffer_emitter(ColorChoice::Never).build();
+        sess.lint_levels.set(builtin::UNUSED_EVENTS.name, LintLevel::Deny);
+        let diags = lint_src(&sess, src);
+        assert!(diags.contains("error: event `E` is never emitted"), "{diags}");
+    }
+}
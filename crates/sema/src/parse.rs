@@ -6,9 +6,9 @@ use solar_data_structures::{
     map::FxHashSet,
 };
 use solar_interface::{
-    diagnostics::DiagCtxt,
+    diagnostics::{DiagCtxt, ErrorGuaranteed},
     source_map::{FileName, FileResolver, SourceFile},
-    Result, Session,
+    Result, Session, Span,
 };
 use solar_parse::{unescape, Lexer, Parser};
 use std::{borrow::Cow, fmt, path::Path, sync::Arc};
@@ -83,7 +83,7 @@ impl<'sess> ParsingContext<'sess> {
 
     /// Adds a preloaded file to the resolver.
     pub fn add_file(&mut self, file: Arc<SourceFile>) {
-        self.sources.add_file(file);
+        self.sources.add_file(file, 0);
     }
 
     pub fn parse_and_resolve(self) -> Result<()> {
@@ -112,6 +112,11 @@ impl<'sess> ParsingContext<'sess> {
                 total_lines = sources.iter().map(|s| s.file.count_lines()).sum::<usize>(),
                 "parsed",
             );
+            if self.sess.collect_span_tables {
+                for source in sources.iter_mut() {
+                    source.span_table = source.ast.as_ref().map(ast::SpanTable::new);
+                }
+            }
         }
         sources.assert_unique();
         sources
@@ -123,16 +128,21 @@ impl<'sess> ParsingContext<'sess> {
             let Some(source) = sources.get(current_file) else { break };
             debug_assert!(source.ast.is_none(), "source already parsed");
 
-            let ast = self.parse_one(&source.file, arena);
+            let (ast, recovery_points) = self.parse_one(&source.file, arena);
             let n_sources = sources.len();
-            for (import_item_id, import) in resolve_imports!(self, &source.file, ast.as_ref()) {
-                sources.add_import(current_file, import_item_id, import);
+            for (import_item_id, import, span) in resolve_imports!(self, &source.file, ast.as_ref())
+            {
+                self.add_import(sources, current_file, import_item_id, import, span);
             }
             let new_files = sources.len() - n_sources;
             if new_files > 0 {
                 trace!(new_files);
             }
             sources[current_file].ast = ast;
+            sources[current_file].recovery_points = recovery_points;
+            if self.should_stop_parsing(sources) {
+                break;
+            }
         }
     }
 
@@ -155,43 +165,86 @@ impl<'sess> ParsingContext<'sess> {
                 .enumerate()
                 .flat_map_iter(|(i, source)| {
                     debug_assert!(source.ast.is_none(), "source already parsed");
-                    source.ast = self.parse_one(&source.file, arenas.get_or_default());
+                    let (ast, recovery_points) =
+                        self.parse_one(&source.file, arenas.get_or_default());
+                    source.ast = ast;
+                    source.recovery_points = recovery_points;
                     resolve_imports!(self, &source.file, source.ast.as_ref())
                         .map(move |import| (i, import))
                 })
                 .collect_vec_list();
             let n_sources = sources.len();
-            for (i, (import_item_id, import)) in imports.into_iter().flatten() {
-                sources.add_import(SourceId::from_usize(base + i), import_item_id, import);
+            for (i, (import_item_id, import, span)) in imports.into_iter().flatten() {
+                let current = SourceId::from_usize(base + i);
+                self.add_import(sources, current, import_item_id, import, span);
             }
             let new_files = sources.len() - n_sources;
             if new_files > 0 {
                 trace!(new_files);
             }
+            if self.should_stop_parsing(sources) {
+                break;
+            }
         }
     }
 
-    /// Parses a single file.
+    /// Adds an import of the `current` source, unless the imported file would be nested deeper
+    /// than [`Session::max_import_depth`], in which case it is reported as unresolved.
+    fn add_import(
+        &self,
+        sources: &mut ParsedSources<'_>,
+        current: SourceId,
+        import_item_id: ast::ItemId,
+        import: Result<Arc<SourceFile>, ErrorGuaranteed>,
+        span: Span,
+    ) {
+        let max_depth = self.sess.max_import_depth;
+        let import = import.and_then(|file| {
+            // Files that are already loaded are not imported any deeper.
+            if sources[current].depth < max_depth || sources.find_file(&file).is_some() {
+                return Ok(file);
+            }
+            let msg = format!("maximum import depth of {max_depth} exceeded");
+            let depth = sources[current].depth + 1;
+            let note = format!("the imported file would be nested {depth} imports deep");
+            let help = "increase the limit with `--max-import-depth`";
+            Err(self.dcx().err(msg).span(span).note(note).help(help).emit())
+        });
+        sources.add_import(current, import_item_id, import);
+    }
+
+    /// Returns `true` if an import could not be resolved and unresolved imports are fatal.
+    fn should_stop_parsing(&self, sources: &ParsedSources<'_>) -> bool {
+        self.sess.unresolved_imports.is_fatal() && sources.unresolved_imports().next().is_some()
+    }
+
+    /// Parses a single file, returning its AST and the spans at which the parser recovered.
     #[instrument(level = "debug", skip_all, fields(file = %file.name.display()))]
     fn parse_one<'ast>(
         &self,
         file: &SourceFile,
         arena: &'ast ast::Arena,
-    ) -> Option<ast::SourceUnit<'ast>> {
+    ) -> (Option<ast::SourceUnit<'ast>>, Vec<Span>) {
         let lexer = Lexer::from_source_file(self.sess, file);
         let mut parser = Parser::from_lexer(arena, lexer);
         let r = if self.sess.language.is_yul() {
             let _file = parser.parse_yul_file_object().map_err(|e| e.emit());
             None
         } else {
-            parser.parse_file().map_err(|e| e.emit()).ok()
+            let (unit, err) = parser.parse_file_partial();
+            if let Some(err) = err {
+                err.emit();
+            }
+            Some(unit)
         };
         trace!(allocated = arena.allocated_bytes(), used = arena.used_bytes(), "AST arena stats");
-        r
+        (r, parser.take_recovery_points())
     }
 }
 
-/// Resolves the imports of the given file, returning an iterator over all the imported files.
+/// Resolves the imports of the given file, returning an iterator over all the imported files, or
+/// the errors emitted for the imports that could not be resolved, along with the spans of the
+/// import directives.
 ///
 /// This is currently a macro as I have not figured out how to win against the borrow checker to
 /// return `impl Iterator` instead of having to collect, since it obviously isn't necessary given
@@ -223,11 +276,11 @@ macro_rules! resolve_imports {
                     this.dcx().err("import path is not a valid UTF-8 string").span(span).emit();
                     return None;
                 };
-                this.file_resolver
+                let file = this
+                    .file_resolver
                     .resolve_file(path, parent.as_deref())
-                    .map_err(|e| this.dcx().err(e.to_string()).span(span).emit())
-                    .ok()
-                    .map(|file| (id, file))
+                    .map_err(|e| this.dcx().err(e.to_string()).span(span).emit());
+                Some((id, file, span))
             })
     }};
 }
@@ -274,25 +327,43 @@ impl ParsedSources<'_> {
         Self { sources: IndexVec::new() }
     }
 
+    /// Returns the errors emitted for all the imports that could not be resolved.
+    pub fn unresolved_imports(&self) -> impl Iterator<Item = ErrorGuaranteed> + '_ {
+        self.sources.iter().flat_map(|source| source.unresolved_imports.iter().map(|&(_, e)| e))
+    }
+
     fn add_import(
         &mut self,
         current: SourceId,
         import_item_id: ast::ItemId,
-        import: Arc<SourceFile>,
+        import: Result<Arc<SourceFile>, ErrorGuaranteed>,
     ) {
-        let import_id = self.add_file(import);
-        self.sources[current].imports.push((import_item_id, import_id));
+        match import {
+            Ok(import) => {
+                let import_id = self.add_file(import, self.sources[current].depth + 1);
+                self.sources[current].imports.push((import_item_id, import_id));
+            }
+            Err(guar) => self.sources[current].unresolved_imports.push((import_item_id, guar)),
+        }
     }
 
+    /// Adds a file at the given import depth, returning the ID of the existing source if the file
+    /// was already added.
     #[instrument(level = "debug", skip_all)]
-    fn add_file(&mut self, file: Arc<SourceFile>) -> SourceId {
-        if let Some((id, _)) =
-            self.sources.iter_enumerated().find(|(_, source)| Arc::ptr_eq(&source.file, &file))
-        {
+    fn add_file(&mut self, file: Arc<SourceFile>, depth: usize) -> SourceId {
+        if let Some(id) = self.find_file(&file) {
             trace!(file = %file.name.display(), "skipping duplicate source file");
             return id;
         }
-        self.sources.push(ParsedSource::new(file))
+        self.sources.push(ParsedSource { depth, ..ParsedSource::new(file) })
+    }
+
+    /// Returns the ID of the source of the given file, if it was already added.
+    fn find_file(&self, file: &Arc<SourceFile>) -> Option<SourceId> {
+        self.sources
+            .iter_enumerated()
+            .find(|(_, source)| Arc::ptr_eq(&source.file, file))
+            .map(|(id, _)| id)
     }
 
     /// Asserts that all sources are unique.
@@ -383,14 +454,35 @@ pub struct ParsedSource<'ast> {
     pub file: Arc<SourceFile>,
     /// The AST IDs and source IDs of all the imports.
     pub imports: Vec<(ast::ItemId, SourceId)>,
-    /// The AST. `None` if an error occurred during parsing, or if the source is a Yul file.
+    /// The AST IDs of the imports that could not be resolved, and the errors emitted for them.
+    pub unresolved_imports: Vec<(ast::ItemId, ErrorGuaranteed)>,
+    /// The number of nested imports through which the source was first reached. Zero for the
+    /// sources that were loaded directly.
+    pub depth: usize,
+    /// The AST. `None` if the source is a Yul file.
+    ///
+    /// If an error aborted parsing, this contains the items parsed before the error.
     pub ast: Option<ast::SourceUnit<'ast>>,
+    /// The spans at which the parser emitted an error and recovered, in source order.
+    ///
+    /// See [`Parser::recovery_points`].
+    pub recovery_points: Vec<Span>,
+    /// The spans of all the statements and expressions in the AST.
+    ///
+    /// Only collected if [`Session::collect_span_tables`] is set.
+    pub span_table: Option<ast::SpanTable>,
 }
 
 impl fmt::Debug for ParsedSource<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut dbg = f.debug_struct("ParsedSource");
         dbg.field("file", &self.file.name).field("imports", &self.imports);
+        if !self.unresolved_imports.is_empty() {
+            dbg.field("unresolved_imports", &self.unresolved_imports);
+        }
+        if !self.recovery_points.is_empty() {
+            dbg.field("recovery_points", &self.recovery_points);
+        }
         if let Some(ast) = &self.ast {
             dbg.field("ast", &ast);
         }
@@ -401,7 +493,15 @@ impl fmt::Debug for ParsedSource<'_> {
 impl ParsedSource<'_> {
     /// Creates a new empty source.
     pub fn new(file: Arc<SourceFile>) -> Self {
-        Self { file, ast: None, imports: Vec::new() }
+        Self {
+            file,
+            ast: None,
+            imports: Vec::new(),
+            unresolved_imports: Vec::new(),
+            depth: 0,
+            recovery_points: Vec::new(),
+            span_table: None,
+        }
     }
 }
 
@@ -425,3 +525,27 @@ fn sort_by_indices<I: Idx, T>(data: &mut IndexVec<I, T>, mut indices: Vec<I>) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solar_interface::ColorChoice;
+
+    #[test]
+    fn partial_ast() {
+        let src = "contract A {}\ncontract B { function f( }\ncontract C {}\n";
+        let sess = Session::builder().with_buffer_emitter(ColorChoice::Never).build();
+        sess.enter(|| {
+            let file =
+                sess.source_map().new_dummy_source_file("test.sol".into(), src.into()).unwrap();
+            let mut pcx = ParsingContext::new(&sess);
+            pcx.add_file(file);
+            let arenas = ThreadLocal::<ast::Arena>::new();
+            let sources = pcx.parse(&arenas);
+            let ast = sources.asts().next().expect("expected a partial AST");
+            let names = ast.items.iter().map(|item| item.name().unwrap().to_string());
+            assert_eq!(names.collect::<Vec<_>>(), ["A"]);
+            assert!(sess.dcx.has_errors().is_err());
+        });
+    }
+}
@@ -2,18 +2,21 @@ use crate::{
     ast_lowering::SymbolResolver,
     builtins::{members, Builtin},
     hir::{self, Hir},
+    unused::UnusedSymbol,
 };
 use alloy_primitives::{keccak256, Selector, B256};
-use solar_ast::ast::{DataLocation, StateMutability, TypeSize, Visibility};
+use solar_ast::ast::{DataLocation, StateMutability, TypeSize, UserDefinableOperator, Visibility};
 use solar_data_structures::{
     fmt_from_fn,
-    map::{FxBuildHasher, FxHashMap, FxHashSet},
+    map::{FxBuildHasher, FxHashMap, FxHashSet, StdEntry},
+    sync::Lock,
     BumpExt,
 };
 use solar_interface::{
     diagnostics::{DiagCtxt, ErrorGuaranteed},
-    Ident, Session, Span,
+    BytePos, Ident, Session, Span, Symbol,
 };
+use solar_parse::lexer::{token::RawTokenKind, Cursor};
 use std::{
     fmt,
     hash::{BuildHasher, Hash},
@@ -73,6 +76,86 @@ impl<'gcx> InterfaceFunctions<'gcx> {
     }
 }
 
+/// A member of a contract, either defined in the contract itself or inherited from a base.
+#[derive(Clone, Copy, Debug)]
+pub struct ContractMember<'gcx> {
+    /// The member: a function, modifier, event, error, or state variable.
+    pub id: hir::ItemId,
+    /// The contract the member is defined in.
+    pub contract: hir::ContractId,
+    /// The members of base contracts that this member overrides, most derived first.
+    pub overrides: &'gcx [hir::ItemId],
+}
+
+impl ContractMember<'_> {
+    /// Returns `true` if the member overrides members of base contracts.
+    pub fn is_override(&self) -> bool {
+        !self.overrides.is_empty()
+    }
+}
+
+/// List of all the members of a contract, including inherited ones.
+///
+/// Return type of [`Gcx::contract_members`].
+#[derive(Clone, Copy, Debug)]
+pub struct ContractMembers<'gcx> {
+    /// The members, in linearization order.
+    pub members: &'gcx [ContractMember<'gcx>],
+    /// The index in `members` where the inherited members start.
+    pub inheritance_start: usize,
+}
+
+impl<'gcx> ContractMembers<'gcx> {
+    /// Returns all the members.
+    pub fn all(&self) -> &'gcx [ContractMember<'gcx>] {
+        self.members
+    }
+
+    /// Returns the members defined in the contract itself.
+    pub fn own(&self) -> &'gcx [ContractMember<'gcx>] {
+        &self.members[..self.inheritance_start]
+    }
+
+    /// Returns the inherited members.
+    pub fn inherited(&self) -> &'gcx [ContractMember<'gcx>] {
+        &self.members[self.inheritance_start..]
+    }
+}
+
+/// A struct field access, one step of a member access chain like `a.b.c`.
+///
+/// Return type of [`Gcx::struct_member`] and [`Gcx::struct_member_chain`].
+#[derive(Clone, Copy, Debug)]
+pub struct StructMemberAccess<'gcx> {
+    /// The accessed struct.
+    pub strukt: hir::StructId,
+    /// The accessed field.
+    pub field: hir::VariableId,
+    /// The name of the member, with the span of the access.
+    pub member: Ident,
+    /// The type of the field, in the data location of the accessed struct.
+    pub ty: Ty<'gcx>,
+}
+
+/// An error resolving a struct field access.
+#[derive(Clone, Copy, Debug)]
+pub enum StructMemberError<'gcx> {
+    /// The struct has no field with the name of the member.
+    UnknownField {
+        /// The accessed struct.
+        strukt: hir::StructId,
+        /// The name of the member.
+        member: Ident,
+    },
+    /// The accessed value is not a struct.
+    NotAStruct {
+        /// The type of the accessed value.
+        ty: Ty<'gcx>,
+        /// The name of the member.
+        member: Ident,
+    },
+}
+
 impl<'gcx> std::ops::Deref for InterfaceFunctions<'gcx> {
     type Target = &'gcx [InterfaceFunction<'gcx>];
 
@@ -92,6 +175,25 @@ impl<'gcx> IntoIterator for InterfaceFunctions<'gcx> {
     }
 }
 
+impl<'gcx> std::ops::Deref for ContractMembers<'gcx> {
+    type Target = &'gcx [ContractMember<'gcx>];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.members
+    }
+}
+
+impl<'gcx> IntoIterator for ContractMembers<'gcx> {
+    type Item = &'gcx ContractMember<'gcx>;
+    type IntoIter = std::slice::Iter<'gcx, ContractMember<'gcx>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.members.iter()
+    }
+}
+
 /// Reference to the [global context](GlobalCtxt).
 #[derive(Clone, Copy)]
 #[cfg_attr(feature = "nightly", rustc_pass_by_value)]
@@ -118,6 +220,12 @@ pub struct GlobalCtxt<'gcx> {
     pub types: CommonTypes<'gcx>,
     pub hir: Hir<'gcx>,
     pub(crate) symbol_resolver: SymbolResolver<'gcx>,
+    /// The symbols reported by the `unused-*` lints. See [`UnusedReport`](crate::UnusedReport).
+    pub(crate) unused_symbols: Lock<Vec<UnusedSymbol>>,
+    /// The contracts selected with [`Session::contracts`], or `None` if all contracts are selected.
+    selected_contracts: Option<FxHashSet<hir::ContractId>>,
+    /// The selected contracts along with all of their bases.
+    analyzed_contracts: Option<FxHashSet<hir::ContractId>>,
 
     interner: Interner<'gcx>,
     cache: Cache<'gcx>,
@@ -129,13 +237,21 @@ impl<'gcx> GlobalCtxt<'gcx> {
         arena: &'gcx ThreadLocal<hir::Arena>,
         hir: Hir<'gcx>,
         symbol_resolver: SymbolResolver<'gcx>,
+        selected_contracts: Option<FxHashSet<hir::ContractId>>,
     ) -> Self {
         let interner = Interner::new(arena);
+        let analyzed_contracts = selected_contracts.as_ref().map(|selected| {
+            let bases = |id| hir.contract(id).linearized_bases.iter().copied();
+            selected.iter().flat_map(|&id| std::iter::once(id).chain(bases(id))).collect()
+        });
         Self {
             sess,
             types: CommonTypes::new(&interner),
             hir,
             symbol_resolver,
+            unused_symbols: Lock::default(),
+            selected_contracts,
+            analyzed_contracts,
             interner,
             cache: Cache::default(),
         }
@@ -152,6 +268,25 @@ impl<'gcx> Gcx<'gcx> {
         &self.sess.dcx
     }
 
+    /// Returns `true` if the given contract was selected with [`Session::contracts`], or if no
+    /// contracts were selected.
+    ///
+    /// Only the output of the selected contracts is emitted.
+    pub fn is_contract_selected(self, id: hir::ContractId) -> bool {
+        self.selected_contracts.as_ref().is_none_or(|selected| selected.contains(&id))
+    }
+
+    /// Returns `true` if the given contract is analyzed: it is either selected or a base of a
+    /// selected contract. See [`is_contract_selected`](Self::is_contract_selected).
+    ///
+    /// Items outside of contracts are always analyzed.
+    pub fn is_contract_analyzed(self, id: Option<hir::ContractId>) -> bool {
+        match (id, &self.analyzed_contracts) {
+            (Some(id), Some(analyzed)) => analyzed.contains(&id),
+            _ => true,
+        }
+    }
+
     pub fn arena(self) -> &'gcx hir::Arena {
         self.interner.arena.get_or_default()
     }
@@ -335,6 +470,45 @@ impl<'gcx> Gcx<'gcx> {
         self.hir.item(id).span()
     }
 
+    /// Resolves the access of `member` in a value of type `ty`, which must be a struct.
+    pub fn struct_member(
+        self,
+        ty: Ty<'gcx>,
+        member: Ident,
+    ) -> Result<StructMemberAccess<'gcx>, StructMemberError<'gcx>> {
+        let TyKind::Struct(id) = ty.peel_refs().kind else {
+            return Err(StructMemberError::NotAStruct { ty, member });
+        };
+        let fields = self.hir.strukt(id).fields;
+        let Some(i) = fields.iter().position(|&f| self.item_name(f).name == member.name) else {
+            return Err(StructMemberError::UnknownField { strukt: id, member });
+        };
+        let mut field_ty = self.struct_field_types(id)[i];
+        if let TyKind::Ref(_, loc) = ty.kind {
+            field_ty = field_ty.with_loc(self, loc);
+        }
+        Ok(StructMemberAccess { strukt: id, field: fields[i], member, ty: field_ty })
+    }
+
+    /// Resolves a chain of struct member accesses starting from a value of type `ty`, like the
+    /// members `b` and `c` of `a.b.c` for `a` of type `ty`.
+    ///
+    /// The type of the last member is the type of the whole chain.
+    pub fn struct_member_chain(
+        self,
+        ty: Ty<'gcx>,
+        members: &[Ident],
+    ) -> Result<Vec<StructMemberAccess<'gcx>>, StructMemberError<'gcx>> {
+        let mut ty = ty;
+        let mut steps = Vec::with_capacity(members.len());
+        for &member in members {
+            let step = self.struct_member(ty, member)?;
+            ty = step.ty;
+            steps.push(step);
+        }
+        Ok(steps)
+    }
+
     /// Returns the 4-byte selector of the given item. Only accepts functions and errors.
     ///
     /// # Panics
@@ -488,18 +662,14 @@ pub fn interface_functions(gcx: _, id: hir::ContractId) -> InterfaceFunctions<'g
         let f = gcx.hir.function(f_id);
         let ty = gcx.type_of_item(f_id.into());
         let TyKind::FnPtr(ty_f) = ty.kind else { unreachable!() };
+        let is_library = f.contract.is_some_and(|c| gcx.hir.contract(c).kind.is_library());
         let mut result = Ok(());
         for (var_id, ty) in f.variables().zip(ty_f.tys()) {
-            if !ty.can_be_exported() {
-                let msg = if ty.has_mapping() {
-                    "types containing mappings cannot be parameter or return types of public functions"
-                } else if ty.is_recursive() {
-                    "recursive types cannot be parameter or return types of public functions"
-                } else {
-                    "this type cannot be parameter or return type of a public function"
-                };
+            if let Some(msg) = external_type_error(ty, is_library) {
                 let span = gcx.item_span(var_id);
-                result = Err(gcx.dcx().err(msg).span(span).emit());
+                let kind = if f.parameters.contains(&var_id) { "parameter" } else { "return value" };
+                let note = format!("the type of this {kind} is `{}`", ty.display(gcx));
+                result = Err(gcx.dcx().err(msg).span(span).note(note).emit());
             }
         }
         if result.is_err() {
@@ -520,10 +690,10 @@ pub fn interface_functions(gcx: _, id: hir::ContractId) -> InterfaceFunctions<'g
             let msg = "function signature hash collision";
             let full_note = format!(
                 "the function signatures `{}` and `{}` produce the same 4-byte selector `{selector}`",
-                gcx.item_signature(f_id.into()),
                 gcx.item_signature(prev.into()),
+                gcx.item_signature(f_id.into()),
             );
-            gcx.dcx().err(msg).span(c.name.span).span_note(f.span, "first function").span_note(f2.span, "second function").note(full_note).emit();
+            gcx.dcx().err(msg).span(c.name.span).span_note(f2.span, "first function").span_note(f.span, "second function").note(full_note).emit();
         }
 
         Some(InterfaceFunction { selector, id: f_id, ty })
@@ -534,6 +704,46 @@ pub fn interface_functions(gcx: _, id: hir::ContractId) -> InterfaceFunctions<'g
     InterfaceFunctions { functions, inheritance_start }
 }
 
+/// Returns all the members of the given contract, including inherited ones.
+///
+/// Members are functions, modifiers, events, errors, and state variables. Constructors, `fallback`
+/// and `receive` functions are not included, and neither are private members of base contracts.
+///
+/// A member that is overridden in a more derived contract is not listed itself, but in the
+/// [`overrides`](ContractMember::overrides) of the overriding member. A public state variable
+/// overrides functions through its getter.
+pub fn contract_members(gcx: _, id: hir::ContractId) -> ContractMembers<'gcx> {
+    let c = gcx.hir.contract(id);
+    let mut inheritance_start = None;
+    let mut members = Vec::<(hir::ItemId, hir::ContractId, Vec<hir::ItemId>)>::new();
+    let mut seen = FxHashMap::default();
+    let bases = std::iter::once(id).chain(c.linearized_bases.iter().copied().filter(|&b| b != id));
+    for base in bases {
+        if base != id && inheritance_start.is_none() {
+            inheritance_start = Some(members.len());
+        }
+        for &item_id in gcx.hir.contract(base).items {
+            if base != id && is_private_member(gcx, item_id) {
+                continue;
+            }
+            let Some(key) = member_key(gcx, item_id) else { continue };
+            match seen.entry(key) {
+                StdEntry::Occupied(entry) => members[*entry.get()].2.push(item_id),
+                StdEntry::Vacant(entry) => {
+                    entry.insert(members.len());
+                    members.push((item_id, base, Vec::new()));
+                }
+            }
+        }
+    }
+    let inheritance_start = inheritance_start.unwrap_or(members.len());
+    let members = gcx.bump().alloc_from_iter(members.into_iter().map(|(id, contract, overrides)| {
+        ContractMember { id, contract, overrides: gcx.bump().alloc_slice_copy(&overrides) }
+    }));
+    debug!("{}.members.len() = {}", gcx.contract_fully_qualified_name(id), members.len());
+    ContractMembers { members, inheritance_start }
+}
+
 /// Returns the ABI signature of the given item. Only accepts functions, errors, and events.
 pub fn item_signature(gcx: _, id: hir::ItemId) -> &'gcx str {
     let name = gcx.item_name(id);
@@ -598,10 +808,100 @@ pub fn struct_field_types(gcx: _, id: hir::StructId) -> &'gcx [Ty<'gcx>] {
     gcx.mk_ty_iter(gcx.hir.strukt(id).fields.iter().map(|&f| gcx.type_of_item(f.into())))
 }
 
+/// Returns the user-defined operators bound to the given UDVT, along with their functions.
+///
+/// Like in solc, operators are only bound by `global` directives in the source of the UDVT.
+pub fn udvt_operators(
+    gcx: _,
+    id: hir::UdvtId,
+) -> &'gcx [(UserDefinableOperator, hir::FunctionId)] {
+    let source = gcx.hir.source(gcx.hir.udvt(id).source);
+    let operators = source
+        .usings
+        .iter()
+        .filter(|using| using.global)
+        .filter(|using| {
+            let Some(ty) = &using.ty else { return false };
+            matches!(ty.kind, hir::TypeKind::Custom(hir::ItemId::Udvt(ty_id)) if ty_id == id)
+        })
+        .flat_map(|using| using.list.functions())
+        .filter_map(|f| Some((f.operator?, f.id)));
+    gcx.bump().alloc_from_iter(operators)
+}
+
 /// Returns the members of the given type.
 pub fn members_of(gcx: _, ty: Ty<'gcx>) -> members::MemberList<'gcx> {
     members::members_of(gcx, ty)
 }
+
+/// Returns the identifiers in the text of the given source with their positions, sorted.
+///
+/// Keywords are included. Unlike the HIR, this also covers inline assembly and the directives that
+/// are not lowered, which makes it suitable to conservatively find references by name.
+pub(crate) fn source_identifiers(gcx: _, id: hir::SourceId) -> &'gcx [(Symbol, BytePos)] {
+    let file = &gcx.hir.source(id).file;
+    let mut idents = Vec::new();
+    let mut pos = 0;
+    for token in Cursor::new(&file.src) {
+        let start = pos;
+        pos += token.len as usize;
+        if token.kind == RawTokenKind::Ident {
+            let lo = file.start_pos + BytePos(start as u32);
+            idents.push((Symbol::intern(&file.src[start..pos]), lo));
+        }
+    }
+    idents.sort_unstable();
+    gcx.bump().alloc_vec(idents)
+}
+}
+
+/// The key under which contract members override each other. See [`Gcx::contract_members`].
+#[derive(PartialEq, Eq, Hash)]
+enum MemberKey<'gcx> {
+    /// Functions override functions with the same name and external parameter types.
+    Function(Symbol, &'gcx [Ty<'gcx>]),
+    /// Modifiers override modifiers with the same name.
+    Modifier(Symbol),
+    /// Members that cannot be overridden.
+    Unique(hir::ItemId),
+}
+
+/// Returns the override key of a contract member, or `None` if the item is not a member.
+fn member_key<'gcx>(gcx: Gcx<'gcx>, id: hir::ItemId) -> Option<MemberKey<'gcx>> {
+    let function_key = |f_id: hir::FunctionId| {
+        let f = gcx.hir.function(f_id);
+        let name = f.name?.name;
+        Some(match f.kind {
+            hir::FunctionKind::Function => {
+                let ty = gcx.type_of_item(f_id.into()).as_externally_callable_function(gcx);
+                MemberKey::Function(name, ty.parameters().unwrap())
+            }
+            hir::FunctionKind::Modifier => MemberKey::Modifier(name),
+            _ => return None,
+        })
+    };
+    match id {
+        // Getters are listed through their variable.
+        hir::ItemId::Function(f_id) if gcx.hir.function(f_id).is_getter() => None,
+        hir::ItemId::Function(f_id) => function_key(f_id),
+        hir::ItemId::Variable(v_id) => match gcx.hir.variable(v_id).getter {
+            Some(getter) => function_key(getter),
+            None => Some(MemberKey::Unique(id)),
+        },
+        hir::ItemId::Event(_) | hir::ItemId::Error(_) => Some(MemberKey::Unique(id)),
+        _ => None,
+    }
+}
+
+/// Returns `true` if the given member is private, and thus not inherited.
+fn is_private_member(gcx: Gcx<'_>, id: hir::ItemId) -> bool {
+    match id {
+        hir::ItemId::Function(f_id) => gcx.hir.function(f_id).visibility == Visibility::Private,
+        hir::ItemId::Variable(v_id) => {
+            gcx.hir.variable(v_id).visibility == Some(Visibility::Private)
+        }
+        _ => false,
+    }
 }
 
 /// `OnceMap::insert` but with `Copy` keys and values.
@@ -627,3 +927,182 @@ fn log_cache_query(name: &str, key: &dyn fmt::Debug) -> tracing::span::EnteredSp
 fn log_cache_query_result(result: &dyn fmt::Debug, hit: bool) {
     trace!(?result, hit);
 }
+
+/// Returns the error message for a type that cannot be a parameter or return type of an
+/// externally visible function, if any.
+fn external_type_error(ty: Ty<'_>, is_library: bool) -> Option<&'static str> {
+    // Libraries can receive storage references, as they are called with `delegatecall`. This
+    // includes mappings and types containing internal functions, which are never copied.
+    if is_library && ty.is_ref_at(DataLocation::Storage) {
+        return None;
+    }
+    Some(if ty.has_mapping() {
+        "types containing mappings cannot be parameter or return types of public functions"
+    } else if ty.is_recursive() {
+        "recursive types cannot be parameter or return types of public functions"
+    } else if ty.has_internal_function() {
+        "internal function types cannot be parameter or return types of public functions"
+    } else if ty.has_error() {
+        "this type cannot be parameter or return type of a public function"
+    } else if ty.is_ref_at(DataLocation::Storage) {
+        "storage references cannot be parameter or return types of public functions"
+    } else {
+        return None;
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParsingContext;
+    use std::path::PathBuf;
+
+    #[test]
+    fn contract_members() {
+        let sess = Session::builder().with_test_emitter().build();
+        sess.enter(|| {
+            let src = "
+                contract A {
+                    uint256 internal x;
+                    uint256 private secret;
+                    event E();
+                    error Err();
+                    modifier m() virtual { _; }
+                    function f() public virtual {}
+                    function g(bytes calldata) external virtual {}
+                    function h() private {}
+                }
+                contract B is A {
+                    uint256 public y;
+                    modifier m() override { _; }
+                    function f() public virtual override {}
+                }
+                contract C is B {
+                    function f() public override {}
+                    function g(bytes memory) public override {}
+                }
+            ";
+            let file = sess
+                .source_map()
+                .new_dummy_source_file(PathBuf::from("test.sol"), src.to_string())
+                .unwrap();
+            let mut pcx = ParsingContext::new(&sess);
+            pcx.add_file(file);
+            let ast_arenas = ThreadLocal::new();
+            let mut sources = pcx.parse(&ast_arenas);
+            sources.topo_sort();
+            let hir_arena = ThreadLocal::<hir::Arena>::new();
+            let (hir, symbol_resolver) =
+                crate::lower(&sess, &sources, hir_arena.get_or_default()).unwrap();
+            let gcx = GlobalCtxt::new(&sess, &hir_arena, hir, symbol_resolver, None);
+            let gcx = Gcx::new(&gcx);
+
+            let contract_name = |id: hir::ContractId| gcx.hir.contract(id).name.to_string();
+            let item_name = |id: hir::ItemId| {
+                format!(
+                    "{}.{}",
+                    contract_name(gcx.hir.item(id).contract().unwrap()),
+                    gcx.item_name(id)
+                )
+            };
+            let describe = |members: &[ContractMember<'_>]| {
+                members
+                    .iter()
+                    .map(|m| {
+                        let overrides =
+                            m.overrides.iter().map(|&o| item_name(o)).collect::<Vec<_>>();
+                        format!("{} {overrides:?}", item_name(m.id))
+                    })
+                    .collect::<Vec<_>>()
+            };
+            let id = |name: &str| {
+                gcx.hir
+                    .contract_ids()
+                    .find(|&id| gcx.hir.contract(id).name.as_str() == name)
+                    .unwrap()
+            };
+
+            let members = gcx.contract_members(id("C"));
+            assert!(members.iter().all(|m| m.contract == gcx.hir.item(m.id).contract().unwrap()));
+            assert_eq!(describe(members.own()), [r#"C.f ["B.f", "A.f"]"#, r#"C.g ["A.g"]"#]);
+            assert_eq!(
+                describe(members.inherited()),
+                ["B.y []", r#"B.m ["A.m"]"#, "A.x []", "A.E []", "A.Err []"]
+            );
+
+            let members = gcx.contract_members(id("A"));
+            assert!(members.inherited().is_empty());
+            assert_eq!(
+                describe(members.own()),
+                [
+                    "A.x []",
+                    "A.secret []",
+                    "A.E []",
+                    "A.Err []",
+                    "A.m []",
+                    "A.f []",
+                    "A.g []",
+                    "A.h []"
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn members_in_source_order() {
+        let sess = Session::builder().with_test_emitter().build();
+        sess.enter(|| {
+            let src = "
+                contract A {
+                    event E();
+                    uint256 public x;
+                    constructor() {}
+                    struct S { uint256 a; }
+                    function f() public {}
+                    error Err();
+                    receive() external payable {}
+                    enum En { V }
+                    modifier m() { _; }
+                    uint256 internal y;
+                    fallback() external {}
+                }
+            ";
+            let file = sess
+                .source_map()
+                .new_dummy_source_file(PathBuf::from("test.sol"), src.to_string())
+                .unwrap();
+            let mut pcx = ParsingContext::new(&sess);
+            pcx.add_file(file);
+            let ast_arenas = ThreadLocal::new();
+            let mut sources = pcx.parse(&ast_arenas);
+            sources.topo_sort();
+            let hir_arena = ThreadLocal::<hir::Arena>::new();
+            let (hir, _) = crate::lower(&sess, &sources, hir_arena.get_or_default()).unwrap();
+
+            let contract = hir.contracts().next().unwrap();
+            let members = contract
+                .members_in_source_order(&hir)
+                .map(|item| match item.name() {
+                    Some(name) => format!("{} {name}", item.description()),
+                    None => item.description().to_string(),
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                members,
+                [
+                    "event E",
+                    "variable x",
+                    "constructor",
+                    "struct S",
+                    "function f",
+                    "error Err",
+                    "receive",
+                    "enum En",
+                    "modifier m",
+                    "variable y",
+                    "fallback",
+                ]
+            );
+        });
+    }
+}
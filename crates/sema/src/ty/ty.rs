@@ -2,7 +2,7 @@ use super::Gcx;
 use crate::{builtins::Builtin, hir};
 use alloy_primitives::U256;
 use solar_ast::ast::{DataLocation, ElementaryType, StateMutability, TypeSize, Visibility};
-use solar_data_structures::{map::FxHashSet, smallvec::SmallVec, Interned};
+use solar_data_structures::{fmt_from_fn, map::FxHashSet, smallvec::SmallVec, Interned};
 use solar_interface::diagnostics::ErrorGuaranteed;
 use std::{borrow::Borrow, fmt, hash::Hash, ops::ControlFlow};
 
@@ -29,7 +29,6 @@ impl<'gcx> Ty<'gcx> {
         gcx.mk_ty(kind)
     }
 
-    // TODO: with_loc_if_ref ?
     pub fn with_loc(self, gcx: Gcx<'gcx>, loc: DataLocation) -> Self {
         let mut ty = self;
         if let TyKind::Ref(inner, l2) = self.kind {
@@ -41,6 +40,15 @@ impl<'gcx> Ty<'gcx> {
         Self::new(gcx, TyKind::Ref(ty, loc))
     }
 
+    /// Returns the type in the given data location if it is not a value type.
+    pub fn with_loc_if_ref(self, gcx: Gcx<'gcx>, loc: DataLocation) -> Self {
+        if self.is_value_type() {
+            self
+        } else {
+            self.with_loc(gcx, loc)
+        }
+    }
+
     /// Peels `Ref` layers from the type, returning the inner type.
     pub fn peel_refs(self) -> Self {
         let mut ty = self;
@@ -147,10 +155,18 @@ impl<'gcx> Ty<'gcx> {
         self.flags.contains(TyFlags::HAS_ERROR)
     }
 
+    /// Returns `true` if this type contains an internal function pointer.
+    pub fn has_internal_function(self) -> bool {
+        self.flags.contains(TyFlags::HAS_INTERNAL_FN)
+    }
+
     /// Returns `true` if this type can be part of an externally callable function.
     #[inline]
     pub fn can_be_exported(self) -> bool {
-        !(self.is_recursive() || self.has_mapping() || self.has_error())
+        !(self.is_recursive()
+            || self.has_mapping()
+            || self.has_internal_function()
+            || self.has_error())
     }
 
     /// Returns the parameter types of the type.
@@ -190,6 +206,83 @@ impl<'gcx> Ty<'gcx> {
         }
     }
 
+    /// Returns a human-readable representation of the type, for use in diagnostics.
+    pub fn display(self, gcx: Gcx<'gcx>) -> impl fmt::Display + use<'gcx> {
+        fmt_from_fn(move |f| self.fmt_display(gcx, f))
+    }
+
+    fn fmt_display(self, gcx: Gcx<'gcx>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list = |f: &mut fmt::Formatter<'_>, tys: &[Self]| {
+            for (i, ty) in tys.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(",")?;
+                }
+                ty.fmt_display(gcx, f)?;
+            }
+            Ok(())
+        };
+        match self.kind {
+            TyKind::Elementary(ty) => {
+                ty.write_abi_str(f)?;
+                if matches!(ty, ElementaryType::Address(true)) {
+                    f.write_str(" payable")?;
+                }
+                Ok(())
+            }
+            TyKind::StringLiteral(..) => f.write_str("literal_string"),
+            TyKind::IntLiteral(_) => f.write_str("int_const"),
+            TyKind::Ref(ty, loc) => {
+                ty.fmt_display(gcx, f)?;
+                write!(f, " {loc}")
+            }
+            TyKind::DynArray(ty) => {
+                ty.fmt_display(gcx, f)?;
+                f.write_str("[]")
+            }
+            TyKind::Array(ty, len) => {
+                ty.fmt_display(gcx, f)?;
+                write!(f, "[{len}]")
+            }
+            TyKind::Tuple(tys) => {
+                f.write_str("tuple(")?;
+                list(f, tys)?;
+                f.write_str(")")
+            }
+            TyKind::Mapping(key, value) => {
+                f.write_str("mapping(")?;
+                key.fmt_display(gcx, f)?;
+                f.write_str(" => ")?;
+                value.fmt_display(gcx, f)?;
+                f.write_str(")")
+            }
+            TyKind::FnPtr(ptr) => {
+                f.write_str("function(")?;
+                list(f, ptr.parameters)?;
+                f.write_str(")")?;
+                if !ptr.returns.is_empty() {
+                    f.write_str(" returns (")?;
+                    list(f, ptr.returns)?;
+                    f.write_str(")")?;
+                }
+                Ok(())
+            }
+            TyKind::Contract(id) => write!(f, "contract {}", gcx.item_canonical_name(id)),
+            TyKind::Struct(id) => write!(f, "struct {}", gcx.item_canonical_name(id)),
+            TyKind::Enum(id) => write!(f, "enum {}", gcx.item_canonical_name(id)),
+            TyKind::Error(_, id) => write!(f, "error {}", gcx.item_canonical_name(id)),
+            TyKind::Event(_, id) => write!(f, "event {}", gcx.item_canonical_name(id)),
+            TyKind::Udvt(_, id) => write!(f, "{}", gcx.item_canonical_name(id)),
+            TyKind::Module(_) => f.write_str("module"),
+            TyKind::BuiltinModule(builtin) => write!(f, "{}", builtin.name()),
+            TyKind::Type(ty) | TyKind::Meta(ty) => {
+                f.write_str("type(")?;
+                ty.fmt_display(gcx, f)?;
+                f.write_str(")")
+            }
+            TyKind::Err(_) => f.write_str("<error>"),
+        }
+    }
+
     /// Visits the type and its subtypes.
     pub fn visit<T>(self, f: &mut impl FnMut(Self) -> ControlFlow<T>) -> ControlFlow<T> {
         f(self)?;
@@ -339,6 +432,8 @@ bitflags::bitflags! {
         const HAS_MAPPING  = 1 << 1;
         /// Whether an error is reachable.
         const HAS_ERROR    = 1 << 2;
+        /// Whether this type contains an internal function pointer.
+        const HAS_INTERNAL_FN = 1 << 3;
     }
 }
 
@@ -355,11 +450,16 @@ impl TyFlags {
             | TyKind::StringLiteral(..)
             | TyKind::IntLiteral(_)
             | TyKind::Contract(_)
-            | TyKind::FnPtr(_)
             | TyKind::Enum(_)
             | TyKind::Module(_)
             | TyKind::BuiltinModule(_) => {}
 
+            TyKind::FnPtr(f) => {
+                if f.visibility == Visibility::Internal {
+                    self.add(Self::HAS_INTERNAL_FN);
+                }
+            }
+
             TyKind::Ref(ty, _)
             | TyKind::DynArray(ty)
             | TyKind::Array(ty, _)
@@ -0,0 +1,93 @@
+//! Lint for `assert` and `require` calls whose condition is always false, used to revert.
+//!
+//! The condition is evaluated with the [`ConstantEvaluator`], so only literals, constants and
+//! operators on them are considered. This is separate from conditions that are always true, which
+//! are redundant rather than misused.
+
+use crate::{
+    builtins::Builtin,
+    eval::ConstantEvaluator,
+    hir::{self, Visit},
+    lint::{builtin, Lint, LintContext, LintInfo},
+};
+use std::ops::ControlFlow;
+
+/// Warns about `assert(false)` and `require(false)` calls.
+pub(crate) struct AssertFalse;
+
+impl Lint for AssertFalse {
+    fn info(&self) -> &'static LintInfo {
+        &builtin::ASSERT_FALSE
+    }
+
+    fn check_function(&self, cx: &LintContext<'_>, id: hir::FunctionId) {
+        let _ = AssertFinder { cx: *cx }.visit_nested_function(id);
+    }
+}
+
+struct AssertFinder<'gcx> {
+    cx: LintContext<'gcx>,
+}
+
+impl<'gcx> AssertFinder<'gcx> {
+    fn check_call(
+        &self,
+        call: &'gcx hir::Expr<'gcx>,
+        callee: &hir::Expr<'_>,
+        args: &[hir::Expr<'_>],
+    ) {
+        let hir::ExprKind::Ident(res) = callee.kind else { return };
+        let is_assert = res.iter().any(|res| matches!(res, hir::Res::Builtin(Builtin::Assert)));
+        let is_require = res
+            .iter()
+            .any(|res| matches!(res, hir::Res::Builtin(Builtin::Require | Builtin::RequireMsg)));
+        if !is_assert && !is_require {
+            return;
+        }
+        let Some(cond) = args.first() else { return };
+        let gcx = self.cx.gcx();
+        let Ok(value) = ConstantEvaluator::new(gcx).try_eval(cond) else { return };
+        if value.to_bool() {
+            return;
+        }
+
+        if is_assert {
+            let note = "`assert` is meant for internal errors that should never happen; \
+                        before solc 0.8.0, a failing `assert` consumes all the remaining gas";
+            let help = "use `revert` with a custom error instead, like `revert Unreachable();`";
+            let msg = "`assert(false)` used to revert";
+            if let Some(diag) = self.cx.struct_lint_with(call.span, msg) {
+                diag.note(note).help(help).emit();
+            }
+        } else {
+            let message =
+                args.get(1).and_then(|msg| gcx.sess.source_map().span_to_snippet(msg.span).ok());
+            let help = match message {
+                Some(msg) => {
+                    format!("use `revert({msg})` instead, or `revert` with a custom error")
+                }
+                None => "use `revert()` instead, or `revert` with a custom error".to_string(),
+            };
+            let note = "a `require` whose condition is always false always reverts";
+            let msg = "`require(false)` used to revert";
+            if let Some(diag) = self.cx.struct_lint_with(call.span, msg) {
+                diag.note(note).help(help).emit();
+            }
+        }
+    }
+}
+
+impl<'gcx> Visit<'gcx> for AssertFinder<'gcx> {
+    type BreakValue = ();
+
+    fn hir(&self) -> &'gcx hir::Hir<'gcx> {
+        &self.cx.gcx().hir
+    }
+
+    fn visit_expr(&mut self, expr: &'gcx hir::Expr<'gcx>) -> ControlFlow<Self::BreakValue> {
+        if let hir::ExprKind::Call(callee, hir::CallArgs::Unnamed(args)) = expr.kind {
+            self.check_call(expr, callee, args);
+        }
+        self.walk_expr(expr)
+    }
+}
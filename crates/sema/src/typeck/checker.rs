@@ -0,0 +1,1800 @@
+use crate::{
+    builtins::Builtin,
+    eval::ConstantEvaluator,
+    hir::{self, Visit},
+    lint::builtin,
+    ty::{Gcx, StructMemberError, Ty, TyKind},
+};
+use alloy_primitives::U256;
+use solar_ast::ast::{
+    BinOpKind, ContractKind, DataLocation, ElementaryType, LitKind, TypeSize, UnOpKind, VarMut,
+};
+use solar_interface::{
+    diagnostics::{Applicability, DeclaredHere},
+    sym, Ident, Span, Symbol,
+};
+use std::ops::ControlFlow;
+
+/// Checks the expressions in the given source.
+pub(super) fn check_source(gcx: Gcx<'_>, id: hir::SourceId) {
+    let mut checker = TyChecker { gcx, contract: None, function: None, callee: None };
+    let _ = checker.visit_nested_source(id);
+}
+
+/// Expression type checker.
+///
+/// Types are computed on a best-effort basis: expressions whose type cannot be determined yet
+/// are given no type, and are not diagnosed.
+struct TyChecker<'gcx> {
+    gcx: Gcx<'gcx>,
+    /// The contract currently being visited, if any.
+    contract: Option<hir::ContractId>,
+    /// The function currently being visited, if any.
+    function: Option<hir::FunctionId>,
+    /// The callee of the innermost call being visited, if any.
+    callee: Option<hir::ExprId>,
+}
+
+/// The values of a `return` statement or of the right-hand side of a destructuring.
+enum TupleValues<'gcx> {
+    /// A list of expressions: `return a;` or `return (a, b);`, with `None` for the empty
+    /// components of a tuple, like in `return (a, );`.
+    Exprs(Vec<Option<&'gcx hir::Expr<'gcx>>>),
+    /// The types of a call returning multiple values: `return f();`.
+    Tys(&'gcx [Ty<'gcx>]),
+}
+
+impl TupleValues<'_> {
+    fn len(&self) -> usize {
+        match self {
+            Self::Exprs(exprs) => exprs.len(),
+            Self::Tys(tys) => tys.len(),
+        }
+    }
+}
+
+/// A component of the left-hand side of a destructuring.
+enum Target<'gcx> {
+    /// An omitted component: `(, b) = f();`.
+    Omitted,
+    /// A variable or an assigned expression, with its type if it can be determined.
+    Value(Span, Option<Ty<'gcx>>),
+    /// A nested tuple: `((a, b), c) = f();`.
+    Tuple(Span, Vec<Target<'gcx>>),
+}
+
+impl Target<'_> {
+    fn span(&self) -> Option<Span> {
+        match *self {
+            Self::Omitted => None,
+            Self::Value(span, _) | Self::Tuple(span, _) => Some(span),
+        }
+    }
+}
+
+/// The result of resolving a call to an overloaded function by its arguments.
+enum Overload {
+    /// Exactly one candidate matches the arguments.
+    Unique(hir::FunctionId),
+    /// No candidate matches the arguments. Contains all the candidates.
+    NoMatch(Vec<hir::FunctionId>),
+    /// Multiple candidates match the arguments. Contains the matching candidates.
+    Ambiguous(Vec<hir::FunctionId>),
+    /// The candidates or the types of the arguments cannot be determined.
+    Unknown,
+}
+
+/// Returns `true` if the expression is `abi.decode`.
+fn is_abi_decode(expr: &hir::Expr<'_>) -> bool {
+    if let hir::ExprKind::Member(base, member) = expr.peel_parens().kind {
+        if let hir::ExprKind::Ident([hir::Res::Builtin(Builtin::Abi)]) = base.kind {
+            return member.name == sym::decode;
+        }
+    }
+    false
+}
+
+impl<'gcx> TyChecker<'gcx> {
+    /// Returns the type of the given expression, if it can be determined.
+    fn expr_ty(&self, expr: &'gcx hir::Expr<'gcx>) -> Option<Ty<'gcx>> {
+        let gcx = self.gcx;
+        let expr = expr.peel_parens();
+        match expr.kind {
+            hir::ExprKind::Ident(&[res]) => self.res_ty(res),
+            hir::ExprKind::Lit(lit) => match lit.kind {
+                LitKind::Address(_) => Some(gcx.types.address),
+                LitKind::Bool(_) => Some(gcx.types.bool),
+                LitKind::Str(_, ref s) => Some(gcx.mk_ty_string_literal(s)),
+                LitKind::Number(ref n) => {
+                    let bytes = n.bits().div_ceil(8);
+                    let size = TypeSize::new(bytes.try_into().ok()?)?;
+                    Some(gcx.mk_ty_int_literal(size))
+                }
+                _ => None,
+            },
+            hir::ExprKind::Call(callee, ref args) => self.call_ty(callee, args),
+            hir::ExprKind::CallOptions(callee, _) => self.expr_ty(callee),
+            hir::ExprKind::Member(base, member) => {
+                let base = self.expr_ty(base)?;
+                self.member_ty(base, member.name)
+            }
+            hir::ExprKind::Index(base, Some(_)) => {
+                let base = self.expr_ty(base)?;
+                match base.kind {
+                    TyKind::Ref(inner, loc) => match inner.kind {
+                        TyKind::DynArray(elem) | TyKind::Array(elem, _) => {
+                            Some(elem.with_loc_if_ref(gcx, loc))
+                        }
+                        TyKind::Mapping(_, value) => Some(value.with_loc_if_ref(gcx, loc)),
+                        _ => None,
+                    },
+                    _ => None,
+                }
+            }
+            hir::ExprKind::Payable(_) => Some(gcx.types.address_payable),
+            hir::ExprKind::Ternary(_, true_, false_) => {
+                let ty = self.expr_ty(true_)?;
+                (self.expr_ty(false_)? == ty).then_some(ty)
+            }
+            hir::ExprKind::Binary(lhs, op, _) => {
+                let lhs_ty = self.expr_ty(lhs);
+                if let Some(f) = lhs_ty.and_then(|ty| self.user_defined_operator(ty, op.kind)) {
+                    let TyKind::FnPtr(f) = gcx.type_of_item(f.into()).kind else { unreachable!() };
+                    return match *f.returns {
+                        [ret] => Some(ret),
+                        _ => None,
+                    };
+                }
+                match op.kind {
+                    BinOpKind::Lt
+                    | BinOpKind::Le
+                    | BinOpKind::Gt
+                    | BinOpKind::Ge
+                    | BinOpKind::Eq
+                    | BinOpKind::Ne
+                    | BinOpKind::Or
+                    | BinOpKind::And => Some(gcx.types.bool),
+                    _ => None,
+                }
+            }
+            hir::ExprKind::Unary(op, _) if op.kind == UnOpKind::Not => Some(gcx.types.bool),
+            hir::ExprKind::Type(ref ty) if ty.kind.is_elementary() => {
+                Some(gcx.type_of_hir_ty(ty).make_type_type(gcx))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the type of the given resolved name, if it can be determined.
+    fn res_ty(&self, res: hir::Res) -> Option<Ty<'gcx>> {
+        let gcx = self.gcx;
+        match res {
+            hir::Res::Builtin(Builtin::This) => Some(gcx.mk_ty(TyKind::Contract(self.contract?))),
+            hir::Res::Builtin(Builtin::Super) => None,
+            hir::Res::Item(
+                id @ (hir::ItemId::Contract(_)
+                | hir::ItemId::Struct(_)
+                | hir::ItemId::Enum(_)
+                | hir::ItemId::Udvt(_)),
+            ) => Some(gcx.type_of_item(id).make_type_type(gcx)),
+            hir::Res::Err(_) => None,
+            _ => Some(gcx.type_of_res(res)),
+        }
+    }
+
+    /// Returns the type of the result of calling `callee`, if it can be determined.
+    fn call_ty(
+        &self,
+        callee_expr: &'gcx hir::Expr<'gcx>,
+        args: &hir::CallArgs<'gcx>,
+    ) -> Option<Ty<'gcx>> {
+        let gcx = self.gcx;
+        let callee = match callee_expr.peel_parens().kind {
+            // Overloaded functions are resolved by their arguments.
+            hir::ExprKind::Ident(res @ [_, _, ..]) => {
+                let &hir::CallArgs::Unnamed(args) = args else { return None };
+                let Overload::Unique(id) = self.resolve_overload(res, args) else { return None };
+                gcx.type_of_item(id.into())
+            }
+            _ => self.expr_ty(callee_expr)?,
+        };
+        match callee.kind {
+            // Type conversion: `T(x)`.
+            TyKind::Type(ty) => match ty.kind {
+                TyKind::Elementary(_) if ty.is_value_type() => Some(ty),
+                TyKind::Contract(_) | TyKind::Enum(_) => Some(ty),
+                _ => None,
+            },
+            // The return types of `abi.decode` depend on its arguments.
+            TyKind::FnPtr(_) if is_abi_decode(callee_expr) => None,
+            TyKind::FnPtr(f) => match *f.returns {
+                [ret] => Some(ret),
+                _ => Some(gcx.mk_ty(TyKind::Tuple(f.returns))),
+            },
+            _ => None,
+        }
+    }
+
+    /// Resolves a call to the overloaded function `res` by the types of its arguments.
+    ///
+    /// Like in solc, a candidate matches if each argument is implicitly convertible to the
+    /// corresponding parameter, and the call must match exactly one candidate; candidates are not
+    /// ranked further. Candidates with the same parameter types, like a function and the base
+    /// functions it overrides, are considered once.
+    fn resolve_overload(&self, res: &[hir::Res], args: &'gcx [hir::Expr<'gcx>]) -> Overload {
+        let gcx = self.gcx;
+        let mut candidates = Vec::<(hir::FunctionId, &[Ty<'gcx>])>::new();
+        for res in res {
+            let hir::Res::Item(hir::ItemId::Function(id)) = *res else { return Overload::Unknown };
+            let TyKind::FnPtr(f) = gcx.type_of_item(id.into()).kind else { unreachable!() };
+            if !candidates.iter().any(|&(_, params)| params == f.parameters) {
+                candidates.push((id, f.parameters));
+            }
+        }
+
+        let mut matching = Vec::new();
+        let mut unknown = false;
+        for &(id, params) in &candidates {
+            if params.len() != args.len() {
+                continue;
+            }
+            let mut is_match = Some(true);
+            for (arg, &param) in args.iter().zip(params) {
+                match self.expr_ty(arg).and_then(|ty| self.is_implicitly_convertible(ty, param)) {
+                    Some(true) => {}
+                    Some(false) => {
+                        is_match = Some(false);
+                        break;
+                    }
+                    None => is_match = None,
+                }
+            }
+            match is_match {
+                Some(true) => matching.push(id),
+                Some(false) => {}
+                None => unknown = true,
+            }
+        }
+
+        match (&matching[..], unknown) {
+            (&[id], false) => Overload::Unique(id),
+            ([], false) => Overload::NoMatch(candidates.into_iter().map(|(id, _)| id).collect()),
+            ([_, _, ..], _) => Overload::Ambiguous(matching),
+            _ => Overload::Unknown,
+        }
+    }
+
+    /// Returns whether a value of type `from` is implicitly convertible to type `to`, or `None` if
+    /// this cannot be determined.
+    fn is_implicitly_convertible(&self, from: Ty<'gcx>, to: Ty<'gcx>) -> Option<bool> {
+        use ElementaryType::*;
+
+        let from = self.peel_value_ref(from);
+        let to = self.peel_value_ref(to);
+        if from == to {
+            return Some(true);
+        }
+        let r = match (&from.kind, &to.kind) {
+            (TyKind::Err(_), _) | (_, TyKind::Err(_)) => return None,
+
+            (&TyKind::StringLiteral(utf8, size), _) => match to.peel_refs().kind {
+                TyKind::Elementary(String) => utf8,
+                TyKind::Elementary(Bytes) => true,
+                TyKind::Elementary(FixedBytes(to)) => size.bytes_raw() <= to.bytes(),
+                _ => false,
+            },
+            (&TyKind::IntLiteral(size), &TyKind::Elementary(to)) => match to {
+                UInt(to) => size.bytes_raw() <= to.bytes(),
+                Int(to) if size.bytes_raw() == to.bytes() => return None,
+                Int(to) => size.bytes_raw() < to.bytes(),
+                Fixed(..) | UFixed(..) | FixedBytes(_) => return None,
+                Address(_) | Bool | String | Bytes => false,
+            },
+
+            (&TyKind::Ref(from, from_loc), &TyKind::Ref(to, to_loc)) => {
+                from == to && (from_loc == to_loc || to_loc == DataLocation::Memory)
+            }
+            // Missing data locations are diagnosed separately.
+            (TyKind::Ref(..), _) | (_, TyKind::Ref(..)) => from.peel_refs() == to.peel_refs(),
+
+            (&TyKind::Elementary(from), &TyKind::Elementary(to)) => match (from, to) {
+                (Fixed(..) | UFixed(..), _) | (_, Fixed(..) | UFixed(..)) => return None,
+                (UInt(from), UInt(to)) | (Int(from), Int(to)) => from.bytes() <= to.bytes(),
+                (UInt(from), Int(to)) => from.bytes() < to.bytes(),
+                (FixedBytes(from), FixedBytes(to)) => from.bytes() <= to.bytes(),
+                (Address(true), Address(false)) => true,
+                _ => false,
+            },
+
+            (&TyKind::Contract(from), &TyKind::Contract(to)) => {
+                self.gcx.hir.contract(from).linearized_bases.contains(&to)
+            }
+
+            (
+                TyKind::Elementary(_)
+                | TyKind::IntLiteral(_)
+                | TyKind::Contract(_)
+                | TyKind::Struct(_)
+                | TyKind::Enum(_)
+                | TyKind::Udvt(..),
+                TyKind::Elementary(_)
+                | TyKind::Contract(_)
+                | TyKind::Struct(_)
+                | TyKind::Enum(_)
+                | TyKind::Udvt(..),
+            ) => false,
+
+            _ => return None,
+        };
+        Some(r)
+    }
+
+    /// Returns the type of the member `name` of `ty`, if it can be determined.
+    fn member_ty(&self, ty: Ty<'gcx>, name: Symbol) -> Option<Ty<'gcx>> {
+        let ty = self.peel_value_ref(ty);
+        let can_have_members = match ty.kind {
+            TyKind::Elementary(ElementaryType::Bytes) => false,
+            TyKind::Elementary(_)
+            | TyKind::Ref(..)
+            | TyKind::Contract(_)
+            | TyKind::Module(_)
+            | TyKind::BuiltinModule(_)
+            | TyKind::Type(_) => true,
+            _ => false,
+        };
+        if !can_have_members {
+            return None;
+        }
+        let mut members = self.gcx.members_of(ty).iter().filter(|m| m.name == name);
+        let member = members.next()?;
+        // Overloaded members are resolved by their arguments.
+        if members.next().is_some() {
+            return None;
+        }
+        Some(member.ty)
+    }
+
+    /// Peels the reference off value types, such as address state variables.
+    fn peel_value_ref(&self, ty: Ty<'gcx>) -> Ty<'gcx> {
+        match ty.kind {
+            TyKind::Ref(inner, _) if inner.is_value_type() => inner,
+            _ => ty,
+        }
+    }
+
+    fn check_return(&self, expr: &'gcx hir::Expr<'gcx>) {
+        let Some(id) = self.function else { return };
+        let gcx = self.gcx;
+        let func = gcx.hir.function(id);
+        let returns = func.returns;
+
+        // `return (a, b);` or `return f();`, where `f` returns multiple values.
+        let values = match expr.peel_parens().kind {
+            hir::ExprKind::Tuple(exprs) if exprs.len() != 1 => TupleValues::Exprs(exprs.to_vec()),
+            _ => match self.expr_ty(expr) {
+                Some(ty) => match ty.kind {
+                    TyKind::Tuple(tys) => TupleValues::Tys(tys),
+                    _ => TupleValues::Exprs(vec![Some(expr)]),
+                },
+                None => TupleValues::Exprs(vec![Some(expr)]),
+            },
+        };
+
+        let found = values.len();
+        if found != returns.len() {
+            let expected = returns.len();
+            let s = if expected == 1 { "" } else { "s" };
+            let msg = format!("expected {expected} return value{s}, found {found}");
+            let mut err = gcx.dcx().err(msg).span(expr.span);
+            if let (Some(&first), Some(&last)) = (returns.first(), returns.last()) {
+                let span = gcx.item_span(first).to(gcx.item_span(last));
+                err = err.subdiagnostic(DeclaredHere::new("return values", span));
+            } else {
+                let span = func.name.map_or(func.span, |name| name.span);
+                err = err.span_note(span, "this function has no return values");
+            }
+            if found < expected && returns.iter().all(|&ret| gcx.hir.variable(ret).name.is_some()) {
+                err = err.help(
+                    "use `return;` to return the current values of the named return variables",
+                );
+            }
+            err.emit();
+            return;
+        }
+
+        for (i, &ret) in returns.iter().enumerate() {
+            let (span, ty) = match values {
+                // Empty components are not type checked.
+                TupleValues::Exprs(ref exprs) => match exprs[i] {
+                    Some(value) => (value.span, self.expr_ty(value)),
+                    None => continue,
+                },
+                TupleValues::Tys(tys) => (expr.span, Some(tys[i])),
+            };
+            let Some(ty) = ty else { continue };
+            let expected = gcx.type_of_item(ret.into());
+            if self.is_implicitly_convertible(ty, expected) == Some(false) {
+                let msg = format!(
+                    "return value of type `{}` is not implicitly convertible to expected type `{}`",
+                    self.peel_value_ref(ty).display(gcx),
+                    self.peel_value_ref(expected).display(gcx),
+                );
+                let note = DeclaredHere::new("return value", gcx.item_span(ret));
+                gcx.dcx().err(msg).span(span).subdiagnostic(note).emit();
+            }
+        }
+    }
+
+    /// Returns the targets of a destructuring assignment, like `(a, (b, c)) = f();`.
+    fn assignment_targets(
+        &self,
+        exprs: &'gcx [Option<&'gcx hir::Expr<'gcx>>],
+    ) -> Vec<Target<'gcx>> {
+        exprs
+            .iter()
+            .map(|&expr| {
+                let Some(expr) = expr else { return Target::Omitted };
+                match expr.peel_parens().kind {
+                    hir::ExprKind::Tuple(exprs) if exprs.len() != 1 => {
+                        Target::Tuple(expr.span, self.assignment_targets(exprs))
+                    }
+                    // Values are copied into storage on assignment, so data locations of the
+                    // targets do not matter.
+                    _ => Target::Value(expr.span, self.expr_ty(expr).map(|ty| ty.peel_refs())),
+                }
+            })
+            .collect()
+    }
+
+    /// Checks that the components of a destructuring declaration or assignment, like
+    /// `(uint a, bool b) = f();`, match the values of the right-hand side in number and type.
+    ///
+    /// Omitted components, like in `(, bool b) = f();`, are not type checked. Nested tuples, like
+    /// in `((a, b), c) = ((1, true), 2);`, are checked recursively.
+    fn check_destructuring(&self, targets: &[Target<'gcx>], value: &'gcx hir::Expr<'gcx>) {
+        let gcx = self.gcx;
+        let values = match value.peel_parens().kind {
+            hir::ExprKind::Tuple(exprs) if exprs.len() != 1 => {
+                if exprs.iter().any(Option::is_none) {
+                    let msg =
+                        "components of the right-hand side of a destructuring cannot be empty";
+                    gcx.dcx().err(msg).span(value.span).emit();
+                    return;
+                }
+                TupleValues::Exprs(exprs.to_vec())
+            }
+            _ => match self.expr_ty(value) {
+                Some(ty) => match ty.kind {
+                    TyKind::Tuple(tys) => TupleValues::Tys(tys),
+                    _ => TupleValues::Exprs(vec![Some(value)]),
+                },
+                // The right-hand side may be a call returning multiple values.
+                None => return,
+            },
+        };
+
+        if values.len() != targets.len() {
+            self.report_destructuring_arity(targets, values.len(), value.span);
+            return;
+        }
+
+        for (i, target) in targets.iter().enumerate() {
+            let (span, expected) = match *target {
+                Target::Omitted => continue,
+                Target::Value(span, expected) => (span, expected),
+                Target::Tuple(_, ref targets) => {
+                    match values {
+                        TupleValues::Exprs(ref exprs) => {
+                            if let Some(value) = exprs[i] {
+                                self.check_destructuring(targets, value)
+                            }
+                        }
+                        // Functions cannot return tuples.
+                        TupleValues::Tys(_) => {
+                            self.report_destructuring_arity(targets, 1, value.span)
+                        }
+                    }
+                    continue;
+                }
+            };
+            let ty = match values {
+                TupleValues::Exprs(ref exprs) => {
+                    let Some(value) = exprs[i] else { continue };
+                    if let hir::ExprKind::Tuple(components) = value.peel_parens().kind {
+                        if components.len() != 1 {
+                            let n = components.len();
+                            let msg = format!(
+                                "expected a single value, found a tuple with {n} components"
+                            );
+                            gcx.dcx().err(msg).span(value.span).emit();
+                            continue;
+                        }
+                    }
+                    self.expr_ty(value)
+                }
+                TupleValues::Tys(tys) => Some(tys[i]),
+            };
+            let (Some(ty), Some(expected)) = (ty, expected) else { continue };
+            if self.is_implicitly_convertible(ty, expected) == Some(false) {
+                let msg = format!(
+                    "component of type `{}` is not implicitly convertible to expected type `{}`",
+                    self.peel_value_ref(ty).display(gcx),
+                    self.peel_value_ref(expected).display(gcx),
+                );
+                gcx.dcx().err(msg).span(span).emit();
+            }
+        }
+    }
+
+    /// Reports a destructuring whose right-hand side has `found` components, which does not match
+    /// the number of `targets`.
+    fn report_destructuring_arity(&self, targets: &[Target<'gcx>], found: usize, span: Span) {
+        let expected = targets.len();
+        let s = if expected == 1 { "" } else { "s" };
+        let s2 = if found == 1 { "" } else { "s" };
+        let msg = format!(
+            "expected a tuple with {expected} component{s}, found one with {found} component{s2}"
+        );
+        let mut err = self.gcx.dcx().err(msg).span(span);
+        let mut spans = targets.iter().filter_map(Target::span);
+        if let Some(first) = spans.next() {
+            let span = spans.last().map_or(first, |last| first.to(last));
+            let note = format!("the left-hand side has {expected} component{s}");
+            err = err.span_note(span, note);
+        }
+        err.emit();
+    }
+
+    fn check_emit(
+        &self,
+        stmt: &'gcx hir::Stmt<'gcx>,
+        res: &'gcx [hir::Res],
+        args: &hir::CallArgs<'gcx>,
+    ) {
+        let gcx = self.gcx;
+        if res.iter().any(|res| matches!(res, hir::Res::Err(_))) {
+            return;
+        }
+        let mut events = res.iter().filter_map(|res| match *res {
+            hir::Res::Item(hir::ItemId::Event(id)) => Some(id),
+            _ => None,
+        });
+        let Some(id) = events.next() else {
+            let Some(res) = res.first() else { return };
+            let msg = format!("expected event, found {}", res.description());
+            gcx.dcx().err(msg).span(stmt.span).emit();
+            return;
+        };
+        // Overloaded events are resolved by their arguments.
+        if events.next().is_some() {
+            return;
+        }
+        let &hir::CallArgs::Unnamed(args) = args else { return };
+
+        let event = gcx.hir.event(id);
+        let params = event.parameters;
+        if args.len() != params.len() {
+            let expected = params.len();
+            let s = if expected == 1 { "" } else { "s" };
+            let msg = format!("expected {expected} argument{s}, found {}", args.len());
+            let note = DeclaredHere::new("event", event.name.span);
+            gcx.dcx().err(msg).span(stmt.span).subdiagnostic(note).emit();
+            return;
+        }
+
+        for (arg, &param) in args.iter().zip(params) {
+            let Some(ty) = self.expr_ty(arg) else { continue };
+            // Event parameters have no data location.
+            let expected = gcx.type_of_item(param.into()).peel_refs();
+            if self.is_implicitly_convertible(ty, expected) == Some(false) {
+                let msg = format!(
+                    "argument of type `{}` is not implicitly convertible to expected type `{}`",
+                    self.peel_value_ref(ty).display(gcx),
+                    expected.display(gcx),
+                );
+                let note = DeclaredHere::new("parameter", gcx.item_span(param));
+                gcx.dcx().err(msg).span(arg.span).subdiagnostic(note).emit();
+            }
+        }
+    }
+
+    /// Checks the arguments of a base constructor call, like `is B(1)` or `constructor() B(1)`,
+    /// against the parameters of the base constructor.
+    fn check_base_constructor_call(&self, modifier: &'gcx hir::Modifier<'gcx>) {
+        let Some(base) = modifier.base_contract() else { return };
+        let hir::CallArgs::Unnamed(args) = modifier.args else { return };
+        // The arguments may be specified elsewhere if there are none, like in `is B`.
+        if args.is_empty() {
+            return;
+        }
+        let gcx = self.gcx;
+        let contract = gcx.hir.contract(base);
+        let ctor = contract.ctor.map(|ctor| gcx.hir.function(ctor));
+        let params = ctor.map_or(&[][..], |ctor| ctor.parameters);
+        if args.len() != params.len() {
+            let expected = params.len();
+            let s = if expected == 1 { "" } else { "s" };
+            let msg = format!(
+                "expected {expected} argument{s} for the constructor of `{}`, found {}",
+                contract.name,
+                args.len(),
+            );
+            let note = match ctor {
+                Some(ctor) => DeclaredHere::new("constructor", ctor.span),
+                None => DeclaredHere::new("contract", contract.name.span),
+            };
+            gcx.dcx().err(msg).span(modifier.span).subdiagnostic(note).emit();
+            return;
+        }
+
+        for (arg, &param) in args.iter().zip(params) {
+            let Some(ty) = self.expr_ty(arg) else { continue };
+            let expected = gcx.type_of_item(param.into());
+            if self.is_implicitly_convertible(ty, expected) == Some(false) {
+                let msg = format!(
+                    "argument of type `{}` is not implicitly convertible to expected type `{}`",
+                    self.peel_value_ref(ty).display(gcx),
+                    self.peel_value_ref(expected).display(gcx),
+                );
+                let note = DeclaredHere::new("parameter", gcx.item_span(param));
+                gcx.dcx().err(msg).span(arg.span).subdiagnostic(note).emit();
+            }
+        }
+    }
+
+    /// Returns the function bound to the binary operator `op` on values of type `ty` with
+    /// `using { f as op } for T global;`, if `ty` is a UDVT.
+    fn user_defined_operator(&self, ty: Ty<'gcx>, op: BinOpKind) -> Option<hir::FunctionId> {
+        let TyKind::Udvt(_, id) = self.peel_value_ref(ty).kind else { return None };
+        // `-` is bound to both negation and subtraction, depending on the number of parameters.
+        self.gcx.udvt_operators(id).iter().find_map(|&(operator, f)| {
+            (operator.to_op().right() == Some(op) && self.gcx.hir.function(f).parameters.len() == 2)
+                .then_some(f)
+        })
+    }
+
+    fn check_binary(
+        &self,
+        expr: &'gcx hir::Expr<'gcx>,
+        lhs: &'gcx hir::Expr<'gcx>,
+        op: BinOpKind,
+        rhs: &'gcx hir::Expr<'gcx>,
+    ) {
+        let (Some(lhs_ty), Some(rhs_ty)) = (self.expr_ty(lhs), self.expr_ty(rhs)) else { return };
+        let lhs_ty = self.peel_value_ref(lhs_ty);
+        let rhs_ty = self.peel_value_ref(rhs_ty);
+        if matches!(lhs_ty.kind, TyKind::Udvt(..)) || matches!(rhs_ty.kind, TyKind::Udvt(..)) {
+            self.check_user_defined_operator(expr, lhs_ty, op, rhs_ty);
+            return;
+        }
+        // Shifts and exponentiation allow operands of different types.
+        if matches!(
+            op,
+            BinOpKind::Shl
+                | BinOpKind::Shr
+                | BinOpKind::Sar
+                | BinOpKind::Pow
+                | BinOpKind::And
+                | BinOpKind::Or
+        ) {
+            return;
+        }
+        let (TyKind::Elementary(l), TyKind::Elementary(r)) = (&lhs_ty.kind, &rhs_ty.kind) else {
+            return;
+        };
+        if !matches!(
+            (l, r),
+            (ElementaryType::Int(_), ElementaryType::UInt(_))
+                | (ElementaryType::UInt(_), ElementaryType::Int(_))
+        ) {
+            return;
+        }
+        // A common type exists if one of the operands is implicitly convertible to the other.
+        if self.is_implicitly_convertible(lhs_ty, rhs_ty) != Some(false)
+            || self.is_implicitly_convertible(rhs_ty, lhs_ty) != Some(false)
+        {
+            return;
+        }
+
+        let gcx = self.gcx;
+        let msg = format!(
+            "operator `{}` not compatible with types `{}` and `{}`",
+            op.to_str(),
+            lhs_ty.display(gcx),
+            rhs_ty.display(gcx),
+        );
+        let mut err = gcx.dcx().err(msg).span(expr.span);
+        err = err.note("there is no implicit conversion between signed and unsigned integers");
+        // The conversion may change the value, so the suggestion is not machine applicable.
+        if let Ok(snippet) = gcx.sess.source_map().span_to_snippet(rhs.span) {
+            let ty = lhs_ty.display(gcx);
+            err = err.span_suggestion(
+                rhs.span,
+                format!("convert the right operand to `{ty}` explicitly"),
+                format!("{ty}({snippet})"),
+                Applicability::MaybeIncorrect,
+            );
+        }
+        err.emit();
+    }
+
+    /// Warns about comparisons of a boolean expression to a boolean literal, like `x == true`,
+    /// which can be replaced by the expression itself or by its negation.
+    fn check_bool_literal_comparison(
+        &self,
+        expr: &'gcx hir::Expr<'gcx>,
+        lhs: &'gcx hir::Expr<'gcx>,
+        op: BinOpKind,
+        rhs: &'gcx hir::Expr<'gcx>,
+    ) {
+        let as_bool_lit = |e: &hir::Expr<'_>| match e.peel_parens().kind {
+            hir::ExprKind::Lit(lit) => match lit.kind {
+                LitKind::Bool(b) => Some(b),
+                _ => None,
+            },
+            _ => None,
+        };
+        let (operand, lit) = match (as_bool_lit(lhs), as_bool_lit(rhs)) {
+            (None, Some(lit)) => (lhs, lit),
+            (Some(lit), None) => (rhs, lit),
+            _ => return,
+        };
+        let negate = match op {
+            BinOpKind::Eq => !lit,
+            BinOpKind::Ne => lit,
+            _ => return,
+        };
+        let gcx = self.gcx;
+        let Some(ty) = self.expr_ty(operand) else { return };
+        if self.peel_value_ref(ty) != gcx.types.bool {
+            return;
+        }
+
+        let msg = format!("comparison of a boolean expression to `{lit}`");
+        let lint = &builtin::BOOL_LITERAL_COMPARISONS;
+        let Some(mut err) = gcx.sess.struct_lint(lint, expr.span, msg) else { return };
+        if let Ok(snippet) = gcx.sess.source_map().span_to_snippet(operand.span) {
+            let (msg, replacement) = if !negate {
+                ("use the expression directly", snippet)
+            } else if matches!(
+                operand.kind,
+                hir::ExprKind::Ident(_)
+                    | hir::ExprKind::Call(..)
+                    | hir::ExprKind::Member(..)
+                    | hir::ExprKind::Index(..)
+                    | hir::ExprKind::Tuple(_)
+                    | hir::ExprKind::Unary(..)
+            ) {
+                ("negate the expression", format!("!{snippet}"))
+            } else {
+                ("negate the expression", format!("!({snippet})"))
+            };
+            err =
+                err.span_suggestion(expr.span, msg, replacement, Applicability::MachineApplicable);
+        }
+        err.emit();
+    }
+
+    /// Checks a binary operation on a UDVT, which only supports the operators bound to it.
+    fn check_user_defined_operator(
+        &self,
+        expr: &'gcx hir::Expr<'gcx>,
+        lhs_ty: Ty<'gcx>,
+        op: BinOpKind,
+        rhs_ty: Ty<'gcx>,
+    ) {
+        let gcx = self.gcx;
+        let msg = format!(
+            "operator `{}` not compatible with types `{}` and `{}`",
+            op.to_str(),
+            lhs_ty.display(gcx),
+            rhs_ty.display(gcx),
+        );
+        if lhs_ty != rhs_ty {
+            let note = "user-defined operators require both operands to be of the same type";
+            gcx.dcx().err(msg).span(expr.span).note(note).emit();
+            return;
+        }
+        if self.user_defined_operator(lhs_ty, op).is_some() {
+            return;
+        }
+
+        let TyKind::Udvt(_, id) = lhs_ty.kind else { unreachable!() };
+        let note = "user-defined value types only support the operators bound to them";
+        let help = format!(
+            "bind a function with `using {{ f as {} }} for {} global;` next to the type definition",
+            op.to_str(),
+            lhs_ty.display(gcx),
+        );
+        gcx.dcx()
+            .err(msg)
+            .span(expr.span)
+            .note(note)
+            .help(help)
+            .subdiagnostic(DeclaredHere::new("type", gcx.item_span(id.into())))
+            .emit();
+    }
+
+    fn check_delete(&self, operand: &'gcx hir::Expr<'gcx>) {
+        let gcx = self.gcx;
+        let operand = operand.peel_parens();
+        let is_lvalue = match operand.kind {
+            hir::ExprKind::Ident(&[hir::Res::Item(hir::ItemId::Variable(id))]) => {
+                gcx.hir.variable(id).mutability != Some(VarMut::Constant)
+            }
+            hir::ExprKind::Ident(&[hir::Res::Err(_)])
+            | hir::ExprKind::Index(..)
+            | hir::ExprKind::Member(..)
+            | hir::ExprKind::Tuple(_)
+            | hir::ExprKind::Err(_) => true,
+            _ => false,
+        };
+        if !is_lvalue {
+            let msg = "`delete` can only be applied to variables, members and index accesses";
+            gcx.dcx().err(msg).span(operand.span).emit();
+            return;
+        }
+
+        let Some(ty) = self.expr_ty(operand) else { return };
+        let ty = ty.peel_refs();
+        if let TyKind::Mapping(..) = ty.kind {
+            let msg = format!("`delete` cannot be applied to type `{}`", ty.display(gcx));
+            let help = "mappings cannot be deleted as a whole; delete individual keys instead";
+            gcx.dcx().err(msg).span(operand.span).help(help).emit();
+        }
+    }
+
+    /// Checks that events are not called like functions, which was allowed before solc `0.5.0`.
+    fn check_event_call(&self, expr: &'gcx hir::Expr<'gcx>, callee: &'gcx hir::Expr<'gcx>) {
+        let hir::ExprKind::Ident(&[hir::Res::Item(hir::ItemId::Event(id)), ..]) =
+            callee.peel_parens().kind
+        else {
+            return;
+        };
+        if self.gcx.sess.solc_compat.is_some_and(|v| !v.requires_emit()) {
+            return;
+        }
+        let name = self.gcx.hir.event(id).name;
+        let msg = "event invocations have to be prefixed by `emit`";
+        let help = format!("add `emit` before the event invocation: `emit {name}(...)`");
+        self.gcx.dcx().err(msg).span(expr.span).help(help).emit();
+    }
+
+    /// Returns the builtin referenced by the given expression: `gasleft` or `block.basefee`.
+    fn builtin_of(&self, expr: &'gcx hir::Expr<'gcx>) -> Option<Builtin> {
+        match expr.peel_parens().kind {
+            hir::ExprKind::Ident(&[hir::Res::Builtin(builtin)]) => Some(builtin),
+            hir::ExprKind::Member(base, member) => {
+                let hir::ExprKind::Ident(&[hir::Res::Builtin(module)]) = base.peel_parens().kind
+                else {
+                    return None;
+                };
+                module.members()?.iter().copied().find(|b| b.name() == member.name)
+            }
+            _ => None,
+        }
+    }
+
+    /// Checks that the builtin referenced by the given expression is available in the target EVM
+    /// version.
+    fn check_builtin_availability(&self, expr: &'gcx hir::Expr<'gcx>) {
+        let Some(builtin) = self.builtin_of(expr) else { return };
+        let Some(min_version) = builtin.min_evm_version() else { return };
+        let gcx = self.gcx;
+        if gcx.sess.evm_version < min_version {
+            let name = match expr.peel_parens().kind {
+                hir::ExprKind::Member(base, _) => {
+                    let Some(module) = self.builtin_of(base) else { return };
+                    format!("{}.{}", module.name(), builtin.name())
+                }
+                _ => builtin.name().to_string(),
+            };
+            let msg = format!("`{name}` is not available before EVM version {min_version}");
+            let note = format!("the target EVM version is {}", gcx.sess.evm_version);
+            gcx.dcx().err(msg).span(expr.span).note(note).emit();
+        }
+    }
+
+    /// Reports `block.difficulty` on EVM versions since Paris, where it was replaced by
+    /// `block.prevrandao`, at the level of the [`block-difficulty`](builtin::BLOCK_DIFFICULTY)
+    /// lint.
+    fn check_block_difficulty(&self, expr: &'gcx hir::Expr<'gcx>, member: Ident) {
+        if self.builtin_of(expr) != Some(Builtin::BlockDifficulty) {
+            return;
+        }
+        let gcx = self.gcx;
+        if !gcx.sess.evm_version.has_prev_randao() {
+            return;
+        }
+        let note = "since EVM version paris, the `DIFFICULTY` opcode returns the randomness \
+                    beacon value of the previous block instead (EIP-4399)";
+        let lint = &builtin::BLOCK_DIFFICULTY;
+        let Some(err) = gcx.sess.struct_lint(lint, member.span, lint.message) else { return };
+        err.note(note)
+            .note(format!("the target EVM version is {}", gcx.sess.evm_version))
+            .span_suggestion(
+                member.span,
+                "use the randomness beacon instead",
+                "prevrandao",
+                Applicability::MachineApplicable,
+            )
+            .emit();
+    }
+
+    /// Checks the arguments of calls to global builtin functions, such as `blockhash(n)`.
+    fn check_builtin_call(
+        &self,
+        expr: &'gcx hir::Expr<'gcx>,
+        callee: &'gcx hir::Expr<'gcx>,
+        args: &hir::CallArgs<'gcx>,
+    ) {
+        // Overloaded builtins, such as `require`, are resolved by their arguments.
+        let hir::ExprKind::Ident(&[hir::Res::Builtin(builtin)]) = callee.peel_parens().kind else {
+            return;
+        };
+        if !Builtin::global().contains(&builtin) {
+            return;
+        }
+        let gcx = self.gcx;
+        let TyKind::FnPtr(f) = builtin.ty(gcx).kind else { return };
+        let name = builtin.name();
+        let args = match *args {
+            hir::CallArgs::Unnamed(args) => args,
+            hir::CallArgs::Named(_) => {
+                let msg = format!("named arguments are not supported for `{name}`");
+                gcx.dcx().err(msg).span(expr.span).note("builtin parameters have no names").emit();
+                return;
+            }
+        };
+        if args.len() != f.parameters.len() {
+            let expected = f.parameters.len();
+            let s = if expected == 1 { "" } else { "s" };
+            let msg = format!("expected {expected} argument{s} for `{name}`, found {}", args.len());
+            gcx.dcx().err(msg).span(expr.span).emit();
+            return;
+        }
+
+        for (arg, &expected) in args.iter().zip(f.parameters) {
+            let Some(ty) = self.expr_ty(arg) else { continue };
+            if self.is_implicitly_convertible(ty, expected) == Some(false) {
+                let msg = format!(
+                    "argument of type `{}` is not implicitly convertible to expected type `{}`",
+                    self.peel_value_ref(ty).display(gcx),
+                    self.peel_value_ref(expected).display(gcx),
+                );
+                gcx.dcx().err(msg).span(arg.span).emit();
+            }
+        }
+    }
+
+    /// Checks that calls to overloaded functions, like `f(1)` with `f(uint)` and `f(string)`, match
+    /// exactly one of the overloads.
+    fn check_overloaded_call(
+        &self,
+        expr: &'gcx hir::Expr<'gcx>,
+        callee: &'gcx hir::Expr<'gcx>,
+        args: &hir::CallArgs<'gcx>,
+    ) {
+        let hir::ExprKind::Ident(res @ [_, _, ..]) = callee.peel_parens().kind else { return };
+        let &hir::CallArgs::Unnamed(args) = args else { return };
+        let gcx = self.gcx;
+        let (is_ambiguous, candidates) = match self.resolve_overload(res, args) {
+            Overload::Unique(_) | Overload::Unknown => return,
+            Overload::NoMatch(candidates) => (false, candidates),
+            Overload::Ambiguous(candidates) => (true, candidates),
+        };
+        let name = gcx.item_name(candidates[0]);
+        let signature = |id: hir::FunctionId| {
+            let TyKind::FnPtr(f) = gcx.type_of_item(id.into()).kind else { unreachable!() };
+            let params = f.parameters.iter().map(|ty| ty.display(gcx).to_string());
+            format!("{name}({})", params.collect::<Vec<_>>().join(", "))
+        };
+
+        let msg = if is_ambiguous {
+            format!("multiple overloads of function `{name}` match the arguments")
+        } else {
+            format!("no overload of function `{name}` matches the arguments")
+        };
+        let mut err = gcx.dcx().err(msg).span(expr.span);
+        for &id in &candidates {
+            let span = gcx.item_name(id).span;
+            err = err.span_note(span, format!("candidate `{}`", signature(id)));
+        }
+        if is_ambiguous {
+            err = err.help("convert the arguments explicitly to select one of the overloads");
+        } else {
+            let tys = args
+                .iter()
+                .map(|arg| {
+                    let ty = self.expr_ty(arg)?;
+                    Some(format!("`{}`", self.peel_value_ref(ty).display(gcx)))
+                })
+                .collect::<Option<Vec<_>>>();
+            match tys {
+                Some(tys) if tys.is_empty() => err = err.note("the call has no arguments"),
+                Some(tys) if tys.len() == 1 => {
+                    err = err.note(format!("the argument has type {}", tys[0]))
+                }
+                Some(tys) => err = err.note(format!("the arguments have types {}", tys.join(", "))),
+                None => {}
+            }
+        }
+        err.emit();
+    }
+
+    /// Checks calls to `selfdestruct`, which is deprecated, and which would destroy the calling
+    /// contract if used in a library.
+    fn check_selfdestruct(&self, expr: &'gcx hir::Expr<'gcx>, callee: &'gcx hir::Expr<'gcx>) {
+        let hir::ExprKind::Ident(&[hir::Res::Builtin(Builtin::Selfdestruct)]) =
+            callee.peel_parens().kind
+        else {
+            return;
+        };
+        let gcx = self.gcx;
+        if self.contract.is_some_and(|c| gcx.hir.contract(c).kind.is_library()) {
+            let msg = "`selfdestruct` cannot be used in libraries";
+            let note = "libraries are executed in the context of the calling contract, \
+                        which would be destroyed instead";
+            gcx.dcx().err(msg).span(expr.span).note(note).emit();
+            return;
+        }
+        if !gcx.sess.solc_compat.is_some_and(|v| v.has_selfdestruct_deprecation()) {
+            return;
+        }
+        let msg = "`selfdestruct` has been deprecated";
+        let note = "since the Cancun hard fork, it only transfers the Ether of the contract to \
+                    the beneficiary, unless it is executed in the transaction which created the \
+                    contract (EIP-6780)";
+        gcx.dcx().warn(msg).span(expr.span).note(note).emit();
+    }
+
+    /// Checks the names of the arguments of calls with named arguments: `f({a: 1, b: 2})`,
+    /// `emit E({a: 1})` or `revert E({a: 1})`, where `res` is the resolved callee.
+    fn check_named_args(&self, span: Span, res: &[hir::Res], args: &hir::CallArgs<'gcx>) {
+        let &hir::CallArgs::Named(args) = args else { return };
+        let gcx = self.gcx;
+        // Overloaded functions are resolved by their arguments.
+        let &[hir::Res::Item(id)] = res else { return };
+        let (kind, name, params, param_kind) = match id {
+            hir::ItemId::Function(id) => {
+                let f = gcx.hir.function(id);
+                let Some(name) = f.name else { return };
+                ("function", name, f.parameters, "parameter")
+            }
+            hir::ItemId::Event(id) => {
+                let event = gcx.hir.event(id);
+                ("event", event.name, event.parameters, "parameter")
+            }
+            hir::ItemId::Error(id) => {
+                let error = gcx.hir.error(id);
+                ("error", error.name, error.parameters, "parameter")
+            }
+            hir::ItemId::Struct(id) => {
+                let strukt = gcx.hir.strukt(id);
+                ("struct", strukt.name, strukt.fields, "field")
+            }
+            _ => return,
+        };
+        let param_name = |id: hir::VariableId| gcx.hir.variable(id).name.map(|name| name.name);
+
+        for (i, arg) in args.iter().enumerate() {
+            if let Some(prev) = args[..i].iter().find(|prev| prev.name.name == arg.name.name) {
+                let msg = format!("duplicate argument `{}`", arg.name);
+                gcx.dcx()
+                    .err(msg)
+                    .span(arg.name.span)
+                    .span_note(prev.name.span, "previously specified here")
+                    .emit();
+            } else if !params.iter().any(|&param| param_name(param) == Some(arg.name.name)) {
+                let msg = format!("{kind} `{name}` has no {param_kind} named `{}`", arg.name);
+                let note = DeclaredHere::new(kind, name.span);
+                gcx.dcx().err(msg).span(arg.name.span).subdiagnostic(note).emit();
+            }
+        }
+
+        let missing = params
+            .iter()
+            .filter(|&&param| !args.iter().any(|arg| param_name(param) == Some(arg.name.name)))
+            .map(|&param| match param_name(param) {
+                Some(name) => format!("`{name}`"),
+                None => "<unnamed>".to_string(),
+            })
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            let s = if missing.len() == 1 { "" } else { "s" };
+            let msg = format!("missing argument{s} for {param_kind}{s} {}", missing.join(", "));
+            let note = DeclaredHere::new(kind, name.span);
+            gcx.dcx().err(msg).span(span).subdiagnostic(note).emit();
+        }
+    }
+
+    /// Checks the arguments of `bytes.concat(...)` and `string.concat(...)`.
+    fn check_concat(&self, callee: &'gcx hir::Expr<'gcx>, args: &hir::CallArgs<'gcx>) {
+        let hir::ExprKind::Member(base, member) = callee.peel_parens().kind else { return };
+        if member.name != sym::concat {
+            return;
+        }
+        let hir::ExprKind::Type(hir::Type { kind: hir::TypeKind::Elementary(base_ty), .. }) =
+            base.kind
+        else {
+            return;
+        };
+        let gcx = self.gcx;
+        let (name, is_string, min_version) = match base_ty {
+            ElementaryType::String => ("string", true, "0.8.12"),
+            ElementaryType::Bytes => ("bytes", false, "0.8.4"),
+            _ => return,
+        };
+
+        if let Some(version) = gcx.sess.solc_compat {
+            let available =
+                if is_string { version.has_string_concat() } else { version.has_bytes_concat() };
+            if !available {
+                let msg = format!("`{name}.concat` is not available before solc {min_version}");
+                gcx.dcx().err(msg).span(callee.span).emit();
+                return;
+            }
+        }
+
+        let &hir::CallArgs::Unnamed(args) = args else { return };
+        for arg in args {
+            let Some(ty) = self.expr_ty(arg) else { continue };
+            let ok = if is_string {
+                self.is_implicitly_convertible(ty, gcx.types.string_ref.memory)
+            } else if let TyKind::Elementary(ElementaryType::FixedBytes(_)) =
+                self.peel_value_ref(ty).kind
+            {
+                Some(true)
+            } else {
+                self.is_implicitly_convertible(ty, gcx.types.bytes_ref.memory)
+            };
+            if ok == Some(false) {
+                let expected = if is_string { "`string`" } else { "`bytes` or `bytesN`" };
+                let msg = format!(
+                    "invalid argument to `{name}.concat`: expected {expected}, found `{}`",
+                    ty.display(gcx)
+                );
+                gcx.dcx().err(msg).span(arg.span).emit();
+            }
+        }
+    }
+
+    /// Checks the arguments of `abi.encodeCall(f, (...))` against the parameters of `f`.
+    fn check_encode_call(
+        &self,
+        expr: &'gcx hir::Expr<'gcx>,
+        callee: &'gcx hir::Expr<'gcx>,
+        args: &hir::CallArgs<'gcx>,
+    ) {
+        let hir::ExprKind::Member(base, member) = callee.peel_parens().kind else { return };
+        if member.name != sym::encodeCall {
+            return;
+        }
+        let hir::ExprKind::Ident([hir::Res::Builtin(Builtin::Abi)]) = base.kind else { return };
+        let gcx = self.gcx;
+        let &hir::CallArgs::Unnamed(args) = args else { return };
+        let &[target, tuple] = args else {
+            let msg = format!(
+                "`abi.encodeCall` expects 2 arguments, a function pointer and a tuple, found {}",
+                args.len()
+            );
+            gcx.dcx().err(msg).span(expr.span).emit();
+            return;
+        };
+
+        let Some(id) = self.encode_call_target(target) else { return };
+        let func = gcx.hir.function(id);
+        let Some(name) = func.name else { return };
+        // `abi.encodeCall(f, (a, b))`, `abi.encodeCall(f, ())` or `abi.encodeCall(f, a)`.
+        let single = [Some(tuple.peel_parens())];
+        let values = match tuple.peel_parens().kind {
+            hir::ExprKind::Tuple(exprs) if exprs.len() != 1 => exprs,
+            _ => &single[..],
+        };
+        let params = func.parameters;
+        if values.len() != params.len() {
+            let expected = params.len();
+            let s = if expected == 1 { "" } else { "s" };
+            let found = values.len();
+            let msg =
+                format!("expected {expected} argument{s} for function `{name}`, found {found}");
+            let note = DeclaredHere::new("function", name.span);
+            gcx.dcx().err(msg).span(tuple.span).subdiagnostic(note).emit();
+            return;
+        }
+
+        for (value, &param) in values.iter().zip(params) {
+            let Some(value) = *value else { continue };
+            let Some(ty) = self.expr_ty(value) else { continue };
+            // The arguments are encoded, so the data location of the parameter does not matter.
+            let expected = gcx.type_of_item(param.into()).peel_refs();
+            if self.is_implicitly_convertible(ty, expected) == Some(false) {
+                let msg = format!(
+                    "argument of type `{}` is not implicitly convertible to expected type `{}`",
+                    self.peel_value_ref(ty).display(gcx),
+                    expected.display(gcx),
+                );
+                let note = DeclaredHere::new("parameter", gcx.item_span(param));
+                gcx.dcx().err(msg).span(value.span).subdiagnostic(note).emit();
+            }
+        }
+    }
+
+    /// Resolves the function referenced by the first argument of `abi.encodeCall`: `I.f` or
+    /// `i.f`, where `i` is of contract type `I`.
+    fn encode_call_target(&self, expr: &'gcx hir::Expr<'gcx>) -> Option<hir::FunctionId> {
+        let gcx = self.gcx;
+        let hir::ExprKind::Member(base, member) = expr.peel_parens().kind else { return None };
+        let contract = match self.peel_value_ref(self.expr_ty(base)?).kind {
+            TyKind::Contract(id) => id,
+            TyKind::Type(ty) => match ty.kind {
+                TyKind::Contract(id) => id,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        let mut candidates = gcx.hir.contract(contract).linearized_bases.iter().flat_map(|&base| {
+            gcx.hir.contract(base).functions().filter(|&f| {
+                let f = gcx.hir.function(f);
+                f.name.is_some_and(|name| name.name == member.name)
+                    && f.is_part_of_external_interface()
+            })
+        });
+        let parameters = |id: hir::FunctionId| {
+            let TyKind::FnPtr(f) = gcx.type_of_item(id.into()).kind else { unreachable!() };
+            f.parameters
+        };
+        // The most derived function comes first; overridden functions have the same parameters.
+        let id = candidates.next()?;
+        // Overloaded functions are resolved by their arguments.
+        if candidates.any(|other| parameters(other) != parameters(id)) {
+            return None;
+        }
+        Some(id)
+    }
+
+    /// Checks that `E.V` refers to a variant of the enum `E`.
+    fn check_enum_member(&self, base: &'gcx hir::Expr<'gcx>, member: Ident) {
+        let Some(ty) = self.expr_ty(base) else { return };
+        let TyKind::Type(ty) = ty.kind else { return };
+        let TyKind::Enum(id) = ty.kind else { return };
+        let enumm = self.gcx.hir.enumm(id);
+        if !enumm.variants.iter().any(|v| v.name == member.name) {
+            let msg = format!("variant `{}` not found in `{}`", member.name, ty.display(self.gcx));
+            let note = DeclaredHere::new("enum", enumm.name.span);
+            self.gcx.dcx().err(msg).span(member.span).subdiagnostic(note).emit();
+        }
+    }
+
+    /// Checks member accesses on structs, like `s.a`, and on the fields of structs, like `s.a.b`.
+    fn check_struct_member(
+        &self,
+        expr: &'gcx hir::Expr<'gcx>,
+        base: &'gcx hir::Expr<'gcx>,
+        member: Ident,
+    ) {
+        let gcx = self.gcx;
+        let Some(base_ty) = self.expr_ty(base) else { return };
+        match gcx.struct_member(base_ty, member) {
+            Ok(_) => {}
+            Err(StructMemberError::UnknownField { strukt, member }) => {
+                let strukt = gcx.hir.strukt(strukt);
+                let msg = format!("field `{}` not found in `struct {}`", member.name, strukt.name);
+                let fields = strukt
+                    .fields
+                    .iter()
+                    .map(|&f| format!("`{}`", gcx.item_name(f).name))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                gcx.dcx()
+                    .err(msg)
+                    .span(member.span)
+                    .note(format!("available fields are: {fields}"))
+                    .subdiagnostic(DeclaredHere::new("struct", strukt.name.span))
+                    .emit();
+            }
+            Err(StructMemberError::NotAStruct { ty, member }) => {
+                // Contract-level `using for` directives are not resolved yet, and may attach
+                // functions to any type, so only accesses on the elementary fields of structs that
+                // are not called are checked.
+                let hir::ExprKind::Member(parent, _) = base.peel_parens().kind else { return };
+                let Some(parent_ty) = self.expr_ty(parent) else { return };
+                if !matches!(parent_ty.peel_refs().kind, TyKind::Struct(_)) {
+                    return;
+                }
+                let ty = self.peel_value_ref(ty);
+                if !matches!(ty.kind, TyKind::Elementary(_)) || self.callee == Some(expr.id) {
+                    return;
+                }
+                if gcx.members_of(ty).iter().any(|m| m.name == member.name) {
+                    return;
+                }
+                let msg = format!("member `{}` not found in `{}`", member.name, ty.display(gcx));
+                let note =
+                    format!("this field has type `{}`, which is not a struct", ty.display(gcx));
+                gcx.dcx().err(msg).span(member.span).span_note(base.span, note).emit();
+            }
+        }
+    }
+
+    /// Checks member accesses on mappings, like `m.length`, which usually come from attempts to
+    /// iterate over a mapping.
+    fn check_mapping_member(
+        &self,
+        expr: &'gcx hir::Expr<'gcx>,
+        base: &'gcx hir::Expr<'gcx>,
+        member: Ident,
+    ) {
+        // Library functions may be attached to mappings with contract-level `using for`
+        // directives, which are not resolved yet, so calls are not checked.
+        if self.callee == Some(expr.id) {
+            return;
+        }
+        let Some(ty) = self.expr_ty(base) else { return };
+        let ty = ty.peel_refs();
+        if !matches!(ty.kind, TyKind::Mapping(..)) {
+            return;
+        }
+        let msg = format!("member `{}` not found in `{}`", member.name, ty.display(self.gcx));
+        self.gcx
+            .dcx()
+            .err(msg)
+            .span(member.span)
+            .note("mappings cannot be iterated over, and their keys or length cannot be enumerated")
+            .help("keep track of the keys separately, for example in an array")
+            .emit();
+    }
+
+    /// Checks explicit conversions from and to enums: `uint8(E.V)` and `E(x)`.
+    ///
+    /// Enums can only be converted to and from integers. Constant integers converted to an enum
+    /// must be in range of its variants.
+    fn check_enum_conversion(&self, callee: &'gcx hir::Expr<'gcx>, args: &hir::CallArgs<'gcx>) {
+        let gcx = self.gcx;
+        let Some(callee_ty) = self.expr_ty(callee) else { return };
+        let TyKind::Type(to) = callee_ty.kind else { return };
+        let &hir::CallArgs::Unnamed([arg]) = args else { return };
+        let Some(from) = self.expr_ty(arg) else { return };
+        let from = self.peel_value_ref(from);
+
+        let is_integer = |ty: Ty<'gcx>| {
+            matches!(
+                ty.kind,
+                TyKind::IntLiteral(_)
+                    | TyKind::Elementary(ElementaryType::Int(_) | ElementaryType::UInt(_))
+            )
+        };
+        let allowed = match (&from.kind, &to.kind) {
+            (TyKind::Err(_), _) => return,
+            (TyKind::Enum(a), TyKind::Enum(b)) => a == b,
+            (TyKind::Enum(_), TyKind::Elementary(_)) => is_integer(to),
+            (_, TyKind::Enum(_)) => is_integer(from),
+            _ => return,
+        };
+        if !allowed {
+            let msg = format!(
+                "explicit type conversion not allowed from `{}` to `{}`",
+                from.display(gcx),
+                to.display(gcx),
+            );
+            let help = "enums can only be converted to and from integers";
+            gcx.dcx().err(msg).span(arg.span).help(help).emit();
+            return;
+        }
+
+        let TyKind::Enum(id) = to.kind else { return };
+        if !is_integer(from) {
+            return;
+        }
+        // Conversions from non-constant integers are checked at runtime.
+        let Ok(value) = ConstantEvaluator::new(gcx).try_eval(arg) else { return };
+        let enumm = gcx.hir.enumm(id);
+        let len = enumm.variants.len();
+        if value.data >= U256::from(len) {
+            let msg = format!(
+                "integer constant `{}` is out of range for `{}`",
+                value.data,
+                to.display(gcx),
+            );
+            let s = if len == 1 { "" } else { "s" };
+            let note = format!("`{}` has {len} variant{s}", enumm.name);
+            gcx.dcx()
+                .err(msg)
+                .span(arg.span)
+                .note(note)
+                .subdiagnostic(DeclaredHere::new("enum", enumm.name.span))
+                .emit();
+        }
+    }
+
+    /// Checks the implicit conversion of an assigned value to the type of its target, like in
+    /// `uint8 x = y;` or `x = y;`.
+    ///
+    /// Integers and fixed-size byte arrays can only be implicitly converted to types that can
+    /// represent all of their values. Conversions from unsigned to signed integers, like `uint8` to
+    /// `int16`, are allowed, and are warned about with `-Zwarn-sign-conversions`.
+    fn check_implicit_conversion(&self, value: &'gcx hir::Expr<'gcx>, expected: Ty<'gcx>) {
+        use ElementaryType::*;
+
+        let gcx = self.gcx;
+        let Some(from) = self.expr_ty(value) else { return };
+        let from = self.peel_value_ref(from);
+        let to = self.peel_value_ref(expected);
+        let is_literal = match (&from.kind, &to.kind) {
+            (&TyKind::Elementary(from_elem), &TyKind::Elementary(to_elem)) => {
+                match (from_elem, to_elem) {
+                    (UInt(a), UInt(b)) | (Int(a), Int(b)) | (FixedBytes(a), FixedBytes(b))
+                        if a.bytes() > b.bytes() => {}
+                    (UInt(a), Int(b)) if a.bytes() >= b.bytes() => {}
+                    (Int(_), UInt(_)) => {}
+                    (UInt(_), Int(_)) => {
+                        if gcx.sess.is_lint_enabled(&builtin::SIGN_CONVERSIONS) {
+                            self.report_sign_conversion(value, from, to);
+                        }
+                        return;
+                    }
+                    _ => return,
+                }
+                false
+            }
+            (TyKind::IntLiteral(_), TyKind::Elementary(UInt(_) | Int(_)))
+                if self.is_implicitly_convertible(from, to) == Some(false) =>
+            {
+                true
+            }
+            _ => return,
+        };
+
+        let (from_s, to_s) = (from.display(gcx), to.display(gcx));
+        let msg = format!(
+            "value of type `{from_s}` is not implicitly convertible to expected type `{to_s}`"
+        );
+        let mut err = gcx.dcx().err(msg).span(value.span);
+        if is_literal {
+            err = err.note(format!("the literal does not fit in `{to_s}`"));
+        } else {
+            err = err.note(format!("`{to_s}` cannot represent all the values of `{from_s}`"));
+            // The conversion may change the value, so the suggestion is not machine applicable.
+            if let Ok(snippet) = gcx.sess.source_map().span_to_snippet(value.span) {
+                err = err.span_suggestion(
+                    value.span,
+                    format!("convert the value to `{to_s}` explicitly"),
+                    format!("{to_s}({snippet})"),
+                    Applicability::MaybeIncorrect,
+                );
+            }
+        }
+        err.emit();
+    }
+
+    /// Warns about the implicit conversion of `value` from an unsigned to a signed integer type.
+    fn report_sign_conversion(&self, value: &'gcx hir::Expr<'gcx>, from: Ty<'gcx>, to: Ty<'gcx>) {
+        let gcx = self.gcx;
+        let (from_s, to_s) = (from.display(gcx), to.display(gcx));
+        let msg = format!("implicit conversion from unsigned `{from_s}` to signed `{to_s}`");
+        let lint = &builtin::SIGN_CONVERSIONS;
+        let Some(mut err) = gcx.sess.struct_lint(lint, value.span, msg) else { return };
+        if let Ok(snippet) = gcx.sess.source_map().span_to_snippet(value.span) {
+            err = err.span_suggestion(
+                value.span,
+                "convert the value explicitly to make the change of sign visible",
+                format!("{to_s}({snippet})"),
+                Applicability::MachineApplicable,
+            );
+        }
+        err.emit();
+    }
+
+    /// Checks that the argument of `payable(...)` can be converted to `address payable`.
+    ///
+    /// Only addresses, contracts and the literal `0` can be converted directly. Other values that
+    /// are convertible to `address`, like `uint160` and `bytes20`, must be converted to it first.
+    fn check_payable_conversion(&self, expr: &'gcx hir::Expr<'gcx>, arg: &'gcx hir::Expr<'gcx>) {
+        let gcx = self.gcx;
+        let Some(from) = self.expr_ty(arg) else { return };
+        let from = self.peel_value_ref(from);
+        let allowed = match from.kind {
+            TyKind::Err(_) => return,
+            TyKind::Elementary(ElementaryType::Address(_)) | TyKind::Contract(_) => true,
+            TyKind::IntLiteral(_) => {
+                ConstantEvaluator::new(gcx).try_eval(arg).is_ok_and(|value| value.data.is_zero())
+            }
+            _ => false,
+        };
+        if allowed {
+            return;
+        }
+
+        let msg = format!(
+            "explicit type conversion not allowed from `{}` to `address payable`",
+            from.display(gcx),
+        );
+        let to_address = match from.kind {
+            TyKind::Elementary(ElementaryType::UInt(size) | ElementaryType::FixedBytes(size)) => {
+                size.bytes() == 20
+            }
+            _ => false,
+        };
+        let mut err = gcx.dcx().err(msg).span(arg.span);
+        err = err.note("only addresses, contracts and the literal `0` can be converted directly");
+        if to_address {
+            if let Ok(snippet) = gcx.sess.source_map().span_to_snippet(arg.span) {
+                err = err.span_suggestion(
+                    expr.span,
+                    "convert to `address` first",
+                    format!("payable(address({snippet}))"),
+                    Applicability::MachineApplicable,
+                );
+            }
+        }
+        err.emit();
+    }
+
+    /// Warns about conversions of a value type to itself, like `uint256(x)` with `x: uint256`.
+    fn check_redundant_conversion(
+        &self,
+        expr: &'gcx hir::Expr<'gcx>,
+        callee: &'gcx hir::Expr<'gcx>,
+        args: &hir::CallArgs<'gcx>,
+    ) {
+        let gcx = self.gcx;
+        let Some(callee_ty) = self.expr_ty(callee) else { return };
+        let TyKind::Type(to) = callee_ty.kind else { return };
+        let &hir::CallArgs::Unnamed([ref arg]) = args else { return };
+        let Some(from) = self.expr_ty(arg) else { return };
+        if !to.is_value_type() || self.peel_value_ref(from) != to {
+            return;
+        }
+        let Ok(snippet) = gcx.sess.source_map().span_to_snippet(arg.span) else { return };
+        // Keep the precedence of the operand in the surrounding expression.
+        let replacement = match arg.kind {
+            hir::ExprKind::Ident(_)
+            | hir::ExprKind::Lit(_)
+            | hir::ExprKind::Member(..)
+            | hir::ExprKind::Index(..)
+            | hir::ExprKind::Call(..)
+            | hir::ExprKind::Tuple(_) => snippet,
+            _ => format!("({snippet})"),
+        };
+        let msg = format!("redundant conversion to `{}`", to.display(gcx));
+        let lint = &builtin::REDUNDANT_CONVERSIONS;
+        let Some(err) = gcx.sess.struct_lint(lint, expr.span, msg) else { return };
+        err.note("the expression is already of this type")
+            .span_suggestion(
+                expr.span,
+                "remove the conversion",
+                replacement,
+                Applicability::MachineApplicable,
+            )
+            .emit();
+    }
+
+    /// Checks that `this.f` refers to a function that can be called externally.
+    fn check_this_member(&self, base: &'gcx hir::Expr<'gcx>, member: Ident) {
+        let gcx = self.gcx;
+        let hir::ExprKind::Ident([hir::Res::Builtin(Builtin::This)]) = base.peel_parens().kind
+        else {
+            return;
+        };
+        let Some(contract_id) = self.contract else { return };
+        let c = gcx.hir.contract(contract_id);
+        let mut functions = c.linearized_bases.iter().flat_map(|&base| {
+            gcx.hir.contract(base).functions().filter(|&f| {
+                let f = gcx.hir.function(f);
+                f.is_ordinary() && f.name.is_some_and(|name| name.name == member.name)
+            })
+        });
+        // The most derived function comes first.
+        let Some(first) = functions.next() else { return };
+        if std::iter::once(first)
+            .chain(functions)
+            .any(|f| gcx.hir.function(f).is_part_of_external_interface())
+        {
+            return;
+        }
+        let f = gcx.hir.function(first);
+        let msg = format!("member `{}` not found in `contract {}`", member.name, c.name);
+        let note = format!(
+            "`{}` is {} and cannot be called externally through `this`",
+            member.name, f.visibility
+        );
+        let mut err = gcx
+            .dcx()
+            .err(msg)
+            .span(member.span)
+            .note(note)
+            .subdiagnostic(DeclaredHere::new("function", f.span));
+        if f.visibility == hir::Visibility::Internal || f.contract == Some(contract_id) {
+            err = err.help(format!("call the function directly: `{}(...)`", member.name));
+        }
+        err.emit();
+    }
+
+    fn check_member_access(&self, base: &'gcx hir::Expr<'gcx>, member: Ident) {
+        if matches!(member.name, sym::code | sym::codehash) {
+            self.check_address_code(base, member);
+            return;
+        }
+        if !matches!(member.name, sym::transfer | sym::send) {
+            return;
+        }
+        if self.gcx.sess.solc_compat.is_some_and(|v| !v.has_address_payable()) {
+            return;
+        }
+        let Some(ty) = self.expr_ty(base) else { return };
+        if let TyKind::Elementary(ElementaryType::Address(false)) = self.peel_value_ref(ty).kind {
+            let msg = format!("member `{}` not found on type `address`", member.name);
+            let help = "`transfer` and `send` are only available on `address payable`; \
+                        use `payable(...)` to convert";
+            self.gcx.dcx().err(msg).span(member.span).help(help).emit();
+        }
+    }
+
+    /// Checks that `new` is not used to create abstract contracts or interfaces.
+    fn check_new_contract(&self, expr: &'gcx hir::Expr<'gcx>, ty: &hir::Type<'gcx>) {
+        let hir::TypeKind::Custom(hir::ItemId::Contract(id)) = ty.kind else { return };
+        let c = self.gcx.hir.contract(id);
+        let kind = match c.kind {
+            ContractKind::AbstractContract => "an abstract contract",
+            ContractKind::Interface => "an interface",
+            ContractKind::Contract | ContractKind::Library => return,
+        };
+        let msg = format!("cannot instantiate {kind} `{}`", c.name);
+        self.gcx
+            .dcx()
+            .err(msg)
+            .span(expr.span)
+            .subdiagnostic(DeclaredHere::new(c.kind.to_str(), c.name.span))
+            .emit();
+    }
+
+    /// Checks that `address.code` and `address.codehash` are available in the emulated `solc`
+    /// version.
+    fn check_address_code(&self, base: &'gcx hir::Expr<'gcx>, member: Ident) {
+        if self.gcx.sess.solc_compat.is_none_or(|v| v.has_address_code()) {
+            return;
+        }
+        let Some(ty) = self.expr_ty(base) else { return };
+        if let TyKind::Elementary(ElementaryType::Address(_)) = self.peel_value_ref(ty).kind {
+            let msg = format!("`address.{}` is not available before solc 0.8.0", member.name);
+            let mut err = self.gcx.dcx().err(msg).span(member.span);
+            if member.name == sym::codehash {
+                err = err.help("use `extcodehash` in inline assembly instead");
+            } else {
+                err = err.help("use `extcodesize` and `extcodecopy` in inline assembly instead");
+            }
+            err.emit();
+        }
+    }
+}
+
+impl<'gcx> Visit<'gcx> for TyChecker<'gcx> {
+    type BreakValue = ();
+
+    fn hir(&self) -> &'gcx hir::Hir<'gcx> {
+        &self.gcx.hir
+    }
+
+    fn visit_nested_contract(&mut self, id: hir::ContractId) -> ControlFlow<Self::BreakValue> {
+        if !self.gcx.is_contract_analyzed(Some(id)) {
+            return ControlFlow::Continue(());
+        }
+        let prev = self.contract.replace(id);
+        let r = self.visit_contract(self.hir().contract(id));
+        self.contract = prev;
+        r
+    }
+
+    fn visit_nested_function(&mut self, id: hir::FunctionId) -> ControlFlow<Self::BreakValue> {
+        let prev = self.function.replace(id);
+        let r = self.visit_function(self.hir().function(id));
+        self.function = prev;
+        r
+    }
+
+    fn visit_nested_var(&mut self, id: hir::VariableId) -> ControlFlow<Self::BreakValue> {
+        let var = self.hir().variable(id);
+        if let Some(init) = var.initializer {
+            self.check_implicit_conversion(init, self.gcx.type_of_item(id.into()));
+        }
+        self.visit_var(var)
+    }
+
+    fn visit_modifier(
+        &mut self,
+        modifier: &'gcx hir::Modifier<'gcx>,
+    ) -> ControlFlow<Self::BreakValue> {
+        self.check_base_constructor_call(modifier);
+        self.walk_modifier(modifier)
+    }
+
+    fn visit_stmt(&mut self, stmt: &'gcx hir::Stmt<'gcx>) -> ControlFlow<Self::BreakValue> {
+        match stmt.kind {
+            hir::StmtKind::Return(Some(expr)) => self.check_return(expr),
+            hir::StmtKind::Emit(res, ref args) => {
+                self.check_emit(stmt, res, args);
+                self.check_named_args(stmt.span, res, args);
+            }
+            hir::StmtKind::Revert(res, ref args) => self.check_named_args(stmt.span, res, args),
+            hir::StmtKind::DeclMulti(vars, value) => {
+                let gcx = self.gcx;
+                let targets = vars
+                    .iter()
+                    .map(|var| match *var {
+                        Some(var) => {
+                            Target::Value(gcx.item_span(var), Some(gcx.type_of_item(var.into())))
+                        }
+                        None => Target::Omitted,
+                    })
+                    .collect::<Vec<_>>();
+                self.check_destructuring(&targets, value);
+            }
+            _ => {}
+        }
+        self.walk_stmt(stmt)
+    }
+
+    fn visit_expr(&mut self, expr: &'gcx hir::Expr<'gcx>) -> ControlFlow<Self::BreakValue> {
+        match expr.kind {
+            hir::ExprKind::Ident(_) => self.check_builtin_availability(expr),
+            hir::ExprKind::Member(base, member) => {
+                self.check_member_access(base, member);
+                self.check_this_member(base, member);
+                self.check_enum_member(base, member);
+                self.check_struct_member(expr, base, member);
+                self.check_mapping_member(expr, base, member);
+                self.check_builtin_availability(expr);
+                self.check_block_difficulty(expr, member);
+            }
+            hir::ExprKind::Delete(operand) => self.check_delete(operand),
+            hir::ExprKind::Payable(arg) => self.check_payable_conversion(expr, arg),
+            hir::ExprKind::Assign(lhs, None, rhs) => {
+                if let hir::ExprKind::Tuple(exprs) = lhs.peel_parens().kind {
+                    if exprs.len() != 1 {
+                        self.check_destructuring(&self.assignment_targets(exprs), rhs);
+                    }
+                } else if let Some(ty) = self.expr_ty(lhs) {
+                    self.check_implicit_conversion(rhs, ty);
+                }
+            }
+            hir::ExprKind::Binary(lhs, op, rhs) => {
+                self.check_binary(expr, lhs, op.kind, rhs);
+                if self.gcx.sess.is_lint_enabled(&builtin::BOOL_LITERAL_COMPARISONS) {
+                    self.check_bool_literal_comparison(expr, lhs, op.kind, rhs);
+                }
+            }
+            hir::ExprKind::Call(callee, ref args) => {
+                let prev = self.callee.replace(match callee.peel_parens().kind {
+                    hir::ExprKind::CallOptions(callee, _) => callee.peel_parens().id,
+                    _ => callee.peel_parens().id,
+                });
+                self.check_event_call(expr, callee);
+                self.check_concat(callee, args);
+                self.check_encode_call(expr, callee, args);
+                self.check_enum_conversion(callee, args);
+                if self.gcx.sess.is_lint_enabled(&builtin::REDUNDANT_CONVERSIONS) {
+                    self.check_redundant_conversion(expr, callee, args);
+                }
+                self.check_builtin_call(expr, callee, args);
+                self.check_overloaded_call(expr, callee, args);
+                self.check_selfdestruct(expr, callee);
+                if let hir::ExprKind::Ident(res) = callee.peel_parens().kind {
+                    self.check_named_args(expr.span, res, args);
+                }
+                let r = self.walk_expr(expr);
+                self.callee = prev;
+                return r;
+            }
+            // Types in expressions are not otherwise lowered, so evaluate their array sizes here.
+            hir::ExprKind::New(ref ty) => {
+                self.check_new_contract(expr, ty);
+                let _ = self.gcx.type_of_hir_ty(ty);
+            }
+            hir::ExprKind::TypeCall(ref ty) | hir::ExprKind::Type(ref ty) => {
+                let _ = self.gcx.type_of_hir_ty(ty);
+            }
+            _ => {}
+        }
+        self.walk_expr(expr)
+    }
+}
@@ -0,0 +1,91 @@
+//! Lint for functions whose cyclomatic complexity exceeds a threshold.
+//!
+//! The cyclomatic complexity of a function is one plus its number of decision points: `if`
+//! statements, loop conditions, `&&` and `||` operators, ternaries and `catch` clauses. Loops are
+//! counted through the `if` of their desugared condition, so a `for` loop without a condition is
+//! not a decision point. Inline assembly is not lowered, and is not counted.
+
+use crate::{
+    hir::{self, Visit},
+    lint::{builtin, Lint, LintContext, LintInfo},
+    ty::Gcx,
+};
+use solar_ast::ast::BinOpKind;
+use std::ops::ControlFlow;
+
+/// Warns about functions whose cyclomatic complexity exceeds
+/// [`Session::max_cyclomatic_complexity`](solar_interface::Session::max_cyclomatic_complexity).
+pub(crate) struct CyclomaticComplexity;
+
+impl Lint for CyclomaticComplexity {
+    fn info(&self) -> &'static LintInfo {
+        &builtin::CYCLOMATIC_COMPLEXITY
+    }
+
+    fn check_function(&self, cx: &LintContext<'_>, id: hir::FunctionId) {
+        let gcx = cx.gcx();
+        let func = gcx.hir.function(id);
+        if func.body.is_none() {
+            return;
+        }
+        let max = gcx.sess.max_cyclomatic_complexity;
+        let complexity = cyclomatic_complexity(gcx, id);
+        if complexity <= max {
+            return;
+        }
+
+        let span = func.name.map_or(func.span, |name| name.span);
+        let msg = match func.name {
+            Some(name) => format!("{} `{name}` is too complex", func.kind),
+            None => format!("{} function is too complex", func.kind),
+        };
+        let note = format!("its cyclomatic complexity is {complexity}, but the maximum is {max}");
+        let help = "consider splitting it into smaller functions";
+        if let Some(diag) = cx.struct_lint_with(span, msg) {
+            diag.note(note).help(help).emit();
+        }
+    }
+}
+
+/// Returns the cyclomatic complexity of the given function.
+fn cyclomatic_complexity(gcx: Gcx<'_>, id: hir::FunctionId) -> usize {
+    let mut counter = DecisionCounter { gcx, decisions: 0 };
+    let _ = counter.visit_nested_function(id);
+    1 + counter.decisions
+}
+
+/// Counts the decision points of a function.
+struct DecisionCounter<'gcx> {
+    gcx: Gcx<'gcx>,
+    decisions: usize,
+}
+
+impl<'gcx> Visit<'gcx> for DecisionCounter<'gcx> {
+    type BreakValue = ();
+
+    fn hir(&self) -> &'gcx hir::Hir<'gcx> {
+        &self.gcx.hir
+    }
+
+    fn visit_stmt(&mut self, stmt: &'gcx hir::Stmt<'gcx>) -> ControlFlow<Self::BreakValue> {
+        match stmt.kind {
+            hir::StmtKind::If(..) => self.decisions += 1,
+            hir::StmtKind::Try(try_) => self.decisions += try_.catch.len(),
+            _ => {}
+        }
+        self.walk_stmt(stmt)
+    }
+
+    fn visit_expr(&mut self, expr: &'gcx hir::Expr<'gcx>) -> ControlFlow<Self::BreakValue> {
+        match expr.kind {
+            hir::ExprKind::Binary(_, op, _)
+                if matches!(op.kind, BinOpKind::And | BinOpKind::Or) =>
+            {
+                self.decisions += 1
+            }
+            hir::ExprKind::Ternary(..) => self.decisions += 1,
+            _ => {}
+        }
+        self.walk_expr(expr)
+    }
+}
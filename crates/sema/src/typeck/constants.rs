@@ -0,0 +1,35 @@
+//! Checks the types of `constant` variables.
+
+use crate::{
+    hir,
+    ty::{Gcx, TyKind},
+};
+use solar_ast::ast::{ElementaryType, VarMut};
+
+/// Checks that the given variable, if it is `constant`, is of a type that can be `constant`.
+///
+/// Only value types can be `constant`, as well as `string` and `bytes` since solc 0.4.0.
+pub(super) fn check_variable(gcx: Gcx<'_>, id: hir::VariableId) {
+    let var = gcx.hir.variable(id);
+    if var.mutability != Some(VarMut::Constant) {
+        return;
+    }
+    let ty = gcx.type_of_hir_ty(&var.ty);
+    if ty.has_error() || ty.is_value_type() {
+        return;
+    }
+    let allows_bytes = !gcx.sess.solc_compat.is_some_and(|v| !v.has_constant_bytes());
+    if allows_bytes
+        && matches!(ty.kind, TyKind::Elementary(ElementaryType::String | ElementaryType::Bytes))
+    {
+        return;
+    }
+
+    let msg = format!("`constant` variables of type `{}` are not supported", ty.display(gcx));
+    let note = if allows_bytes {
+        "only value types, `string` and `bytes` can be `constant`"
+    } else {
+        "only value types can be `constant` before solc 0.4.0"
+    };
+    gcx.dcx().err(msg).span(var.ty.span).note(note).emit();
+}
@@ -0,0 +1,80 @@
+//! Lint for contracts that `delegatecall` and declare their own state variables.
+//!
+//! The code called with `delegatecall` runs on the storage of the calling contract, so in proxies
+//! the state variables of the proxy collide with the ones of the implementation unless both
+//! agree on the storage layout. This can't be checked without the implementation, so this is an
+//! advisory heuristic. Only `delegatecall` member calls are found, as inline assembly is not
+//! lowered.
+
+use crate::{
+    hir::{self, Visit},
+    lint::{builtin, Lint, LintContext, LintInfo},
+    ty::Gcx,
+};
+use solar_ast::ast::DataLocation;
+use solar_interface::{diagnostics::DeclaredHere, kw, Span};
+use std::ops::ControlFlow;
+
+/// Warns about contracts that use `delegatecall` and declare state variables in storage.
+pub(crate) struct DelegatecallStorage;
+
+impl Lint for DelegatecallStorage {
+    fn info(&self) -> &'static LintInfo {
+        &builtin::DELEGATECALL_STORAGE
+    }
+
+    fn check_contract(&self, cx: &LintContext<'_>, id: hir::ContractId) {
+        let gcx = cx.gcx();
+        let contract = gcx.hir.contract(id);
+        // Constants and immutables are not in storage.
+        let mut variables = contract.variables().map(|id| gcx.hir.variable(id)).filter(|var| {
+            var.mutability.is_none() && var.data_location != Some(DataLocation::Transient)
+        });
+        let Some(first_var) = variables.next() else { return };
+
+        let mut finder = DelegatecallFinder { gcx };
+        let Some(delegatecall) =
+            contract.all_functions().find_map(|id| match finder.visit_nested_function(id) {
+                ControlFlow::Break(span) => Some(span),
+                ControlFlow::Continue(()) => None,
+            })
+        else {
+            return;
+        };
+
+        let name = contract.name;
+        let msg = format!("contract `{name}` uses `delegatecall` and declares state variables");
+        let note = "the called code runs on the storage of this contract, and overwrites these \
+                    variables unless its storage layout starts with the same variables";
+        let help = "store the variables of proxies at dedicated slots, like the ones of ERC-1967";
+        if let Some(diag) = cx.struct_lint_with(name.span, msg) {
+            diag.span_note(delegatecall, "`delegatecall` used here")
+                .subdiagnostic(DeclaredHere::new("state variable", first_var.span))
+                .note(note)
+                .help(help)
+                .emit();
+        }
+    }
+}
+
+/// Finds the first `delegatecall` member access.
+struct DelegatecallFinder<'gcx> {
+    gcx: Gcx<'gcx>,
+}
+
+impl<'gcx> Visit<'gcx> for DelegatecallFinder<'gcx> {
+    type BreakValue = Span;
+
+    fn hir(&self) -> &'gcx hir::Hir<'gcx> {
+        &self.gcx.hir
+    }
+
+    fn visit_expr(&mut self, expr: &'gcx hir::Expr<'gcx>) -> ControlFlow<Self::BreakValue> {
+        if let hir::ExprKind::Member(_, member) = expr.kind {
+            if member.name == kw::Delegatecall {
+                return ControlFlow::Break(expr.span);
+            }
+        }
+        self.walk_expr(expr)
+    }
+}
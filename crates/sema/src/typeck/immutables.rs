@@ -0,0 +1,245 @@
+//! Definite-assignment analysis of `immutable` state variables in constructors.
+//!
+//! Since solc 0.8.21, immutables can be read before they are assigned, so this is only checked
+//! when emulating an older version.
+
+use crate::{
+    builtins::Builtin,
+    eval::ConstantEvaluator,
+    hir::{self, Visit},
+    ty::Gcx,
+};
+use solar_ast::ast::{BinOpKind, VarMut};
+use solar_data_structures::map::FxHashSet;
+use std::ops::ControlFlow;
+
+/// Checks that the immutables of the given contract are not read in its constructor before they
+/// are assigned.
+pub(super) fn check_contract(gcx: Gcx<'_>, id: hir::ContractId) {
+    if !gcx.sess.solc_compat.is_some_and(|v| !v.has_relaxed_immutables()) {
+        return;
+    }
+    let contract = gcx.hir.contract(id);
+    let Some(ctor) = contract.ctor else { return };
+    let immutables = contract
+        .variables()
+        .filter(|&id| {
+            let var = gcx.hir.variable(id);
+            var.mutability == Some(VarMut::Immutable) && var.initializer.is_none()
+        })
+        .collect::<FxHashSet<_>>();
+    if immutables.is_empty() {
+        return;
+    }
+
+    let mut checker =
+        ImmutableChecker { gcx, immutables, state: State::default(), loop_exit: None };
+    let _ = checker.visit_nested_function(ctor);
+}
+
+struct ImmutableChecker<'gcx> {
+    gcx: Gcx<'gcx>,
+    /// The immutables that must be assigned in the constructor.
+    immutables: FxHashSet<hir::VariableId>,
+    /// The state at the current point in the constructor.
+    state: State,
+    /// The join of the states at which the innermost loop is exited with `break` or `continue`.
+    loop_exit: Option<State>,
+}
+
+/// The definite-assignment state at a point in the control flow.
+#[derive(Clone, Default)]
+struct State {
+    /// The immutables that are assigned on all paths leading to this point.
+    assigned: FxHashSet<hir::VariableId>,
+    /// Whether all paths leading to this point have returned or reverted.
+    diverged: bool,
+}
+
+impl State {
+    /// Joins the states at the end of two branches.
+    fn join(self, other: Self) -> Self {
+        if self.diverged {
+            return other;
+        }
+        if other.diverged {
+            return self;
+        }
+        let assigned = self.assigned.intersection(&other.assigned).copied().collect();
+        Self { assigned, diverged: false }
+    }
+}
+
+impl<'gcx> ImmutableChecker<'gcx> {
+    /// Visits a branch of the control flow, returning its final state and restoring the current
+    /// one.
+    fn branch(&mut self, f: impl FnOnce(&mut Self) -> ControlFlow<()>) -> State {
+        let prev = self.state.clone();
+        let _ = f(self);
+        std::mem::replace(&mut self.state, prev)
+    }
+
+    /// Records the current state as an exit of the innermost loop, and diverges.
+    fn exit_loop(&mut self) {
+        let state = self.state.clone();
+        self.loop_exit = Some(match self.loop_exit.take() {
+            Some(exit) => exit.join(state),
+            None => state,
+        });
+        self.state.diverged = true;
+    }
+
+    /// Returns whether the given call always reverts: `revert()`, `revert("...")`, or `assert` and
+    /// `require` with a condition that is always false.
+    fn is_diverging_call(&self, callee: &'gcx hir::Expr<'gcx>, args: &hir::CallArgs<'gcx>) -> bool {
+        let hir::ExprKind::Ident(res) = callee.peel_parens().kind else { return false };
+        let builtin = res.iter().find_map(|res| match *res {
+            hir::Res::Builtin(builtin) => Some(builtin),
+            _ => None,
+        });
+        match builtin {
+            Some(Builtin::Revert | Builtin::RevertMsg) => true,
+            Some(Builtin::Assert | Builtin::Require | Builtin::RequireMsg) => {
+                let &hir::CallArgs::Unnamed(args) = args else { return false };
+                args.first().is_some_and(|cond| {
+                    ConstantEvaluator::new(self.gcx).try_eval(cond).is_ok_and(|v| !v.to_bool())
+                })
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the immutable referenced by the given expression, if any.
+    fn immutable(&self, expr: &'gcx hir::Expr<'gcx>) -> Option<hir::VariableId> {
+        match expr.peel_parens().kind {
+            hir::ExprKind::Ident(&[hir::Res::Item(hir::ItemId::Variable(id))])
+                if self.immutables.contains(&id) =>
+            {
+                Some(id)
+            }
+            _ => None,
+        }
+    }
+
+    fn check_read(&self, expr: &'gcx hir::Expr<'gcx>, id: hir::VariableId) {
+        if self.state.diverged || self.state.assigned.contains(&id) {
+            return;
+        }
+        let var = self.gcx.hir.variable(id);
+        let name = var.name.expect("immutables are named");
+        let msg = format!("immutable variable `{name}` is read before being assigned");
+        let note = "immutable variables must be assigned before they are read";
+        self.gcx.dcx().err(msg).span(expr.span).span_note(var.span, note).emit();
+    }
+
+    /// Visits the left-hand side of an assignment.
+    fn visit_assignee(&mut self, expr: &'gcx hir::Expr<'gcx>, compound: bool) -> ControlFlow<()> {
+        let expr = expr.peel_parens();
+        if let hir::ExprKind::Tuple(exprs) = expr.kind {
+            for expr in exprs.iter().copied().flatten() {
+                self.visit_assignee(expr, compound)?;
+            }
+            return ControlFlow::Continue(());
+        }
+        match self.immutable(expr) {
+            Some(id) => {
+                if compound {
+                    self.check_read(expr, id);
+                }
+                self.state.assigned.insert(id);
+                ControlFlow::Continue(())
+            }
+            None => self.visit_expr(expr),
+        }
+    }
+}
+
+impl<'gcx> Visit<'gcx> for ImmutableChecker<'gcx> {
+    type BreakValue = ();
+
+    fn hir(&self) -> &'gcx hir::Hir<'gcx> {
+        &self.gcx.hir
+    }
+
+    fn visit_stmt(&mut self, stmt: &'gcx hir::Stmt<'gcx>) -> ControlFlow<Self::BreakValue> {
+        match stmt.kind {
+            hir::StmtKind::If(cond, then, else_) => {
+                self.visit_expr(cond)?;
+                let then = self.branch(|this| this.visit_stmt(then));
+                let else_ = self.branch(|this| match else_ {
+                    Some(else_) => this.visit_stmt(else_),
+                    None => ControlFlow::Continue(()),
+                });
+                self.state = then.join(else_);
+            }
+            hir::StmtKind::Loop(block, source) => {
+                let outer = self.loop_exit.take();
+                if source == hir::LoopSource::DoWhile {
+                    // The body of a `do while` loop is executed at least once, and the loop is
+                    // only exited with `break`, or `continue` if the condition is false.
+                    let _ = block.iter().try_for_each(|stmt| self.visit_stmt(stmt));
+                    self.state = self
+                        .loop_exit
+                        .take()
+                        .unwrap_or_else(|| State { diverged: true, ..State::default() });
+                } else {
+                    // The body of other loops may not be executed at all.
+                    self.branch(|this| block.iter().try_for_each(|stmt| this.visit_stmt(stmt)));
+                }
+                self.loop_exit = outer;
+            }
+            hir::StmtKind::Try(try_) => {
+                self.visit_expr(&try_.expr)?;
+                let mut state = self
+                    .branch(|this| try_.block.iter().try_for_each(|stmt| this.visit_stmt(stmt)));
+                for catch in try_.catch {
+                    let catch_state = self.branch(|this| {
+                        catch.block.iter().try_for_each(|stmt| this.visit_stmt(stmt))
+                    });
+                    state = state.join(catch_state);
+                }
+                self.state = state;
+            }
+            hir::StmtKind::Return(_) | hir::StmtKind::Revert(..) => {
+                self.walk_stmt(stmt)?;
+                self.state.diverged = true;
+            }
+            hir::StmtKind::Break | hir::StmtKind::Continue => self.exit_loop(),
+            _ => self.walk_stmt(stmt)?,
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_expr(&mut self, expr: &'gcx hir::Expr<'gcx>) -> ControlFlow<Self::BreakValue> {
+        match expr.kind {
+            hir::ExprKind::Ident(_) => {
+                if let Some(id) = self.immutable(expr) {
+                    self.check_read(expr, id);
+                }
+            }
+            hir::ExprKind::Assign(lhs, op, rhs) => {
+                self.visit_expr(rhs)?;
+                self.visit_assignee(lhs, op.is_some())?;
+            }
+            hir::ExprKind::Call(callee, ref args) if self.is_diverging_call(callee, args) => {
+                self.walk_expr(expr)?;
+                self.state.diverged = true;
+            }
+            hir::ExprKind::Ternary(cond, true_, false_) => {
+                self.visit_expr(cond)?;
+                let true_ = self.branch(|this| this.visit_expr(true_));
+                let false_ = self.branch(|this| this.visit_expr(false_));
+                self.state = true_.join(false_);
+            }
+            // The right-hand side of a short-circuiting operator may not be evaluated.
+            hir::ExprKind::Binary(lhs, op, rhs)
+                if matches!(op.kind, BinOpKind::And | BinOpKind::Or) =>
+            {
+                self.visit_expr(lhs)?;
+                self.branch(|this| this.visit_expr(rhs));
+            }
+            _ => self.walk_expr(expr)?,
+        }
+        ControlFlow::Continue(())
+    }
+}
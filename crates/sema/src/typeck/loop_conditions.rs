@@ -0,0 +1,205 @@
+//! Lint for loops whose condition is never updated in their body.
+//!
+//! This is a heuristic, and only considers conditions that are made up of local variables of
+//! value types, constants, literals, and operators: anything else, such as state variables,
+//! member accesses, or function calls, may change without being assigned in the loop body, so
+//! such loops are never flagged. Constant conditions like `while (true)` are not flagged either.
+//!
+//! A loop is flagged if none of the variables in its condition are assigned, incremented,
+//! decremented, or deleted in its body, and the body cannot exit the loop with `break`, `return`
+//! or `revert`. Exits through `require`, `assert`, or calls that always revert are not detected,
+//! and loops containing inline assembly are skipped since it is not lowered.
+
+use crate::{
+    builtins::Builtin,
+    hir::{self, Visit},
+    lint::{builtin, Lint, LintContext, LintInfo},
+    ty::Gcx,
+};
+use solar_ast::ast::{UnOpKind, VarMut};
+use solar_data_structures::map::FxHashSet;
+use std::ops::ControlFlow;
+
+/// Warns about loops whose condition is never updated in their body.
+pub(crate) struct UnchangedLoopCondition;
+
+impl Lint for UnchangedLoopCondition {
+    fn info(&self) -> &'static LintInfo {
+        &builtin::UNCHANGED_LOOP_CONDITION
+    }
+
+    fn check_function(&self, cx: &LintContext<'_>, id: hir::FunctionId) {
+        let _ = LoopFinder { cx: *cx, gcx: cx.gcx() }.visit_nested_function(id);
+    }
+}
+
+struct LoopFinder<'gcx> {
+    cx: LintContext<'gcx>,
+    gcx: Gcx<'gcx>,
+}
+
+impl<'gcx> LoopFinder<'gcx> {
+    fn check_loop(&self, block: hir::Block<'gcx>, source: hir::LoopSource) {
+        // See the desugaring in `lower_loop_stmt`.
+        let (cond, body) = match (source, block) {
+            (
+                hir::LoopSource::While | hir::LoopSource::For,
+                [hir::Stmt { kind: hir::StmtKind::If(cond, body, Some(_)), .. }],
+            ) => (*cond, std::slice::from_ref(*body)),
+            (
+                hir::LoopSource::DoWhile,
+                [body, hir::Stmt { kind: hir::StmtKind::If(cond, _, Some(_)), .. }],
+            ) => (*cond, std::slice::from_ref(body)),
+            _ => return,
+        };
+
+        let mut vars = ConditionVars { gcx: self.gcx, vars: FxHashSet::default() };
+        if vars.visit_expr(cond).is_break() || vars.vars.is_empty() {
+            return;
+        }
+        let mut exits = LoopExits { vars: &vars.vars, gcx: self.gcx, depth: 0 };
+        if body.iter().any(|stmt| exits.visit_stmt(stmt).is_break()) {
+            return;
+        }
+
+        let msg = format!("the condition of this `{}` loop is never updated", source.name());
+        let note = "none of the variables in the condition are assigned in the loop body, and the \
+                    loop has no `break`, `return` or `revert`, so it may never terminate";
+        if let Some(diag) = self.cx.struct_lint_with(cond.span, msg) {
+            diag.note(note).emit();
+        }
+    }
+}
+
+impl<'gcx> Visit<'gcx> for LoopFinder<'gcx> {
+    type BreakValue = ();
+
+    fn hir(&self) -> &'gcx hir::Hir<'gcx> {
+        &self.gcx.hir
+    }
+
+    fn visit_stmt(&mut self, stmt: &'gcx hir::Stmt<'gcx>) -> ControlFlow<Self::BreakValue> {
+        if let hir::StmtKind::Loop(block, source) = stmt.kind {
+            self.check_loop(block, source);
+        }
+        self.walk_stmt(stmt)
+    }
+}
+
+/// Collects the local variables of a loop condition, or breaks if the condition may change
+/// without them being assigned.
+struct ConditionVars<'gcx> {
+    gcx: Gcx<'gcx>,
+    vars: FxHashSet<hir::VariableId>,
+}
+
+impl<'gcx> Visit<'gcx> for ConditionVars<'gcx> {
+    type BreakValue = ();
+
+    fn hir(&self) -> &'gcx hir::Hir<'gcx> {
+        &self.gcx.hir
+    }
+
+    fn visit_expr(&mut self, expr: &'gcx hir::Expr<'gcx>) -> ControlFlow<Self::BreakValue> {
+        match expr.kind {
+            hir::ExprKind::Ident(&[hir::Res::Item(hir::ItemId::Variable(id))]) => {
+                let var = self.gcx.hir.variable(id);
+                if var.mutability == Some(VarMut::Constant) {
+                    return ControlFlow::Continue(());
+                }
+                if var.is_state_variable || !self.gcx.type_of_hir_ty(&var.ty).is_value_type() {
+                    return ControlFlow::Break(());
+                }
+                self.vars.insert(id);
+                ControlFlow::Continue(())
+            }
+            hir::ExprKind::Unary(op, _)
+                if matches!(
+                    op.kind,
+                    UnOpKind::PreInc | UnOpKind::PreDec | UnOpKind::PostInc | UnOpKind::PostDec
+                ) =>
+            {
+                ControlFlow::Break(())
+            }
+            hir::ExprKind::Lit(_)
+            | hir::ExprKind::Binary(..)
+            | hir::ExprKind::Unary(..)
+            | hir::ExprKind::Ternary(..)
+            | hir::ExprKind::Tuple(_) => self.walk_expr(expr),
+            _ => ControlFlow::Break(()),
+        }
+    }
+}
+
+/// Breaks on the first assignment to one of `vars`, or on the first statement that may exit the
+/// loop.
+struct LoopExits<'a, 'gcx> {
+    gcx: Gcx<'gcx>,
+    vars: &'a FxHashSet<hir::VariableId>,
+    /// The number of nested loops, in which `break` does not exit the loop being checked.
+    depth: usize,
+}
+
+impl LoopExits<'_, '_> {
+    /// Returns `true` if the given assignee is one of the condition variables.
+    fn is_condition_var(&self, expr: &hir::Expr<'_>) -> bool {
+        match expr.peel_parens().kind {
+            hir::ExprKind::Ident(&[hir::Res::Item(hir::ItemId::Variable(id))]) => {
+                self.vars.contains(&id)
+            }
+            hir::ExprKind::Tuple(exprs) => exprs.iter().flatten().any(|e| self.is_condition_var(e)),
+            _ => false,
+        }
+    }
+}
+
+impl<'gcx> Visit<'gcx> for LoopExits<'_, 'gcx> {
+    type BreakValue = ();
+
+    fn hir(&self) -> &'gcx hir::Hir<'gcx> {
+        &self.gcx.hir
+    }
+
+    fn visit_stmt(&mut self, stmt: &'gcx hir::Stmt<'gcx>) -> ControlFlow<Self::BreakValue> {
+        match stmt.kind {
+            hir::StmtKind::Break if self.depth == 0 => return ControlFlow::Break(()),
+            hir::StmtKind::Return(_)
+            | hir::StmtKind::Revert(..)
+            | hir::StmtKind::Placeholder
+            | hir::StmtKind::Err(_) => return ControlFlow::Break(()),
+            hir::StmtKind::Loop(..) => {
+                self.depth += 1;
+                let r = self.walk_stmt(stmt);
+                self.depth -= 1;
+                return r;
+            }
+            _ => {}
+        }
+        self.walk_stmt(stmt)
+    }
+
+    fn visit_expr(&mut self, expr: &'gcx hir::Expr<'gcx>) -> ControlFlow<Self::BreakValue> {
+        let breaks = match expr.kind {
+            hir::ExprKind::Assign(lhs, ..) | hir::ExprKind::Delete(lhs) => {
+                self.is_condition_var(lhs)
+            }
+            hir::ExprKind::Unary(op, operand) => {
+                matches!(
+                    op.kind,
+                    UnOpKind::PreInc | UnOpKind::PreDec | UnOpKind::PostInc | UnOpKind::PostDec
+                ) && self.is_condition_var(operand)
+            }
+            hir::ExprKind::Call(callee, _) => match callee.kind {
+                hir::ExprKind::Ident(res) => res.iter().any(|res| {
+                    matches!(res, hir::Res::Builtin(Builtin::Revert | Builtin::RevertMsg))
+                }),
+                _ => false,
+            },
+            _ => false,
+        };
+        if breaks {
+            return ControlFlow::Break(());
+        }
+        self.walk_expr(expr)
+    }
+}
@@ -1,19 +1,77 @@
 use crate::{
     ast_lowering::resolve::{Declaration, Declarations},
     hir::{self, Res},
+    lint::{self, LintContext},
     ty::{Gcx, Ty},
 };
 use rayon::prelude::*;
-use solar_data_structures::{map::FxHashSet, parallel};
+use solar_ast::ast::{ContractKind, DataLocation, StateMutability, Visibility};
+use solar_data_structures::{
+    map::{FxHashMap, FxHashSet},
+    parallel,
+    smallvec::SmallVec,
+};
+use solar_interface::{diagnostics::Applicability, Symbol};
+
+mod assert_false;
+mod checker;
+mod complexity;
+mod constants;
+mod delegatecall_storage;
+mod immutables;
+mod loop_conditions;
+mod msg_data;
+mod unused;
+mod unused_payable;
+
+pub(crate) use assert_false::AssertFalse;
+pub(crate) use complexity::CyclomaticComplexity;
+pub(crate) use delegatecall_storage::DelegatecallStorage;
+pub(crate) use loop_conditions::UnchangedLoopCondition;
+pub(crate) use msg_data::{ManualDispatch, MsgDataInInternal};
+pub(crate) use unused::{
+    UnusedErrors, UnusedEvents, UnusedFunctions, UnusedImports, UnusedVariables,
+};
+pub(crate) use unused_payable::UnusedPayable;
 
 pub(crate) fn check(gcx: Gcx<'_>) {
+    let lints = lint::enabled_lints(gcx.sess);
+    let lints = &lints[..];
     parallel!(
         gcx.sess,
         gcx.hir.par_contract_ids().for_each(|id| {
+            if !gcx.is_contract_analyzed(Some(id)) {
+                return;
+            }
             check_duplicate_definitions(gcx, &gcx.symbol_resolver.contract_scopes[id]);
+            check_overrides(gcx, id);
+            check_unimplemented_functions(gcx, id);
+            immutables::check_contract(gcx, id);
+            for lint in lints {
+                lint.check_contract(&LintContext::new(gcx, lint.info()), id);
+            }
         }),
         gcx.hir.par_source_ids().for_each(|id| {
             check_duplicate_definitions(gcx, &gcx.symbol_resolver.source_scopes[id]);
+            checker::check_source(gcx, id);
+            for lint in lints {
+                lint.check_source(&LintContext::new(gcx, lint.info()), id);
+            }
+        }),
+        gcx.hir.par_variable_ids().for_each(|id| {
+            if gcx.is_contract_analyzed(gcx.hir.variable(id).contract) {
+                constants::check_variable(gcx, id);
+            }
+        }),
+        gcx.hir.par_function_ids().for_each(|id| {
+            if !gcx.is_contract_analyzed(gcx.hir.function(id).contract) {
+                return;
+            }
+            check_mapping_parameters(gcx, id);
+            check_calldata_returns(gcx, id);
+            for lint in lints {
+                lint.check_function(&LintContext::new(gcx, lint.info()), id);
+            }
         }),
     );
 }
@@ -76,6 +134,295 @@ fn check_duplicate_definitions(gcx: Gcx<'_>, scope: &Declarations) {
     }
 }
 
+/// Checks that the mapping parameters and return values of the given function are in `storage`.
+///
+/// Whether mappings are allowed in the signature at all is checked with the external interface.
+fn check_mapping_parameters(gcx: Gcx<'_>, id: hir::FunctionId) {
+    let f = gcx.hir.function(id);
+    if f.is_getter() {
+        return;
+    }
+    for var_id in f.variables() {
+        let var = gcx.hir.variable(var_id);
+        if !matches!(var.ty.kind, hir::TypeKind::Mapping(_)) {
+            continue;
+        }
+        if var.data_location == Some(DataLocation::Storage) {
+            continue;
+        }
+        let kind = if f.parameters.contains(&var_id) { "parameters" } else { "return values" };
+        let given = match var.data_location {
+            Some(loc) => format!("`{}` was given", loc.to_str()),
+            None => "none was given".to_string(),
+        };
+        let msg = format!("data location must be `storage` for mapping {kind}, but {given}");
+        gcx.dcx().err(msg).span(var.span).emit();
+    }
+}
+
+/// Checks that the return values of the given function are not in `calldata` when emulating a
+/// `solc` version that does not allow it.
+fn check_calldata_returns(gcx: Gcx<'_>, id: hir::FunctionId) {
+    if gcx.sess.solc_compat.is_none_or(|v| v.has_calldata_returns()) {
+        return;
+    }
+    let f = gcx.hir.function(id);
+    for &var_id in f.returns {
+        let var = gcx.hir.variable(var_id);
+        // Mapping return values are checked separately.
+        if var.data_location != Some(DataLocation::Calldata)
+            || matches!(var.ty.kind, hir::TypeKind::Mapping(_))
+        {
+            continue;
+        }
+        let msg = "data location must be `memory` for return values before solc 0.6.9, \
+                   but `calldata` was given";
+        gcx.dcx()
+            .err(msg)
+            .span(var.span)
+            .note("calldata is read-only and only holds the arguments of external calls")
+            .help("use `memory` instead")
+            .emit();
+    }
+}
+
+/// Checks that the functions defined in the given contract are compatible with the base functions
+/// they override.
+fn check_overrides(gcx: Gcx<'_>, id: hir::ContractId) {
+    let c = gcx.hir.contract(id);
+    for f_id in c.functions() {
+        let f = gcx.hir.function(f_id);
+        if !f.is_ordinary() || f.is_getter() {
+            continue;
+        }
+        let overridden = overridden_functions(gcx, id, f_id);
+        for &base_f_id in &overridden {
+            check_override(gcx, f_id, base_f_id);
+        }
+        check_missing_override(gcx, f_id, &overridden);
+    }
+    check_inherited_functions(gcx, id);
+}
+
+/// Checks that a function that overrides functions of its bases has the `override` specifier.
+fn check_missing_override(gcx: Gcx<'_>, f_id: hir::FunctionId, overridden: &[hir::FunctionId]) {
+    let f = gcx.hir.function(f_id);
+    if f.override_ {
+        return;
+    }
+    // Implementing an interface function does not require `override` since 0.8.8.
+    let mut overridden = overridden
+        .iter()
+        .map(|&base_f_id| gcx.hir.function(base_f_id))
+        .filter(|base_f| !gcx.hir.contract(base_f.contract.unwrap()).kind.is_interface())
+        .peekable();
+    if overridden.peek().is_none() {
+        return;
+    }
+    let mut err = gcx.dcx().err("overriding function is missing `override` specifier").span(f.span);
+    for base_f in overridden {
+        err = err.span_note(base_f.span, "overridden function is here");
+    }
+    err.emit();
+}
+
+/// Checks that functions with the same signature inherited from unrelated bases are overridden
+/// in the given contract.
+fn check_inherited_functions(gcx: Gcx<'_>, id: hir::ContractId) {
+    let c = gcx.hir.contract(id);
+    // The first function seen for each signature, from the most derived base.
+    let mut seen = FxHashMap::<Symbol, SmallVec<[hir::FunctionId; 2]>>::default();
+    for &base in c.linearized_bases {
+        for f_id in gcx.hir.contract(base).functions() {
+            let f = gcx.hir.function(f_id);
+            if !f.is_ordinary() || f.is_getter() || f.visibility == Visibility::Private {
+                continue;
+            }
+            let Some(name) = f.name else { continue };
+            let f_ty = gcx.type_of_item(f_id.into());
+            let firsts = seen.entry(name.name).or_default();
+            let Some(&first) = firsts
+                .iter()
+                .find(|&&first| same_external_params(gcx, gcx.type_of_item(first.into()), f_ty))
+            else {
+                firsts.push(f_id);
+                continue;
+            };
+
+            // Functions of this contract are checked in `check_missing_override`, and if the
+            // contract of `first` inherits `base`, the error was already reported there.
+            let first_contract = gcx.hir.function(first).contract.unwrap();
+            if first_contract == id
+                || gcx.hir.contract(first_contract).linearized_bases.contains(&base)
+            {
+                continue;
+            }
+            let msg = format!(
+                "derived contract must override function `{}`; \
+                 two or more base contracts define a function with the same signature",
+                gcx.item_signature(first.into()),
+            );
+            gcx.dcx()
+                .err(msg)
+                .span(c.name.span)
+                .span_note(gcx.hir.function(first).span, "first definition")
+                .span_note(f.span, "second definition")
+                .emit();
+        }
+    }
+}
+
+/// Checks that the given contract is declared `abstract` if it has functions or modifiers without
+/// an implementation, including inherited ones that are not implemented by a more derived base.
+fn check_unimplemented_functions(gcx: Gcx<'_>, id: hir::ContractId) {
+    let c = gcx.hir.contract(id);
+    if c.kind != ContractKind::Contract {
+        return;
+    }
+    let unimplemented = gcx
+        .contract_members(id)
+        .all()
+        .iter()
+        .filter_map(|member| member.id.as_function())
+        .filter(|&f_id| gcx.hir.function(f_id).body.is_none())
+        .collect::<Vec<_>>();
+    if unimplemented.is_empty() {
+        return;
+    }
+
+    let msg = format!("contract `{}` should be marked as `abstract`", c.name);
+    let mut err = gcx.dcx().err(msg).span(c.name.span);
+    for f_id in unimplemented {
+        let f = gcx.hir.function(f_id);
+        let span = f.name.map_or(f.span, |name| name.span);
+        err = err.span_note(span, "missing implementation");
+    }
+    err.span_suggestion(
+        c.span.with_hi(c.name.span.hi()),
+        "mark the contract as `abstract`",
+        format!("abstract contract {}", c.name),
+        Applicability::MachineApplicable,
+    )
+    .emit();
+}
+
+/// Returns the closest functions in the base contracts of `id` that are overridden by `f_id`.
+fn overridden_functions(
+    gcx: Gcx<'_>,
+    id: hir::ContractId,
+    f_id: hir::FunctionId,
+) -> SmallVec<[hir::FunctionId; 2]> {
+    let f = gcx.hir.function(f_id);
+    let f_ty = gcx.type_of_item(f_id.into());
+    let mut overridden = SmallVec::<[hir::FunctionId; 2]>::new();
+    for &base in &gcx.hir.contract(id).linearized_bases[1..] {
+        // Skip functions that are already overridden by a more derived base.
+        let already_overridden = overridden.iter().any(|&prev: &hir::FunctionId| {
+            let prev_contract = gcx.hir.function(prev).contract.unwrap();
+            gcx.hir.contract(prev_contract).linearized_bases.contains(&base)
+        });
+        if already_overridden {
+            continue;
+        }
+        // Private functions are not inherited, so they cannot be overridden.
+        let found = gcx.hir.contract(base).functions().find(|&base_f_id| {
+            let base_f = gcx.hir.function(base_f_id);
+            base_f.is_ordinary()
+                && !base_f.is_getter()
+                && base_f.visibility != Visibility::Private
+                && base_f.name.map(|n| n.name) == f.name.map(|n| n.name)
+                && same_external_params(gcx, f_ty, gcx.type_of_item(base_f_id.into()))
+        });
+        overridden.extend(found);
+    }
+    overridden
+}
+
+/// Checks that the signature of `f_id` is compatible with the base function `base_f_id`.
+fn check_override(gcx: Gcx<'_>, f_id: hir::FunctionId, base_f_id: hir::FunctionId) {
+    let f = gcx.hir.function(f_id);
+    let base_f = gcx.hir.function(base_f_id);
+
+    if !base_f.virtual_ {
+        gcx.dcx()
+            .err("trying to override non-virtual function")
+            .span(f.span)
+            .span_note(base_f.span, "overridden function is here")
+            .help("mark the overridden function as `virtual` to allow overriding it")
+            .emit();
+    }
+
+    // `external` functions can be overridden by `public` ones.
+    let base_is_external = base_f.visibility == Visibility::External;
+    if f.visibility != base_f.visibility
+        && !(base_is_external && f.visibility == Visibility::Public)
+    {
+        let msg = format!(
+            "overriding function visibility differs: `{}` overrides `{}`",
+            f.visibility, base_f.visibility
+        );
+        gcx.dcx()
+            .err(msg)
+            .span(f.span)
+            .span_note(base_f.span, "overridden function is here")
+            .emit();
+    }
+
+    if !is_mutability_compatible(base_f.state_mutability, f.state_mutability) {
+        let msg = format!(
+            "overriding function changes state mutability from `{}` to `{}`",
+            base_f.state_mutability, f.state_mutability
+        );
+        gcx.dcx()
+            .err(msg)
+            .span(f.span)
+            .span_note(base_f.span, "overridden function is here")
+            .emit();
+    }
+
+    let f_ty = gcx.type_of_item(f_id.into()).as_externally_callable_function(gcx);
+    let base_ty = gcx.type_of_item(base_f_id.into()).as_externally_callable_function(gcx);
+    if f_ty.returns() != base_ty.returns() {
+        gcx.dcx()
+            .err("overriding function return types differ")
+            .span(f.span)
+            .span_note(base_f.span, "overridden function is here")
+            .emit();
+        return;
+    }
+
+    // Parameter data locations may only differ when overriding an `external` function.
+    let params = if base_is_external { &[][..] } else { f.parameters };
+    let base_params = if base_is_external { &[][..] } else { base_f.parameters };
+    let vars = params.iter().zip(base_params).chain(f.returns.iter().zip(base_f.returns));
+    for (&var_id, &base_var_id) in vars {
+        let var = gcx.hir.variable(var_id);
+        let base_var = gcx.hir.variable(base_var_id);
+        if var.data_location != base_var.data_location {
+            let kind = if f.parameters.contains(&var_id) { "parameter" } else { "return" };
+            let msg = format!("data location of overriding function {kind} differs");
+            gcx.dcx()
+                .err(msg)
+                .span(var.span)
+                .span_note(base_var.span, "overridden declaration is here")
+                .emit();
+        }
+    }
+}
+
+/// Returns `true` if a function with mutability `base` can be overridden by one with `derived`.
+///
+/// Overriding functions may only make the mutability stricter: `nonpayable` can become `view`
+/// or `pure`, and `view` can become `pure`. `payable` cannot be changed.
+fn is_mutability_compatible(base: StateMutability, derived: StateMutability) -> bool {
+    use StateMutability::*;
+    match (base, derived) {
+        (a, b) if a == b => true,
+        (NonPayable, View | Pure) | (View, Pure) => true,
+        _ => false,
+    }
+}
+
 fn same_external_params<'gcx>(gcx: Gcx<'gcx>, a: Ty<'gcx>, b: Ty<'gcx>) -> bool {
     let key = |ty: Ty<'gcx>| ty.as_externally_callable_function(gcx).parameters().unwrap();
     key(a) == key(b)
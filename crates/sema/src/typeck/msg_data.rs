@@ -0,0 +1,137 @@
+//! Lints for uses of `msg.data` and `msg.sig`.
+//!
+//! These are heuristics: forwarders and proxies read the calldata in their fallback or dispatcher
+//! functions, so reading it in an internal function, or decoding it by hand, is usually a mistake,
+//! but not always.
+
+use crate::{
+    builtins::Builtin,
+    hir::{self, Visit},
+    lint::{builtin, Lint, LintContext, LintInfo},
+};
+use solar_ast::ast::{BinOpKind, Visibility};
+use solar_interface::{sym, Ident};
+use std::ops::ControlFlow;
+
+/// Warns about reads of `msg.data` and `msg.sig` in internal and private functions.
+pub(crate) struct MsgDataInInternal;
+
+impl Lint for MsgDataInInternal {
+    fn info(&self) -> &'static LintInfo {
+        &builtin::MSG_DATA_IN_INTERNAL
+    }
+
+    fn check_contract(&self, cx: &LintContext<'_>, id: hir::ContractId) {
+        let gcx = cx.gcx();
+        let contract = gcx.hir.contract(id);
+        for id in contract.functions() {
+            let func = gcx.hir.function(id);
+            if !func.is_ordinary() || func.visibility > Visibility::Internal {
+                continue;
+            }
+            let _ = MsgDataFinder { cx: *cx, func }.visit_function(func);
+        }
+    }
+}
+
+struct MsgDataFinder<'gcx> {
+    cx: LintContext<'gcx>,
+    func: &'gcx hir::Function<'gcx>,
+}
+
+impl<'gcx> Visit<'gcx> for MsgDataFinder<'gcx> {
+    type BreakValue = ();
+
+    fn hir(&self) -> &'gcx hir::Hir<'gcx> {
+        &self.cx.gcx().hir
+    }
+
+    fn visit_expr(&mut self, expr: &'gcx hir::Expr<'gcx>) -> ControlFlow<Self::BreakValue> {
+        if let Some(member) = calldata_member(expr) {
+            let name = self.func.name.expect("ordinary functions are named");
+            let visibility = self.func.visibility;
+            let msg = format!("`msg.{member}` read in {visibility} function `{name}`");
+            let note = "`msg.data` and `msg.sig` refer to the calldata of the external call, not \
+                        to the arguments of this function; they usually belong in fallback \
+                        functions and dispatchers";
+            if let Some(diag) = self.cx.struct_lint_with(expr.span, msg) {
+                diag.note(note).emit();
+            }
+        }
+        self.walk_expr(expr)
+    }
+}
+
+/// Warns about `msg.data` slices and indexing, and `msg.sig` comparisons, which usually
+/// reimplement the decoding and dispatch of external calls.
+pub(crate) struct ManualDispatch;
+
+impl Lint for ManualDispatch {
+    fn info(&self) -> &'static LintInfo {
+        &builtin::MANUAL_DISPATCH
+    }
+
+    fn check_function(&self, cx: &LintContext<'_>, id: hir::FunctionId) {
+        let _ = DispatchFinder { cx: *cx }.visit_nested_function(id);
+    }
+}
+
+struct DispatchFinder<'gcx> {
+    cx: LintContext<'gcx>,
+}
+
+impl<'gcx> Visit<'gcx> for DispatchFinder<'gcx> {
+    type BreakValue = ();
+
+    fn hir(&self) -> &'gcx hir::Hir<'gcx> {
+        &self.cx.gcx().hir
+    }
+
+    fn visit_expr(&mut self, expr: &'gcx hir::Expr<'gcx>) -> ControlFlow<Self::BreakValue> {
+        let cx = self.cx;
+        match expr.kind {
+            hir::ExprKind::Index(base, _) | hir::ExprKind::Slice(base, ..)
+                if calldata_member(base).is_some_and(|member| member.name == sym::data) =>
+            {
+                let access = if matches!(expr.kind, hir::ExprKind::Slice(..)) {
+                    "sliced"
+                } else {
+                    "indexed"
+                };
+                let note = "offsets into the calldata are not checked against the parameters of \
+                            the called function";
+                let msg = format!("`msg.data` {access} to decode the calldata manually");
+                if let Some(diag) = cx.struct_lint_with(base.span, msg) {
+                    diag.note(note)
+                        .help("declare the values as parameters of an `external` function instead")
+                        .emit();
+                }
+            }
+            hir::ExprKind::Binary(lhs, op, rhs)
+                if matches!(op.kind, BinOpKind::Eq | BinOpKind::Ne) =>
+            {
+                for side in [lhs, rhs] {
+                    if calldata_member(side).is_some_and(|member| member.name == sym::sig) {
+                        let note = "external calls are already dispatched to the function \
+                                    with the matching selector";
+                        let msg = "`msg.sig` compared to dispatch calls manually";
+                        if let Some(diag) = cx.struct_lint_with(side.span, msg) {
+                            diag.note(note)
+                                .help("declare the cases as separate `external` functions instead")
+                                .emit();
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+        self.walk_expr(expr)
+    }
+}
+
+/// Returns the member if the expression is `msg.data` or `msg.sig`.
+fn calldata_member(expr: &hir::Expr<'_>) -> Option<Ident> {
+    let hir::ExprKind::Member(base, member) = expr.kind else { return None };
+    let hir::ExprKind::Ident([hir::Res::Builtin(Builtin::Msg)]) = base.kind else { return None };
+    matches!(member.name, sym::data | sym::sig).then_some(member)
+}
@@ -0,0 +1,221 @@
+//! Lints for symbols that are never used: imports, private functions, variables, errors and events.
+//!
+//! References are found by name in the source text rather than in the HIR, since inline assembly
+//! and contract-level `using` directives are not lowered. This errs on the side of not reporting:
+//! any other identifier with the same name counts as a use, whatever it resolves to.
+//!
+//! The reported symbols are also recorded for the [`UnusedReport`](crate::UnusedReport).
+
+use crate::{
+    hir::{self, Res, Visit},
+    lint::{builtin, Lint, LintContext, LintInfo},
+    ty::Gcx,
+    unused::{self, UnusedKind},
+};
+use solar_ast::ast::Visibility;
+use solar_interface::{BytePos, Ident, Span, Symbol};
+use std::ops::ControlFlow;
+
+/// Warns about named imports, like `import {A} from "a.sol";` or `import "a.sol" as a;`, that are
+/// never referenced in the importing file.
+///
+/// Plain imports of all the declarations of a file, like `import "a.sol";`, are not reported.
+pub(crate) struct UnusedImports;
+
+impl Lint for UnusedImports {
+    fn info(&self) -> &'static LintInfo {
+        &builtin::UNUSED_IMPORTS
+    }
+
+    fn check_source(&self, cx: &LintContext<'_>, id: hir::SourceId) {
+        let gcx = cx.gcx();
+        let sm = gcx.sess.source_map();
+        let mut imports = Vec::new();
+        for (&name, decls) in &gcx.symbol_resolver.source_scopes[id].declarations {
+            for decl in decls {
+                let imported = match decl.res {
+                    Res::Namespace(_) => true,
+                    // Items declared in this source are declared at their name.
+                    Res::Item(item) => gcx.item_name_opt(item).is_none_or(|n| n.span != decl.span),
+                    Res::Builtin(_) | Res::Err(_) => false,
+                };
+                // Plain imports are declared at the whole import directive instead of a name.
+                if imported && sm.span_to_snippet(decl.span).is_ok_and(|s| s == name.as_str()) {
+                    imports.push(Ident::new(name, decl.span));
+                }
+            }
+        }
+        imports.sort_by_key(|import| import.span.lo());
+        imports.dedup_by_key(|import| import.span);
+
+        for import in imports {
+            if !is_referenced(gcx, id, import, None) {
+                let msg = format!("import `{import}` is never used");
+                report(cx, UnusedKind::Import, import, msg, "remove the import");
+            }
+        }
+    }
+}
+
+/// Warns about private functions that are never referenced in their contract.
+///
+/// Internal and free functions are not reported, as they may be used from other files.
+pub(crate) struct UnusedFunctions;
+
+impl Lint for UnusedFunctions {
+    fn info(&self) -> &'static LintInfo {
+        &builtin::UNUSED_FUNCTIONS
+    }
+
+    fn check_contract(&self, cx: &LintContext<'_>, id: hir::ContractId) {
+        let gcx = cx.gcx();
+        let contract = gcx.hir.contract(id);
+        for f in contract.functions() {
+            let func = gcx.hir.function(f);
+            if !func.is_ordinary() || func.visibility != Visibility::Private {
+                continue;
+            }
+            let Some(name) = func.name else { continue };
+            if !is_referenced(gcx, contract.source, name, Some(contract.span)) {
+                let msg = format!("private function `{name}` is never used");
+                report(cx, UnusedKind::Function, name, msg, "remove the function");
+            }
+        }
+    }
+}
+
+/// Warns about local variables and function parameters that are never referenced in their
+/// function.
+///
+/// Return parameters are not reported, as they may be used to name the returned values.
+pub(crate) struct UnusedVariables;
+
+impl Lint for UnusedVariables {
+    fn info(&self) -> &'static LintInfo {
+        &builtin::UNUSED_VARIABLES
+    }
+
+    fn check_function(&self, cx: &LintContext<'_>, id: hir::FunctionId) {
+        let gcx = cx.gcx();
+        let func = gcx.hir.function(id);
+        let Some(body) = func.body else { return };
+
+        let mut finder = LocalFinder { gcx, locals: Vec::new() };
+        for stmt in body {
+            let _ = finder.visit_stmt(stmt);
+        }
+
+        let params = func.parameters.iter().map(|&var| (var, "parameter"));
+        let locals = finder.locals.into_iter().map(|var| (var, "variable"));
+        for (var, kind) in params.chain(locals) {
+            let Some(name) = gcx.hir.variable(var).name else { continue };
+            if !is_referenced(gcx, func.source, name, Some(func.span)) {
+                let msg = format!("{kind} `{name}` is never used");
+                let help = match kind {
+                    "parameter" => "remove or comment out the name of the parameter",
+                    _ => "remove the variable",
+                };
+                report(cx, UnusedKind::Variable, name, msg, help);
+            }
+        }
+    }
+}
+
+/// Warns about custom errors that are never referenced in any source.
+pub(crate) struct UnusedErrors;
+
+impl Lint for UnusedErrors {
+    fn info(&self) -> &'static LintInfo {
+        &builtin::UNUSED_ERRORS
+    }
+
+    fn check_source(&self, cx: &LintContext<'_>, id: hir::SourceId) {
+        let gcx = cx.gcx();
+        for error in gcx.hir.errors().filter(|error| error.source == id) {
+            let name = error.name;
+            if !is_referenced_anywhere(gcx, name) {
+                let msg = format!("error `{name}` is never used");
+                report(cx, UnusedKind::Error, name, msg, "remove the error");
+            }
+        }
+    }
+}
+
+/// Warns about events that are never referenced in any source.
+pub(crate) struct UnusedEvents;
+
+impl Lint for UnusedEvents {
+    fn info(&self) -> &'static LintInfo {
+        &builtin::UNUSED_EVENTS
+    }
+
+    fn check_source(&self, cx: &LintContext<'_>, id: hir::SourceId) {
+        let gcx = cx.gcx();
+        for event in gcx.hir.events().filter(|event| event.source == id) {
+            let name = event.name;
+            if !is_referenced_anywhere(gcx, name) {
+                let msg = format!("event `{name}` is never emitted");
+                report(cx, UnusedKind::Event, name, msg, "remove the event");
+            }
+        }
+    }
+}
+
+/// Reports an unused symbol, and records it for the report if the lint is not allowed.
+fn report(cx: &LintContext<'_>, kind: UnusedKind, name: Ident, msg: String, help: &'static str) {
+    if let Some(diag) = cx.struct_lint_with(name.span, msg) {
+        diag.help(help).emit();
+        unused::record(cx.gcx(), kind, name.to_string(), name.span);
+    }
+}
+
+/// Returns `true` if the declaration `name` is referenced in the given source, in `span` if given.
+fn is_referenced(gcx: Gcx<'_>, source: hir::SourceId, name: Ident, span: Option<Span>) -> bool {
+    occurrences(gcx, source, name.name)
+        .any(|pos| pos != name.span.lo() && span.is_none_or(|s| s.lo() <= pos && pos < s.hi()))
+}
+
+/// Returns `true` if the declaration `name` is referenced in any source.
+fn is_referenced_anywhere(gcx: Gcx<'_>, name: Ident) -> bool {
+    gcx.hir.source_ids().any(|source| is_referenced(gcx, source, name, None))
+}
+
+/// Returns the positions of the identifiers named `name` in the given source.
+fn occurrences(
+    gcx: Gcx<'_>,
+    source: hir::SourceId,
+    name: Symbol,
+) -> impl Iterator<Item = BytePos> + '_ {
+    let idents = gcx.source_identifiers(source);
+    let start = idents.partition_point(|&(ident, _)| ident < name);
+    idents[start..].iter().take_while(move |&&(ident, _)| ident == name).map(|&(_, pos)| pos)
+}
+
+/// Collects the local variables declared in a function body.
+struct LocalFinder<'gcx> {
+    gcx: Gcx<'gcx>,
+    locals: Vec<hir::VariableId>,
+}
+
+impl<'gcx> Visit<'gcx> for LocalFinder<'gcx> {
+    type BreakValue = ();
+
+    fn hir(&self) -> &'gcx hir::Hir<'gcx> {
+        &self.gcx.hir
+    }
+
+    fn visit_stmt(&mut self, stmt: &'gcx hir::Stmt<'gcx>) -> ControlFlow<Self::BreakValue> {
+        match stmt.kind {
+            hir::StmtKind::DeclSingle(var) => self.locals.push(var),
+            hir::StmtKind::DeclMulti(vars, _) => self.locals.extend(vars.iter().flatten()),
+            hir::StmtKind::Try(try_) => {
+                self.locals.extend(try_.returns);
+                for catch in try_.catch {
+                    self.locals.extend(catch.args);
+                }
+            }
+            _ => {}
+        }
+        self.walk_stmt(stmt)
+    }
+}
@@ -0,0 +1,124 @@
+//! Lint for `payable` functions that never use the value they receive.
+
+use crate::{
+    builtins::Builtin,
+    hir::{self, Visit},
+    lint::{builtin, Lint, LintContext, LintInfo},
+    ty::Gcx,
+};
+use solar_ast::ast::StateMutability;
+use solar_data_structures::map::FxHashSet;
+use solar_interface::sym;
+use std::ops::ControlFlow;
+
+/// Warns about `payable` functions which never read `msg.value` nor forward value, directly or
+/// through the modifiers and the functions that they reference.
+///
+/// Constructors and `receive` functions are exempt, as they commonly accept Ether without further
+/// processing.
+pub(crate) struct UnusedPayable;
+
+impl Lint for UnusedPayable {
+    fn info(&self) -> &'static LintInfo {
+        &builtin::UNUSED_PAYABLE
+    }
+
+    fn check_contract(&self, cx: &LintContext<'_>, id: hir::ContractId) {
+        let gcx = cx.gcx();
+        let contract = gcx.hir.contract(id);
+        for id in contract.functions().chain(contract.fallback) {
+            let func = gcx.hir.function(id);
+            if func.state_mutability != StateMutability::Payable || func.body.is_none() {
+                continue;
+            }
+            if uses_value(gcx, id) {
+                continue;
+            }
+
+            let span = func.name.map_or(func.span, |name| name.span);
+            let msg = match func.name {
+                Some(name) => format!("payable function `{name}` does not use `msg.value`"),
+                None => format!("payable {} function does not use `msg.value`", func.kind),
+            };
+            let note = "Ether sent to this function may get stuck in the contract; \
+                        remove `payable` if this is not intended";
+            if let Some(diag) = cx.struct_lint_with(span, msg) {
+                diag.note(note).emit();
+            }
+        }
+    }
+}
+
+/// Returns `true` if the given function may read `msg.value` or forward value.
+fn uses_value(gcx: Gcx<'_>, id: hir::FunctionId) -> bool {
+    let mut finder = ValueFinder { gcx, seen: FxHashSet::default(), queue: vec![id] };
+    while let Some(id) = finder.queue.pop() {
+        if !finder.seen.insert(id) {
+            continue;
+        }
+        let func = gcx.hir.function(id);
+        for modifier in func.modifiers {
+            match modifier.id {
+                hir::ItemId::Function(modifier) => finder.queue.push(modifier),
+                hir::ItemId::Contract(base) => finder.queue.extend(gcx.hir.contract(base).ctor),
+                _ => {}
+            }
+        }
+        if finder.visit_function(func).is_break() {
+            return true;
+        }
+    }
+    false
+}
+
+/// Finds reads of `msg.value` and value transfers, and collects the referenced functions.
+struct ValueFinder<'gcx> {
+    gcx: Gcx<'gcx>,
+    seen: FxHashSet<hir::FunctionId>,
+    queue: Vec<hir::FunctionId>,
+}
+
+impl<'gcx> Visit<'gcx> for ValueFinder<'gcx> {
+    type BreakValue = ();
+
+    fn hir(&self) -> &'gcx hir::Hir<'gcx> {
+        &self.gcx.hir
+    }
+
+    fn visit_stmt(&mut self, stmt: &'gcx hir::Stmt<'gcx>) -> ControlFlow<Self::BreakValue> {
+        // Inline assembly is not lowered, and may read `callvalue()`.
+        if let hir::StmtKind::Err(_) = stmt.kind {
+            return ControlFlow::Break(());
+        }
+        self.walk_stmt(stmt)
+    }
+
+    fn visit_expr(&mut self, expr: &'gcx hir::Expr<'gcx>) -> ControlFlow<Self::BreakValue> {
+        match expr.kind {
+            hir::ExprKind::Ident(res) => {
+                for &res in res {
+                    if let hir::Res::Item(hir::ItemId::Function(id)) = res {
+                        self.queue.push(id);
+                    }
+                }
+            }
+            hir::ExprKind::Member(base, member) if member.name == sym::value => {
+                if let hir::ExprKind::Ident([hir::Res::Builtin(Builtin::Msg)]) = base.kind {
+                    return ControlFlow::Break(());
+                }
+            }
+            hir::ExprKind::CallOptions(_, options) => {
+                if options.iter().any(|option| option.name.name == sym::value) {
+                    return ControlFlow::Break(());
+                }
+            }
+            hir::ExprKind::Member(_, member)
+                if matches!(member.name, sym::transfer | sym::send) =>
+            {
+                return ControlFlow::Break(());
+            }
+            _ => {}
+        }
+        self.walk_expr(expr)
+    }
+}
@@ -0,0 +1,130 @@
+//! The report of the symbols found by the `unused-*` lints.
+
+use crate::ty::Gcx;
+use serde::Serialize;
+use solar_interface::{Result, Session, Span};
+use std::{
+    fmt,
+    io::{self, Write},
+};
+
+/// The symbols reported by the `unused-*` lints, for bulk cleanup tools.
+///
+/// The report is built from the diagnostics emitted by the lints, so it only contains the symbols
+/// of the lints that are enabled, in the files where they are not allowed.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct UnusedReport {
+    /// The unused symbols, in source order.
+    pub symbols: Vec<UnusedSymbol>,
+}
+
+/// A symbol reported by an `unused-*` lint. See [`UnusedReport`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UnusedSymbol {
+    /// The kind of the symbol.
+    pub kind: UnusedKind,
+    /// The name of the symbol.
+    pub name: String,
+    /// The name of the file in which the symbol is declared.
+    pub file: String,
+    /// The 1-based line of the name of the symbol.
+    pub line: usize,
+    /// The 1-based column of the name of the symbol.
+    pub column: usize,
+    /// The byte offset of the start of the name of the symbol in the file.
+    pub start: usize,
+    /// The byte offset of the end of the name of the symbol in the file.
+    pub end: usize,
+    /// The span of the name of the symbol.
+    #[serde(skip)]
+    pub span: Span,
+}
+
+/// The kind of an [`UnusedSymbol`], which is the `unused-*` lint that reported it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UnusedKind {
+    /// A named import. See `unused-imports`.
+    Import,
+    /// A private function. See `unused-functions`.
+    Function,
+    /// A local variable or a function parameter. See `unused-variables`.
+    Variable,
+    /// A custom error. See `unused-errors`.
+    Error,
+    /// An event. See `unused-events`.
+    Event,
+}
+
+impl UnusedKind {
+    /// Returns the string representation of the kind.
+    pub const fn to_str(self) -> &'static str {
+        match self {
+            Self::Import => "import",
+            Self::Function => "function",
+            Self::Variable => "variable",
+            Self::Error => "error",
+            Self::Event => "event",
+        }
+    }
+}
+
+impl fmt::Display for UnusedKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.to_str())
+    }
+}
+
+impl UnusedSymbol {
+    /// Creates a new unused symbol from the span of its name.
+    pub fn new(sess: &Session, kind: UnusedKind, name: impl Into<String>, span: Span) -> Self {
+        let sm = sess.source_map();
+        let loc = sm.lookup_char_pos(span.lo());
+        let file = sm.filename_for_diagnostics(&loc.file.name).to_string();
+        let start = (span.lo() - loc.file.start_pos).0 as usize;
+        let end = (span.hi() - loc.file.start_pos).0 as usize;
+        let column = loc.col.0 + 1;
+        Self { kind, name: name.into(), file, line: loc.line, column, start, end, span }
+    }
+}
+
+impl UnusedReport {
+    /// Builds the report from the symbols reported by the `unused-*` lints.
+    ///
+    /// Must be called after type checking, which runs the lints.
+    pub fn new(gcx: Gcx<'_>) -> Self {
+        let mut symbols = gcx.unused_symbols.lock().clone();
+        symbols.sort_by_key(|symbol| (symbol.span.lo(), symbol.kind));
+        Self { symbols }
+    }
+
+    /// Renders the report as JSON.
+    pub fn to_json(&self, pretty: bool) -> String {
+        let json =
+            if pretty { serde_json::to_string_pretty(self) } else { serde_json::to_string(self) };
+        json.expect("failed to serialize unused symbols")
+    }
+}
+
+/// Records a symbol reported by an `unused-*` lint for the [`UnusedReport`].
+pub(crate) fn record(gcx: Gcx<'_>, kind: UnusedKind, name: impl Into<String>, span: Span) {
+    let symbol = UnusedSymbol::new(gcx.sess, kind, name, span);
+    gcx.unused_symbols.lock().push(symbol);
+}
+
+/// Emits the report of the unused symbols if requested with [`Session::unused_report`].
+pub(crate) fn emit(gcx: Gcx<'_>) -> Result<()> {
+    let sess = gcx.sess;
+    if !sess.unused_report {
+        return Ok(());
+    }
+    let mut rendered = UnusedReport::new(gcx).to_json(sess.pretty_json);
+    rendered.push('\n');
+    (|| {
+        let out_path = sess.out_dir.as_deref().map(|dir| dir.join("unused.json"));
+        let mut writer = crate::emit::out_writer(out_path.as_deref())?;
+        writer.write_all(rendered.as_bytes())?;
+        writer.flush()
+    })()
+    .map_err(|e: io::Error| sess.dcx.err(format!("failed to write to output: {e}")).emit())
+}
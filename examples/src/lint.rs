@@ -0,0 +1,60 @@
+use solar::{
+    config::LintLevel,
+    interface::{ColorChoice, Session},
+    sema::{
+        hir,
+        lint::{self, Lint, LintContext, LintInfo},
+        ParsingContext,
+    },
+};
+
+/// A custom lint, which is declared with its name, default level and message.
+static PUBLIC_UNDERSCORE: LintInfo = LintInfo {
+    name: "public-underscore",
+    default_level: LintLevel::Warn,
+    message: "public function name starts with an underscore",
+};
+
+/// The lint pass, which reports public and external functions whose names start with `_`.
+struct PublicUnderscore;
+
+impl Lint for PublicUnderscore {
+    fn info(&self) -> &'static LintInfo {
+        &PUBLIC_UNDERSCORE
+    }
+
+    fn check_function(&self, cx: &LintContext<'_>, id: hir::FunctionId) {
+        let func = cx.gcx().hir.function(id);
+        let Some(name) = func.name else { return };
+        if func.is_part_of_external_interface() && name.as_str().starts_with('_') {
+            // Returns `None` if the lint is allowed.
+            if let Some(diag) = cx.struct_lint(name.span) {
+                diag.help("by convention, names starting with an underscore are internal").emit();
+            }
+        }
+    }
+}
+
+#[test]
+fn main() {
+    let src = "contract C { function _f() public {} function _g() internal {} }";
+
+    let mut sess = Session::builder().with_buffer_emitter(ColorChoice::Never).build();
+    // Register the lint, and report it as an error instead of its default level.
+    lint::register(&mut sess, PublicUnderscore);
+    sess.lint_levels.set(PUBLIC_UNDERSCORE.name, LintLevel::Deny);
+
+    // Parse and analyze the source. The lint is run alongside the built-in lints.
+    let _ = sess.enter(|| -> solar::interface::Result<()> {
+        let file = sess.source_map().new_dummy_source_file("C.sol".into(), src.into()).unwrap();
+        let mut pcx = ParsingContext::new(&sess);
+        pcx.add_file(file);
+        solar::sema::parse_and_resolve(pcx)
+    });
+
+    // Only `_f` is reported.
+    let Err(diags) = sess.emitted_diagnostics().unwrap() else { panic!("no errors emitted") };
+    let diags = diags.to_string();
+    println!("{diags}");
+    assert_eq!(diags.matches("error: public function name starts with an underscore").count(), 1);
+}
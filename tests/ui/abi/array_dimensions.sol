@@ -0,0 +1,15 @@
+//@ignore-host: windows
+//@compile-flags: --emit=abi,hashes --pretty-json
+
+uint constant N = 2;
+
+contract C {
+    // 3 arrays of 2 elements each.
+    uint[2][3] public fixedFixed;
+    mapping(uint => uint[]) public mapOfArrays;
+
+    function fixedFixedParam(uint[2][3] memory a) public {}
+    function fixedDyn(uint[][5] memory a) public {}
+    function dynFixed(uint[3][] memory a) public {}
+    function constSize(uint[N * 2][N + 1] memory a) public {}
+}
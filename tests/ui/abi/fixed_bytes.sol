@@ -0,0 +1,7 @@
+//@ignore-host: windows
+//@compile-flags: --emit=abi,hashes --pretty-json --solc-compat 0.7.6
+
+contract C {
+    function f(bytes1 a, bytes16 b, bytes32 c) public {}
+    function g(byte a, byte[2] memory b) public returns (byte) {}
+}
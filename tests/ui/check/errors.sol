@@ -0,0 +1,8 @@
+//@compile-flags: --check --emit=abi
+
+contract C {
+    function f(address a) public returns (uint) {
+        a.transfer(1); //~ ERROR: member `transfer` not found on type `address`
+        return true; //~ ERROR: return value of type `bool` is not implicitly convertible to expected type `uint256`
+    }
+}
@@ -0,0 +1,10 @@
+//@compile-flags: --check --emit=abi,hashes
+
+contract C {
+    uint public x;
+
+    function f(uint a) public returns (uint) {
+        x = a;
+        return a;
+    }
+}
@@ -0,0 +1,9 @@
+//@compile-flags: --solc-compat 0.7.0
+
+contract C {
+    function f(address a) public view returns (uint256, bytes32) {
+        bytes memory code = a.code; //~ ERROR: `address.code` is not available before solc 0.8.0
+        bytes32 hash = a.codehash; //~ ERROR: `address.codehash` is not available before solc 0.8.0
+        return (code.length, hash);
+    }
+}
@@ -0,0 +1,9 @@
+//@compile-flags: --solc-compat 0.8.0
+
+contract C {
+    function f(address a) public view returns (uint256, bytes32) {
+        bytes memory code = a.code;
+        bytes32 hash = a.codehash;
+        return (code.length, hash);
+    }
+}
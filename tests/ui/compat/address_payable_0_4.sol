@@ -0,0 +1,10 @@
+//@compile-flags: --solc-compat 0.4
+
+contract C {
+    function f(address a, address payable b) public {
+        a.transfer(1);
+        a.send(1);
+        b.transfer(1);
+        b.send(1);
+    }
+}
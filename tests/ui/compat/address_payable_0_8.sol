@@ -0,0 +1,10 @@
+//@compile-flags: --solc-compat 0.8
+
+contract C {
+    function f(address a, address payable b) public {
+        a.transfer(1); //~ ERROR: member `transfer` not found on type `address`
+        a.send(1); //~ ERROR: member `send` not found on type `address`
+        b.transfer(1);
+        b.send(1);
+    }
+}
@@ -0,0 +1,8 @@
+//@compile-flags: --solc-compat 0.8.0
+
+contract C {
+    function f() public pure returns (bytes1) {
+        byte b = 0x01; //~ ERROR: `byte` has been removed in solc 0.8.0
+        return b;
+    }
+}
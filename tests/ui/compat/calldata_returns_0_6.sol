@@ -0,0 +1,11 @@
+//@compile-flags: --solc-compat 0.6.0
+
+contract C {
+    function f(bytes calldata data) external pure returns (bytes calldata) { //~ ERROR: data location must be `memory` for return values before solc 0.6.9, but `calldata` was given
+        return data;
+    }
+
+    function g(bytes calldata data) external pure returns (bytes memory) {
+        return data;
+    }
+}
@@ -0,0 +1,11 @@
+//@compile-flags: --solc-compat 0.8.0
+
+contract C {
+    function f(bytes calldata data) external pure returns (bytes calldata) {
+        return data;
+    }
+
+    function g(bytes calldata data) external pure returns (bytes memory) {
+        return data;
+    }
+}
@@ -0,0 +1,8 @@
+//@compile-flags: --solc-compat 0.8.4
+
+contract C {
+    function f(string memory a, bytes memory b) public pure {
+        bytes.concat(b, b);
+        string.concat(a, a); //~ ERROR: `string.concat` is not available before solc 0.8.12
+    }
+}
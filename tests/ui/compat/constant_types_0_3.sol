@@ -0,0 +1,8 @@
+//@compile-flags: --solc-compat 0.3.6
+
+contract C {
+    uint256 constant X = 1;
+    bytes32 constant HASH = "a";
+    string constant NAME = "name"; //~ ERROR: `constant` variables of type `string` are not supported
+    bytes constant DATA = ""; //~ ERROR: `constant` variables of type `bytes` are not supported
+}
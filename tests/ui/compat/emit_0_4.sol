@@ -0,0 +1,10 @@
+//@compile-flags: --solc-compat 0.4.21
+
+contract C {
+    event Transfer(address indexed from, address indexed to, uint256 value);
+
+    function f(address to) public {
+        emit Transfer(msg.sender, to, 1);
+        Transfer(msg.sender, to, 1);
+    }
+}
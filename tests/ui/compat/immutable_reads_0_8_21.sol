@@ -0,0 +1,10 @@
+//@compile-flags: --solc-compat 0.8.21
+
+contract C {
+    uint immutable a;
+
+    constructor() {
+        uint x = a;
+        a = x + 1;
+    }
+}
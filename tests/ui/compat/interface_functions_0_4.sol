@@ -0,0 +1,6 @@
+//@compile-flags: --solc-compat 0.4
+
+interface I {
+    function f() public;
+    function g() external;
+}
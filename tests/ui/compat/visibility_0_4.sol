@@ -0,0 +1,14 @@
+//@compile-flags: --solc-compat 0.4
+
+contract C {
+    function f() {}
+    function g() public {}
+
+    modifier m() {
+        _;
+    }
+}
+
+interface I {
+    function h();
+}
@@ -0,0 +1,14 @@
+//@compile-flags: --solc-compat 0.8
+
+contract C {
+    function f() {} //~ ERROR: no visibility specified
+    function g() public {}
+
+    modifier m() {
+        _;
+    }
+}
+
+interface I {
+    function h(); //~ ERROR: no visibility specified
+}
@@ -0,0 +1,31 @@
+//@compile-flags: -Zwarn-duplicate-function-bodies
+
+contract C {
+    uint256 total;
+
+    function deposit(uint256 amount) public {
+        require(amount > 0, "zero amount");
+        total += amount;
+    }
+
+    function add(uint256 value) public { //~ WARN: function `add` has the same body as `deposit`
+        require(value > 0, "zero amount");
+        total += value;
+    }
+
+    function withdraw(uint256 amount) public {
+        require(amount > 0, "zero amount");
+        total -= amount;
+    }
+
+    function a() public {}
+    function b() public {}
+}
+
+function double(uint256 x) pure returns (uint256) {
+    return x * 2;
+}
+
+function twice(uint256 y) pure returns (uint256 r) { //~ WARN: function `twice` has the same body as `double`
+    return y * 2;
+}
@@ -0,0 +1,11 @@
+pragma solidity ^0.8.0;
+pragma solidity >=0.8.4 <0.9.0;
+pragma solidity ^0.8.0; //~ WARN: duplicate `pragma solidity` directive
+pragma solidity ^0.7.0; //~ ERROR: conflicting `pragma solidity` directives
+
+pragma abicoder v2;
+pragma abicoder v2; //~ WARN: duplicate `pragma abicoder` directive
+pragma abicoder v1; //~ ERROR: conflicting `pragma abicoder` directives
+
+pragma experimental ABIEncoderV2;
+pragma experimental "ABIEncoderV2"; //~ WARN: duplicate `pragma experimental` directive
@@ -0,0 +1,7 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+/* SPDX-License-Identifier: MIT */ //~ WARN: duplicate SPDX license identifier
+// SPDX-License-Identifier: Apache-2.0 //~ ERROR: conflicting SPDX license identifiers
+
+contract C {}
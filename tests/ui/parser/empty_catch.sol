@@ -0,0 +1,39 @@
+//@compile-flags: -Zwarn-empty-catch
+
+contract C {
+    event Failed(bytes data);
+    error CallFailed();
+
+    function f() external pure returns (uint256) {
+        return 1;
+    }
+
+    function swallow() public {
+        try this.f() {} catch {} //~ WARN: empty `catch` clause swallows the error
+    }
+
+    function swallowTyped() public view returns (uint256 r) {
+        try this.f() returns (uint256 x) {
+            r = x;
+        } catch Error(string memory) {} //~ WARN: empty `catch` clause swallows the error
+        catch (bytes memory) { {} } //~ WARN: empty `catch` clause swallows the error
+    }
+
+    function nested(bool b) public {
+        if (b) {
+            try this.f() {} catch {} //~ WARN: empty `catch` clause swallows the error
+        }
+    }
+
+    function log() public {
+        try this.f() {} catch (bytes memory data) {
+            emit Failed(data);
+        }
+    }
+
+    function rethrow() public view {
+        try this.f() {} catch {
+            revert CallFailed();
+        }
+    }
+}
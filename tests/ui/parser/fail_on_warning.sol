@@ -0,0 +1,7 @@
+//@compile-flags: --fail-on-warning 6162
+
+contract C {
+    function fallback() public {} //~ WARN: function named `fallback`
+
+    function(uint256 x) internal f; //~ ERROR: named function type parameters are deprecated
+}
@@ -0,0 +1,22 @@
+contract C {
+    bytes1 a;
+    bytes16 b;
+    bytes32 c;
+    bytes0 d; //~ ERROR: invalid fixed-size byte array type `bytes0`
+    bytes33 e; //~ ERROR: invalid fixed-size byte array type `bytes33`
+
+    // Not types, just identifiers.
+    uint256 bytes33;
+    uint256 bytes01;
+
+    function f(bytes64 x) public view returns (bytes4) { //~ ERROR: invalid fixed-size byte array type `bytes64`
+        bytes33 y = x; //~ ERROR: invalid fixed-size byte array type `bytes33`
+        bytes2 z = bytes2(a);
+        return bytes4(z);
+    }
+
+    function g() public pure returns (bytes1) {
+        byte b = 0x01; //~ WARN: `byte` is a deprecated alias for `bytes1`
+        return bytes1(b);
+    }
+}
@@ -0,0 +1,26 @@
+contract B {
+    function f() public virtual {}
+    function g() external view virtual returns (uint) {}
+}
+
+contract C is B {
+    modifier m() {
+        _;
+    }
+
+    function a() public view returns (uint) {}
+    function b() view public returns (uint) {}
+    function c() m pure internal returns (uint) {}
+    function f() override m public {}
+    function g() virtual override view external m returns (uint) {}
+
+    function d() public public {} //~ ERROR: visibility already specified
+    function e() public external {} //~ ERROR: `external` conflicts with previously specified visibility `public`
+    function h() public view pure {} //~ ERROR: `pure` conflicts with previously specified state mutability `view`
+    function i() view public view {} //~ ERROR: state mutability already specified
+    function j() virtual public virtual {} //~ ERROR: virtual already specified
+    function k() returns (uint) public {} //~ ERROR: function attributes must be specified before `returns`
+    function l() public returns (uint) view m {}
+    //~^ ERROR: function attributes must be specified before `returns`
+    //~| ERROR: function attributes must be specified before `returns`
+}
@@ -0,0 +1 @@
+import * as a "a.sol"; //~ ERROR: expected `from`, found `<string>`
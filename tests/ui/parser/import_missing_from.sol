@@ -0,0 +1 @@
+import {A} "a.sol"; //~ ERROR: expected `from`, found `<string>`
@@ -0,0 +1,12 @@
+interface I {
+    function ok() external;
+    function okView() external view returns (uint256);
+    fallback() external;
+    receive() external payable;
+
+    function withBody() external {} //~ ERROR: functions in interfaces cannot have an implementation
+    function notExternal() public; //~ ERROR: functions in interfaces must be declared `external`, found `public`
+    function explicitVirtual() external virtual; //~ WARN: interface functions are implicitly `virtual`
+
+    constructor() {} //~ ERROR: interfaces cannot have constructors
+}
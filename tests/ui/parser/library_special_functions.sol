@@ -0,0 +1,19 @@
+library L {
+    constructor() {} //~ ERROR: libraries cannot have constructors
+    fallback() external {} //~ ERROR: libraries cannot have fallback functions
+    receive() external payable {} //~ ERROR: libraries cannot have receive functions
+}
+
+library Ok {
+    function f() internal pure returns (uint) {
+        return 1;
+    }
+
+    function g() public pure {}
+}
+
+contract C {
+    constructor() {}
+    fallback() external {}
+    receive() external payable {}
+}
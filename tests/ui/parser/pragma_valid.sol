@@ -1,21 +1,24 @@
 pragma abicoder v1;
-pragma abicoder v2;
-pragma abicoder "v1";
-pragma abicoder "v2";
+pragma abicoder v2; //~ ERROR: conflicting `pragma abicoder` directives
+pragma abicoder "v1"; //~ WARN: duplicate `pragma abicoder` directive
+pragma abicoder "v2"; //~ ERROR: conflicting `pragma abicoder` directives
 
 // These aren't accepted by solc.
-pragma "abicoder" v1;
-pragma "abicoder" v2;
-pragma "abicoder" "v1";
-pragma "abicoder" "v2";
+pragma "abicoder" v1; //~ WARN: duplicate `pragma abicoder` directive
+pragma "abicoder" v2; //~ ERROR: conflicting `pragma abicoder` directives
+pragma "abicoder" "v1"; //~ WARN: duplicate `pragma abicoder` directive
+pragma "abicoder" "v2"; //~ ERROR: conflicting `pragma abicoder` directives
 
 pragma experimental ABIEncoderV2;
-pragma experimental "ABIEncoderV2";
-pragma experimental SMTChecker;
-pragma experimental "SMTChecker";
+pragma experimental "ABIEncoderV2"; //~ WARN: duplicate `pragma experimental` directive
+pragma experimental SMTChecker; //~ WARN: `pragma experimental SMTChecker` is deprecated
+pragma experimental "SMTChecker"; //~ WARN: duplicate `pragma experimental` directive
+//~| WARN: `pragma experimental SMTChecker` is deprecated
 
 // These aren't accepted by solc.
-pragma "experimental" ABIEncoderV2;
-pragma "experimental" "ABIEncoderV2";
-pragma "experimental" SMTChecker;
-pragma "experimental" "SMTChecker";
+pragma "experimental" ABIEncoderV2; //~ WARN: duplicate `pragma experimental` directive
+pragma "experimental" "ABIEncoderV2"; //~ WARN: duplicate `pragma experimental` directive
+pragma "experimental" SMTChecker; //~ WARN: duplicate `pragma experimental` directive
+//~| WARN: `pragma experimental SMTChecker` is deprecated
+pragma "experimental" "SMTChecker"; //~ WARN: duplicate `pragma experimental` directive
+//~| WARN: `pragma experimental SMTChecker` is deprecated
@@ -1,53 +1,53 @@
 pragma solidity *.*.*;
 pragma solidity *.*.0 ;
-pragma solidity *.*.0;
+pragma solidity *.*.0; //~ WARN: duplicate `pragma solidity` directive
 pragma solidity *.*;
 pragma solidity *.0 .*;
 pragma solidity *.0 .0 ;
-pragma solidity *.0 .0;
+pragma solidity *.0 .0; //~ WARN: duplicate `pragma solidity` directive
 pragma solidity *.0 ;
 pragma solidity *.0.*;
-pragma solidity *.0.0 ;
-pragma solidity *.0.0;
-pragma solidity *.0;
+pragma solidity *.0.0 ; //~ WARN: duplicate `pragma solidity` directive
+pragma solidity *.0.0; //~ WARN: duplicate `pragma solidity` directive
+pragma solidity *.0; //~ WARN: duplicate `pragma solidity` directive
 pragma solidity *;
 pragma solidity 0 .*.*;
 pragma solidity 0 .*.0 ;
-pragma solidity 0 .*.0;
+pragma solidity 0 .*.0; //~ WARN: duplicate `pragma solidity` directive
 pragma solidity 0 .*;
 pragma solidity 0 .0 .*;
 pragma solidity 0 .0 .0 ;
-pragma solidity 0 .0 .0;
+pragma solidity 0 .0 .0; //~ WARN: duplicate `pragma solidity` directive
 pragma solidity 0 .0 ;
 pragma solidity 0 .0.*;
-pragma solidity 0 .0.0 ;
-pragma solidity 0 .0.0;
-pragma solidity 0 .0;
+pragma solidity 0 .0.0 ; //~ WARN: duplicate `pragma solidity` directive
+pragma solidity 0 .0.0; //~ WARN: duplicate `pragma solidity` directive
+pragma solidity 0 .0; //~ WARN: duplicate `pragma solidity` directive
 pragma solidity 0 ;
 pragma solidity 0.*.*;
 pragma solidity 0.*.0 ;
-pragma solidity 0.*.0;
+pragma solidity 0.*.0; //~ WARN: duplicate `pragma solidity` directive
 pragma solidity 0.*;
-pragma solidity 0.0 .*;
-pragma solidity 0.0 .0 ;
-pragma solidity 0.0 .0;
-pragma solidity 0.0 ;
-pragma solidity 0.0.*;
-pragma solidity 0.0.0 ;
-pragma solidity 0.0.0;
-pragma solidity 0.0;
-pragma solidity 0;
+pragma solidity 0.0 .*; //~ WARN: duplicate `pragma solidity` directive
+pragma solidity 0.0 .0 ; //~ WARN: duplicate `pragma solidity` directive
+pragma solidity 0.0 .0; //~ WARN: duplicate `pragma solidity` directive
+pragma solidity 0.0 ; //~ WARN: duplicate `pragma solidity` directive
+pragma solidity 0.0.*; //~ WARN: duplicate `pragma solidity` directive
+pragma solidity 0.0.0 ; //~ WARN: duplicate `pragma solidity` directive
+pragma solidity 0.0.0; //~ WARN: duplicate `pragma solidity` directive
+pragma solidity 0.0; //~ WARN: duplicate `pragma solidity` directive
+pragma solidity 0; //~ WARN: duplicate `pragma solidity` directive
 
-pragma solidity ^0.5.16 =0.8.22 || >=0.8.21 <=2 ~1 0.6.2;
-pragma solidity 0.4 - 1 || 0.3 - 0.5.16;
+pragma solidity ^0.5.16 =0.8.22 || >=0.8.21 <=2 ~1 0.6.2; //~ ERROR: conflicting `pragma solidity` directives
+pragma solidity 0.4 - 1 || 0.3 - 0.5.16; //~ ERROR: conflicting `pragma solidity` directives
 
-pragma solidity ^4294967295;
-pragma solidity ^4294967296;
+pragma solidity ^4294967295; //~ ERROR: conflicting `pragma solidity` directives
+pragma solidity ^4294967296; //~ WARN: duplicate `pragma solidity` directive
 //~^ ERROR: too large
-pragma solidity ^0.4294967296;
+pragma solidity ^0.4294967296; //~ ERROR: conflicting `pragma solidity` directives
 //~^ ERROR: too large
 
-pragma solidity 88_;
+pragma solidity 88_; //~ ERROR: conflicting `pragma solidity` directives
 //~^ ERROR: invalid digit
 
 pragma solidity 0 - 1 0 - 2;
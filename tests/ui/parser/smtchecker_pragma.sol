@@ -0,0 +1,3 @@
+pragma experimental SMTChecker; //~ WARN: `pragma experimental SMTChecker` is deprecated
+
+contract C {}
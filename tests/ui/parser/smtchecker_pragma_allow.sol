@@ -0,0 +1,5 @@
+//@compile-flags: -Zsmtchecker-pragma=allow
+
+pragma experimental SMTChecker;
+
+contract C {}
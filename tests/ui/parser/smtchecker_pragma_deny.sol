@@ -0,0 +1,5 @@
+//@compile-flags: -Zsmtchecker-pragma=deny
+
+pragma experimental SMTChecker; //~ ERROR: `pragma experimental SMTChecker` is deprecated
+
+contract C {}
@@ -0,0 +1,49 @@
+// Soft keywords are ordinary identifiers, except in the positions where they are keywords.
+
+struct S {
+    uint256 error;
+    uint256 from;
+    uint256 global;
+    uint256 revert;
+    uint256 transient;
+}
+
+library L {
+    function get(S memory s) internal pure returns (uint256) {
+        return s.error + s.from + s.global + s.revert + s.transient;
+    }
+}
+
+using L for S global;
+
+error E(uint256 error, address from);
+
+contract C {
+    uint256 error;
+    uint256 global;
+    uint256 transient;
+    uint256 transient transient2;
+
+    mapping(address from => mapping(uint256 global => bool transient)) named;
+
+    event Transfer(address indexed from, uint256 error);
+
+    function f(address from, uint256 global) public {
+        error = 1;
+        transient = global;
+        named[from][global] = true;
+        emit Transfer(from, error);
+        if (from == address(0)) revert E(global, from);
+        if (global == 0) revert("global");
+    }
+
+    function g(uint256 error, uint256 transient) public pure returns (uint256 from) {
+        from = error + transient;
+    }
+
+    function h() public pure returns (uint256) {
+        uint256 revert = 1;
+        revert += 1;
+        return revert;
+    }
+}
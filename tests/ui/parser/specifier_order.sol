@@ -0,0 +1,23 @@
+//@compile-flags: -Zwarn-specifier-order
+
+abstract contract A {
+    modifier m() {
+        _;
+    }
+
+    function canonical() public payable virtual m {}
+
+    function f() payable public {} //~ WARN: function specifiers are not in the canonical order
+
+    function g() m view /* comment */ internal virtual returns (uint256) {} //~ WARN: function specifiers are not in the canonical order
+
+    function h() external virtual;
+
+    constructor() m payable {} //~ WARN: function specifiers are not in the canonical order
+
+    function(uint256) payable external ty;
+}
+
+contract B is A {
+    function h() override(A) external {} //~ WARN: function specifiers are not in the canonical order
+}
@@ -0,0 +1,11 @@
+//@compile-flags: -Zwarn-specifier-order -Zspecifier-order=modifiers,visibility
+
+contract C {
+    modifier m() {
+        _;
+    }
+
+    function canonical() m public view {}
+
+    function f() public m {} //~ WARN: function specifiers are not in the canonical order
+}
@@ -0,0 +1,12 @@
+// The object builtins are not available in inline assembly, so functions can use their names.
+contract C {
+    function f() public pure returns (uint256 r) {
+        assembly {
+            function datasize(a, b) -> s {
+                s := add(a, b)
+            }
+            function linkersymbol() -> s {}
+            r := add(datasize(1, 2), linkersymbol())
+        }
+    }
+}
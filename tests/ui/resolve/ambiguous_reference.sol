@@ -0,0 +1,14 @@
+contract A {
+    function f() internal {}
+}
+
+contract B is A {
+    function f(uint) internal {}
+
+    f x; //~ ERROR: symbol `f` resolved to multiple declarations
+
+    function g() public {
+        uint f = 1;
+        f;
+    }
+}
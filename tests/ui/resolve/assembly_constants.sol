@@ -0,0 +1,47 @@
+uint constant FREE = 0x40;
+
+contract C {
+    uint constant A = 1;
+    uint constant B = A;
+    uint constant C_ = (A + 2) * 3;
+    int constant NEG = -1;
+    bool constant FLAG = true;
+    address constant ADDR = 0x0000000000000000000000000000000000000001;
+    bytes32 constant HASH = keccak256("a");
+    string constant STR = "abc";
+    bytes constant BYTES = hex"01";
+    uint constant D = uint(HASH);
+    uint constant E = D;
+    uint immutable I = 1;
+    uint immutable J;
+
+    constructor() {
+        J = 2;
+    }
+
+    function f() public view {
+        assembly {
+            let a := add(A, B)
+            let b := mul(C_, NEG)
+            let c := FLAG
+            let d := ADDR
+            let e := mload(FREE)
+
+            let h := HASH //~ ERROR: only direct number constants and references to such constants are supported by inline assembly
+            let s := STR //~ ERROR: only direct number constants and references to such constants are supported by inline assembly
+            let y := BYTES //~ ERROR: only direct number constants and references to such constants are supported by inline assembly
+            let z := E //~ ERROR: only direct number constants and references to such constants are supported by inline assembly
+            A := 2 //~ ERROR: constant variables cannot be assigned to
+
+            let i := I //~ ERROR: assembly access to immutable variables is not supported
+            let j := add(J, 1) //~ ERROR: assembly access to immutable variables is not supported
+        }
+    }
+
+    function g() public pure {
+        assembly {
+            let I := 1
+            let x := I
+        }
+    }
+}
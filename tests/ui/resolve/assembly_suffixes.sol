@@ -0,0 +1,32 @@
+contract C {
+    uint x;
+    uint[] arr;
+    uint constant K = 1;
+
+    function f(uint[] calldata data, bytes memory mem) public {
+        uint local;
+        uint[] storage ptr = arr;
+        assembly {
+            let s := x.slot
+            let o := x.offset
+            sstore(arr.slot, 0)
+            let p := ptr.slot
+            let d := data.offset
+            let l := data.length
+
+            let a := local.slot //~ ERROR: the `.slot` suffix can only be used on storage variables
+            let b := mem.length //~ ERROR: the `.length` suffix can only be used on calldata variables
+            let c := local.offset //~ ERROR: the `.offset` suffix can only be used on storage or calldata variables
+            let e := data.slot //~ ERROR: the `.slot` suffix can only be used on storage variables
+            let g := K.slot //~ ERROR: the `.slot` suffix can only be used on storage variables
+            local.slot := 1 //~ ERROR: the `.slot` suffix can only be used on storage variables
+        }
+    }
+
+    function g() public {
+        assembly {
+            let x := 0
+            let y := x.slot
+        }
+    }
+}
@@ -0,0 +1,3 @@
+import "./import_depth_2.sol";
+
+contract D1 is D2 {}
@@ -0,0 +1,3 @@
+import "./import_depth_3.sol";
+
+contract D2 {}
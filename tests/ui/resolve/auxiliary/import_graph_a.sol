@@ -0,0 +1,3 @@
+import * as b from "./import_graph_b.sol";
+
+contract A {}
@@ -0,0 +1,4 @@
+// Imports the file that imports it.
+import "../import_graph.sol" as main;
+
+contract B {}
@@ -0,0 +1,6 @@
+//@compile-flags: --max-import-depth 2
+//@error-in-other-file: maximum import depth of 2 exceeded
+
+import "./auxiliary/import_depth_1.sol";
+
+contract C is D1 {}
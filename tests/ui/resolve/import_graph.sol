@@ -0,0 +1,7 @@
+//@ignore-host: windows
+//@compile-flags: -Zimport-graph=dot
+
+import "./auxiliary/import_graph_a.sol";
+import {B} from "./auxiliary/import_graph_b.sol";
+
+contract Main is A, B {}
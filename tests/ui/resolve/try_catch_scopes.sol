@@ -0,0 +1,30 @@
+contract C {
+    function f() external pure returns (uint256, bool) {
+        return (1, true);
+    }
+
+    function ok() public view returns (uint256 r) {
+        try this.f() returns (uint256 x, bool y) {
+            r = y ? x : 0;
+        } catch Error(string memory reason) {
+            r = bytes(reason).length;
+        } catch (bytes memory data) {
+            r = data.length;
+        }
+        // The names can be reused after the `try` statement.
+        uint256 x = r;
+        r = x;
+    }
+
+    function bad() public view returns (uint256 r) {
+        try this.f() returns (uint256 x, bool) {
+            r = x;
+        } catch Error(string memory reason) {
+            r = x; //~ ERROR: unresolved symbol `x`
+        } catch (bytes memory data) {
+            r = bytes(reason).length; //~ ERROR: unresolved symbol `reason`
+        }
+        r = data.length; //~ ERROR: unresolved symbol `data`
+        r = x; //~ ERROR: unresolved symbol `x`
+    }
+}
@@ -0,0 +1,13 @@
+import "./missing.sol"; //~ ERROR: file ./missing.sol not found
+import {A, B as C} from "./missing.sol"; //~ ERROR: file ./missing.sol not found
+import * as M from "./missing.sol"; //~ ERROR: file ./missing.sol not found
+
+// Names imported from unresolved imports do not emit further errors.
+contract D is A {
+    C c;
+
+    function f() public {
+        M.g();
+        undefined(); //~ ERROR: unresolved symbol `undefined`
+    }
+}
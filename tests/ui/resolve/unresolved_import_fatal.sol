@@ -0,0 +1,10 @@
+//@compile-flags: --unresolved-imports fatal
+
+import {A} from "./missing.sol"; //~ ERROR: file ./missing.sol not found
+
+// Not reported, since resolution stops at the first unresolved import.
+contract D is A {
+    function f() public {
+        undefined();
+    }
+}
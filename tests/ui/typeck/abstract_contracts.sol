@@ -0,0 +1,36 @@
+abstract contract A {
+    function f() public virtual;
+    function g() public virtual returns (uint256);
+}
+
+contract Missing is A { //~ ERROR: contract `Missing` should be marked as `abstract`
+    function f() public override {}
+}
+
+contract Unimplemented { //~ ERROR: contract `Unimplemented` should be marked as `abstract`
+    function h() public virtual;
+}
+
+contract Complete is A {
+    function f() public override {}
+    function g() public pure override returns (uint256) {
+        return 1;
+    }
+}
+
+interface I {
+    function i() external;
+}
+
+contract Impl is I {
+    function i() external {}
+}
+
+contract Factory {
+    function create() public {
+        new Complete();
+        new Impl();
+        new A(); //~ ERROR: cannot instantiate an abstract contract `A`
+        new I(); //~ ERROR: cannot instantiate an interface `I`
+    }
+}
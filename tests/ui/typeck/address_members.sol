@@ -0,0 +1,42 @@
+contract C {
+    address a;
+    address payable p;
+
+    function members(address x, address payable y) public view {
+        x.balance;
+        x.code;
+        x.codehash;
+        y.balance;
+        y.code;
+        y.codehash;
+
+        address(this).balance;
+        address(this).code;
+        address(this).codehash;
+        address(uint160(uint256(1))).balance;
+        a.balance;
+        p.balance;
+    }
+
+    function payableMembers(address x, address payable y) public {
+        y.transfer(1);
+        y.send(1);
+        payable(x).transfer(1);
+        payable(x).send(1);
+        payable(address(this)).transfer(1);
+        payable(msg.sender).transfer(1);
+        block.coinbase.transfer(1);
+        p.transfer(1);
+        p.send(1);
+    }
+
+    function nonPayableMembers(address x) public {
+        x.transfer(1); //~ ERROR: member `transfer` not found on type `address`
+        x.send(1); //~ ERROR: member `send` not found on type `address`
+        a.transfer(1); //~ ERROR: member `transfer` not found on type `address`
+        address(this).transfer(1); //~ ERROR: member `transfer` not found on type `address`
+        address(uint160(uint256(1))).send(1); //~ ERROR: member `send` not found on type `address`
+        msg.sender.transfer(1); //~ ERROR: member `transfer` not found on type `address`
+        (x).send(1); //~ ERROR: member `send` not found on type `address`
+    }
+}
@@ -0,0 +1,17 @@
+uint constant N = 2;
+
+contract C {
+    uint x;
+
+    function f(uint n) public {
+        uint[] memory a = new uint[](n);
+        uint[N][] memory b = new uint[N][](n);
+        uint[2][3] memory c;
+        c[2][1] = 1;
+
+        new uint[n][](1); //~ ERROR: evaluation of constant value failed
+        new uint[x][](1); //~ ERROR: evaluation of constant value failed
+        new uint[0][](1); //~ ERROR: array length must be greater than zero
+        new uint[N - N][](1); //~ ERROR: array length must be greater than zero
+    }
+}
@@ -0,0 +1,19 @@
+//@compile-flags: -Zwarn-assert-false
+
+contract C {
+    bool constant NEVER = false;
+
+    function f(uint x) public pure {
+        assert(false); //~ WARN: `assert(false)` used to revert
+        require(false, "unreachable"); //~ WARN: `require(false)` used to revert
+        require(false); //~ WARN: `require(false)` used to revert
+        assert(NEVER); //~ WARN: `assert(false)` used to revert
+        require(1 > 2, "one is not greater than two"); //~ WARN: `require(false)` used to revert
+        assert(!true); //~ WARN: `assert(false)` used to revert
+
+        assert(true);
+        require(!false, "always true");
+        require(x > 0, "x must be positive");
+        assert(x == 0);
+    }
+}
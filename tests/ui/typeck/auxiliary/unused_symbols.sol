@@ -0,0 +1,3 @@
+contract Used {}
+
+contract Unused {}
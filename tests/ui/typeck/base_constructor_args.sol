@@ -0,0 +1,23 @@
+contract A {
+    constructor(uint x, string memory s) payable {}
+}
+
+contract B {}
+
+contract Ok1 is A(1, "a") {}
+
+contract Ok2 is A {
+    constructor(uint8 x) A(x, "b") {}
+}
+
+abstract contract Ok3 is A {}
+
+contract Bad1 is A(1, 2) {} //~ ERROR: argument of type `int_const` is not implicitly convertible to expected type `string memory`
+
+contract Bad2 is A {
+    constructor(int x) A(x, "c") {} //~ ERROR: argument of type `int256` is not implicitly convertible to expected type `uint256`
+}
+
+contract Bad3 is A(1) {} //~ ERROR: expected 2 arguments for the constructor of `A`, found 1
+
+contract Bad4 is B(1) {} //~ ERROR: expected 0 arguments for the constructor of `B`, found 1
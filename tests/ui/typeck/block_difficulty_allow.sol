@@ -0,0 +1,7 @@
+//@compile-flags: --evm-version paris -Zlint=block-difficulty=allow
+
+contract C {
+    function f() public view returns (uint256) {
+        return block.difficulty;
+    }
+}
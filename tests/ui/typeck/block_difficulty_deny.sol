@@ -0,0 +1,7 @@
+//@compile-flags: --evm-version paris -Zlint=block-difficulty=deny
+
+contract C {
+    function f() public view returns (uint256) {
+        return block.difficulty; //~ ERROR: `block.difficulty` has been deprecated in favor of `block.prevrandao`
+    }
+}
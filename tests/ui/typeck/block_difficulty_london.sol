@@ -0,0 +1,7 @@
+//@compile-flags: --evm-version london
+
+contract C {
+    function f() public view returns (uint256) {
+        return block.difficulty;
+    }
+}
@@ -0,0 +1,10 @@
+//@compile-flags: --evm-version paris
+
+contract C {
+    function f() public view returns (uint256, uint256) {
+        return (
+            block.difficulty, //~ WARN: `block.difficulty` has been deprecated in favor of `block.prevrandao`
+            block.prevrandao
+        );
+    }
+}
@@ -0,0 +1,19 @@
+//@compile-flags: -Zwarn-bool-literal-comparisons
+
+contract C {
+    bool flag;
+
+    function f(bool x, bool y, uint256 n) public view returns (bool) {
+        if (x == true) {} //~ WARN: comparison of a boolean expression to `true`
+        if (x == false) {} //~ WARN: comparison of a boolean expression to `false`
+        if (x != true) {} //~ WARN: comparison of a boolean expression to `true`
+        if (x != false) {} //~ WARN: comparison of a boolean expression to `false`
+        if (true == flag) {} //~ WARN: comparison of a boolean expression to `true`
+        if ((x && y) == false) {} //~ WARN: comparison of a boolean expression to `false`
+        if (n > 1 == false) {} //~ WARN: comparison of a boolean expression to `false`
+
+        if (x == y) {}
+        if (x) {}
+        return !y;
+    }
+}
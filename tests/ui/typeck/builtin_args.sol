@@ -0,0 +1,13 @@
+contract C {
+    function f(address a, bytes calldata data) public {
+        gasleft(1); //~ ERROR: expected 0 arguments for `gasleft`, found 1
+        blockhash(); //~ ERROR: expected 1 argument for `blockhash`, found 0
+        addmod(1, 2); //~ ERROR: expected 3 arguments for `addmod`, found 2
+        mulmod(1, 2, a); //~ ERROR: argument of type `address` is not implicitly convertible to expected type `uint256`
+        blockhash(0);
+        keccak256(data);
+        sha256("abc");
+        selfdestruct(payable(a));
+        selfdestruct(a); //~ ERROR: argument of type `address` is not implicitly convertible to expected type `address payable`
+    }
+}
@@ -0,0 +1,18 @@
+contract C {
+    string s;
+    bytes b;
+    bytes32 h;
+
+    function f(string memory a, string calldata c, bytes memory d, bytes4 e, uint x) public view {
+        string.concat();
+        string.concat(a, c);
+        string.concat(a, s, "lit");
+        string.concat(a, x); //~ ERROR: invalid argument to `string.concat`: expected `string`, found `uint256`
+        string.concat(a, d); //~ ERROR: invalid argument to `string.concat`: expected `string`, found `bytes memory`
+
+        bytes.concat();
+        bytes.concat(d, b, e, h, "lit");
+        bytes.concat(d, x); //~ ERROR: invalid argument to `bytes.concat`: expected `bytes` or `bytesN`, found `uint256`
+        bytes.concat(d, a); //~ ERROR: invalid argument to `bytes.concat`: expected `bytes` or `bytesN`, found `string memory`
+    }
+}
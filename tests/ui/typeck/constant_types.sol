@@ -0,0 +1,23 @@
+struct S {
+    uint256 x;
+}
+
+string constant NAME = "name";
+bytes constant DATA = hex"01";
+uint256[2] constant PAIR = [uint256(1), 2]; //~ ERROR: `constant` variables of type `uint256[2]` are not supported
+
+contract C {
+    enum E {
+        A
+    }
+
+    uint256 constant X = 1;
+    address constant ADDR = address(0);
+    bytes32 constant HASH = keccak256("a");
+    E constant VARIANT = E.A;
+    string constant GREETING = "hello";
+    bytes constant EMPTY = "";
+
+    uint256[] constant LIST = [1]; //~ ERROR: `constant` variables of type `uint256[]` are not supported
+    S constant POINT = S(1); //~ ERROR: `constant` variables of type `struct S` are not supported
+}
@@ -0,0 +1,19 @@
+//@compile-flags: --contract B
+
+contract A {
+    function f() public {
+        delete 1;
+    }
+}
+
+contract Base {
+    function g() public {
+        delete 1; //~ ERROR: `delete` can only be applied to variables, members and index accesses
+    }
+}
+
+contract B is Base {
+    function h() public {
+        delete 1; //~ ERROR: `delete` can only be applied to variables, members and index accesses
+    }
+}
@@ -0,0 +1,6 @@
+//@compile-flags: --contract A,Missing
+//@error-in-other-file: `--contract Missing` did not match any contract
+
+contract A {}
+
+contract B {}
@@ -0,0 +1,27 @@
+//@compile-flags: -Zwarn-cyclomatic-complexity=3
+
+contract C {
+    function simple(uint256 a) public pure returns (uint256) {
+        if (a > 1) {
+            return a;
+        }
+        return a > 0 ? 1 : 0;
+    }
+
+    function complex(uint256 a, bool b) public pure returns (uint256 r) { //~ WARN: function `complex` is too complex
+        for (uint256 i = 0; i < a; i++) {
+            if (b && i > 2) {
+                r += i;
+            }
+        }
+        while (r > 100) {
+            r /= 2;
+        }
+    }
+
+    function infinite() public pure {
+        for (;;) {
+            break;
+        }
+    }
+}
@@ -0,0 +1,33 @@
+//@compile-flags: -Zwarn-delegatecall-storage
+
+contract Proxy { //~ WARN: contract `Proxy` uses `delegatecall` and declares state variables
+    address implementation;
+
+    fallback() external payable {
+        (bool ok,) = implementation.delegatecall(msg.data);
+        require(ok);
+    }
+}
+
+contract ImmutableProxy {
+    address immutable implementation;
+    uint256 constant VERSION = 1;
+
+    constructor(address impl) {
+        implementation = impl;
+    }
+
+    fallback() external payable {
+        (bool ok,) = implementation.delegatecall(msg.data);
+        require(ok);
+    }
+}
+
+contract NoDelegatecall {
+    address implementation;
+
+    function forward(bytes calldata data) external {
+        (bool ok,) = implementation.call(data);
+        require(ok);
+    }
+}
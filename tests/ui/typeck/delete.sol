@@ -0,0 +1,35 @@
+contract C {
+    struct S {
+        uint x;
+        mapping(uint => uint) m;
+    }
+
+    uint[] arr;
+    uint[3] fixedArr;
+    mapping(uint => uint) myMapping;
+    mapping(uint => mapping(uint => bool)) nested;
+    S structVar;
+    uint x;
+    uint constant K = 1;
+
+    function f() public {
+        delete arr;
+        delete fixedArr;
+        delete arr[0];
+        delete structVar;
+        delete structVar.x;
+        delete myMapping[0];
+        delete nested[0][1];
+        delete x;
+        delete (x);
+
+        delete myMapping; //~ ERROR: `delete` cannot be applied to type `mapping(uint256 => uint256)`
+        delete nested[0]; //~ ERROR: `delete` cannot be applied to type `mapping(uint256 => bool)`
+        delete structVar.m; //~ ERROR: `delete` cannot be applied to type `mapping(uint256 => uint256)`
+
+        delete K; //~ ERROR: `delete` can only be applied to variables, members and index accesses
+        delete 1; //~ ERROR: `delete` can only be applied to variables, members and index accesses
+        delete f; //~ ERROR: `delete` can only be applied to variables, members and index accesses
+        delete (x + 1); //~ ERROR: `delete` can only be applied to variables, members and index accesses
+    }
+}
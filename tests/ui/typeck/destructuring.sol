@@ -0,0 +1,50 @@
+contract C {
+    uint256 x;
+    string s;
+
+    function two() internal pure returns (uint256, bool) {
+        return (1, true);
+    }
+
+    function three() internal pure returns (uint256, bool, string memory) {
+        return (1, true, "");
+    }
+
+    function matching() public {
+        (uint256 a, bool b) = two();
+        (, bool c) = two();
+        (uint256 d, , string memory e) = three();
+        (x, , s) = three();
+        (a, b) = (2, false);
+        (a, ) = two();
+    }
+
+    function arity() public {
+        (uint256 a, bool b, uint256 c) = two(); //~ ERROR: expected a tuple with 3 components, found one with 2 components
+        (uint256 d) = three(); //~ ERROR: expected a tuple with 1 component, found one with 3 components
+        (a, b) = three(); //~ ERROR: expected a tuple with 2 components, found one with 3 components
+        (a, b) = (1, true, 2); //~ ERROR: expected a tuple with 2 components, found one with 3 components
+    }
+
+    function types() public {
+        (bool a, bool b) = two(); //~ ERROR: component of type `uint256` is not implicitly convertible to expected type `bool`
+        (, uint256 c) = two(); //~ ERROR: component of type `bool` is not implicitly convertible to expected type `uint256`
+        (a, c) = (true, "a"); //~ ERROR: component of type `literal_string` is not implicitly convertible to expected type `uint256`
+        (x, s, a) = three(); //~ ERROR: component of type `bool` is not implicitly convertible to expected type `string`
+        //~^ ERROR: component of type `string memory` is not implicitly convertible to expected type `bool`
+    }
+
+    function nested() public {
+        uint256 a;
+        bool b;
+        (a, b) = (1, true);
+        ((a, b), x) = ((1, true), 2);
+        (a, (b, )) = (3, (false, 4));
+        ((a, b), x) = ((1, true, 2), 3); //~ ERROR: expected a tuple with 2 components, found one with 3 components
+        ((a, b), x) = (1, 2); //~ ERROR: expected a tuple with 2 components, found one with 1 component
+        ((a, b), b) = two(); //~ ERROR: expected a tuple with 2 components, found one with 1 component
+        (a, b) = ((1, true), false); //~ ERROR: expected a single value, found a tuple with 2 components
+        (a, b) = (1, ); //~ ERROR: components of the right-hand side of a destructuring cannot be empty
+        ((a, b), x) = ((true, false), 1); //~ ERROR: component of type `bool` is not implicitly convertible to expected type `uint256`
+    }
+}
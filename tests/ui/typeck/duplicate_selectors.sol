@@ -6,3 +6,9 @@ contract D is C {
     //~^ ERROR: function signature hash collision
     function BlazingIt4490597615() public {}
 }
+
+contract E {
+    //~^ ERROR: function signature hash collision
+    function mintEfficientN2M_001Z5BWH() public {}
+    function BlazingIt4490597615() public {}
+}
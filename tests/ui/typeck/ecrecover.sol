@@ -0,0 +1,10 @@
+contract C {
+    function recover(bytes32 hash, uint8 v, bytes32 r, bytes32 s) public pure returns (address) {
+        address signer = ecrecover(hash, v, r, s);
+        ecrecover(hash, v, r); //~ ERROR: expected 4 arguments for `ecrecover`, found 3
+        ecrecover(hash, r, r, s); //~ ERROR: argument of type `bytes32` is not implicitly convertible to expected type `uint8`
+        ecrecover({hash: hash, v: v, r: r, s: s}); //~ ERROR: named arguments are not supported for `ecrecover`
+        blockhash(); //~ ERROR: expected 1 argument for `blockhash`, found 0
+        return signer;
+    }
+}
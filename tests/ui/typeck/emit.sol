@@ -0,0 +1,15 @@
+contract C {
+    event Transfer(address indexed from, address indexed to, uint256 value);
+    event Message(string message);
+
+    function f(address to, string memory message) public {
+        emit Transfer(msg.sender, to, 1);
+        emit Message(message);
+        emit Message("hello");
+
+        emit Transfer(msg.sender, to); //~ ERROR: expected 3 arguments, found 2
+        emit Transfer(msg.sender, message, 1); //~ ERROR: argument of type `string memory` is not implicitly convertible to expected type `address`
+        emit Message(to); //~ ERROR: argument of type `address` is not implicitly convertible to expected type `string`
+        emit f(to, message); //~ ERROR: expected event, found function
+    }
+}
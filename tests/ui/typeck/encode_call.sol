@@ -0,0 +1,22 @@
+interface I {
+    function f(uint256 x, address a) external;
+    function g(string calldata s) external;
+}
+
+contract C {
+    function ok(address addr, I i) public pure {
+        bytes memory a = abi.encodeCall(I.f, (1, addr));
+        bytes memory b = abi.encodeCall(i.f, (2, addr));
+        bytes memory c = abi.encodeCall(I.g, ("hello"));
+        bytes memory d = abi.encodeCall(I.g, "hello");
+    }
+
+    function mismatch(address addr) public pure {
+        bytes memory a = abi.encodeCall(I.f, (addr, 1)); //~ ERROR: argument of type `address` is not implicitly convertible to expected type `uint256`
+        //~^ ERROR: argument of type `int_const` is not implicitly convertible to expected type `address`
+        bytes memory b = abi.encodeCall(I.g, (1)); //~ ERROR: argument of type `int_const` is not implicitly convertible to expected type `string`
+        bytes memory c = abi.encodeCall(I.f, (1)); //~ ERROR: expected 2 arguments for function `f`, found 1
+        bytes memory d = abi.encodeCall(I.f, (1, addr, 2)); //~ ERROR: expected 2 arguments for function `f`, found 3
+        bytes memory e = abi.encodeCall(I.f); //~ ERROR: `abi.encodeCall` expects 2 arguments, a function pointer and a tuple, found 1
+    }
+}
@@ -0,0 +1,37 @@
+enum E {
+    A,
+    B,
+    C
+}
+
+enum F {
+    X
+}
+
+contract C {
+    E e = E.C;
+    uint constant THREE = 3;
+
+    function f(uint x, int y, address a) public view returns (E) {
+        E.A;
+        E.D; //~ ERROR: variant `D` not found in `enum E`
+
+        uint8(E.B);
+        uint(e);
+        int8(E.A);
+        E(x);
+        E(y);
+        E(2);
+        E(E.A);
+        E(3); //~ ERROR: integer constant `3` is out of range for `enum E`
+        E(THREE); //~ ERROR: integer constant `3` is out of range for `enum E`
+        F(1); //~ ERROR: integer constant `1` is out of range for `enum F`
+
+        E(F.X); //~ ERROR: explicit type conversion not allowed from `enum F` to `enum E`
+        E(a); //~ ERROR: explicit type conversion not allowed from `address` to `enum E`
+        address(E.A); //~ ERROR: explicit type conversion not allowed from `enum E` to `address`
+        bool(e); //~ ERROR: explicit type conversion not allowed from `enum E` to `bool`
+
+        return E.B;
+    }
+}
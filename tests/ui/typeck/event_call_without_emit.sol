@@ -0,0 +1,12 @@
+contract C {
+    event Transfer(address indexed from, address indexed to, uint256 value);
+    event Log(uint256 value);
+    event Log(string value);
+
+    function f(address to) public {
+        emit Transfer(msg.sender, to, 1);
+        Transfer(msg.sender, to, 1); //~ ERROR: event invocations have to be prefixed by `emit`
+        Log(1); //~ ERROR: event invocations have to be prefixed by `emit`
+        (Log)("a"); //~ ERROR: event invocations have to be prefixed by `emit`
+    }
+}
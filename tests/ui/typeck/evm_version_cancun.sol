@@ -0,0 +1,7 @@
+//@compile-flags: --evm-version cancun
+
+contract C {
+    function f() public view returns (bytes32, uint256, uint256, uint256) {
+        return (blobhash(0), block.blobbasefee, block.basefee, block.chainid);
+    }
+}
@@ -0,0 +1,12 @@
+//@compile-flags: --evm-version shanghai
+
+contract C {
+    function f() public view returns (bytes32, uint256, uint256, uint256) {
+        return (
+            blobhash(0), //~ ERROR: `blobhash` is not available before EVM version cancun
+            block.blobbasefee, //~ ERROR: `block.blobbasefee` is not available before EVM version cancun
+            block.basefee,
+            block.chainid
+        );
+    }
+}
@@ -0,0 +1,40 @@
+contract C {
+    struct S {
+        uint x;
+    }
+
+    struct WithFn {
+        function() internal f;
+    }
+
+    function v1(uint, bytes calldata, S memory) external {}
+    function v2(function() external f) public returns (function() external) {}
+    function v3() public view returns (uint[] memory, S memory) {}
+
+    function m1(mapping(uint => uint) storage) public {} //~ ERROR: types containing mappings cannot be parameter or return types of public functions
+    function m2() external returns (mapping(uint => uint) storage) {} //~ ERROR: types containing mappings cannot be parameter or return types of public functions
+    function m3(mapping(uint => uint) storage m) external {} //~ ERROR: types containing mappings cannot be parameter or return types of public functions
+
+    function f1(function() internal) public {} //~ ERROR: internal function types cannot be parameter or return types of public functions
+    function f2() external returns (function() internal) {} //~ ERROR: internal function types cannot be parameter or return types of public functions
+    function f3(WithFn memory) public {} //~ ERROR: internal function types cannot be parameter or return types of public functions
+
+    function s1(S storage) public {} //~ ERROR: storage references cannot be parameter or return types of public functions
+    function s2() public returns (uint[] storage) {} //~ ERROR: storage references cannot be parameter or return types of public functions
+
+    function i1(mapping(uint => uint) storage, function() internal, S storage) internal {}
+    function i2() private returns (WithFn memory, S storage) {}
+}
+
+library L {
+    struct S {
+        uint x;
+    }
+
+    function l1(S storage) public {}
+    function l3(mapping(uint => uint) storage m, uint k) external view returns (uint) {
+        return m[k];
+    }
+    function l4(mapping(uint => S) storage) public returns (mapping(uint => S) storage) {}
+    function l2(function() internal) external {} //~ ERROR: internal function types cannot be parameter or return types of public functions
+}
@@ -0,0 +1,80 @@
+//@compile-flags: --solc-compat 0.8.20
+
+contract Ordered {
+    uint immutable a;
+    uint immutable b;
+    uint immutable c = 1;
+
+    constructor(bool cond) {
+        a = 1;
+        b = a + c;
+        if (cond) {
+            a;
+        }
+    }
+}
+
+contract Branches {
+    uint immutable a;
+    uint immutable b;
+    uint immutable c;
+    uint immutable d;
+
+    constructor(bool cond) {
+        if (cond) {
+            a = 1;
+        } else {
+            a = 2;
+        }
+        a;
+
+        if (cond) {
+            b = 1;
+        } else {
+            revert();
+        }
+        b;
+
+        if (cond) {
+            c = 1;
+        } else {
+            require(false, "unreachable");
+        }
+        c;
+
+        // The body of a `do while` loop is executed at least once.
+        do {
+            d = 1;
+        } while (cond);
+        d;
+    }
+}
+
+contract ReadBeforeAssign {
+    uint immutable a;
+    uint immutable b;
+    uint immutable c;
+    uint immutable d;
+    uint immutable e;
+
+    constructor(bool cond) {
+        uint x = a; //~ ERROR: immutable variable `a` is read before being assigned
+        a = x;
+
+        b += 1; //~ ERROR: immutable variable `b` is read before being assigned
+
+        if (cond) {
+            c = 1;
+        }
+        c; //~ ERROR: immutable variable `c` is read before being assigned
+
+        cond && (d = 1) > 0;
+        d; //~ ERROR: immutable variable `d` is read before being assigned
+
+        do {
+            if (cond) break;
+            e = 1;
+        } while (cond);
+        e; //~ ERROR: immutable variable `e` is read before being assigned
+    }
+}
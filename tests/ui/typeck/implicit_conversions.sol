@@ -0,0 +1,29 @@
+//@compile-flags: -Zwarn-sign-conversions
+
+contract C {
+    uint8 small = 255;
+    uint8 tooLarge = 256; //~ ERROR: value of type `int_const` is not implicitly convertible to expected type `uint8`
+
+    function widening(uint8 a, int8 b, bytes4 c) public pure {
+        uint256 x = a;
+        int256 y = b;
+        bytes32 z = c;
+        x = a;
+        y = b;
+    }
+
+    function narrowing(uint256 a, int256 b, bytes32 c) public pure {
+        uint8 x = a; //~ ERROR: value of type `uint256` is not implicitly convertible to expected type `uint8`
+        int8 y = b; //~ ERROR: value of type `int256` is not implicitly convertible to expected type `int8`
+        bytes4 z = c; //~ ERROR: value of type `bytes32` is not implicitly convertible to expected type `bytes4`
+        x = a; //~ ERROR: value of type `uint256` is not implicitly convertible to expected type `uint8`
+        uint8 w = uint8(a);
+    }
+
+    function signs(uint8 a, int8 b) public pure {
+        uint256 x = b; //~ ERROR: value of type `int8` is not implicitly convertible to expected type `uint256`
+        int8 y = a; //~ ERROR: value of type `uint8` is not implicitly convertible to expected type `int8`
+        int16 z = a; //~ WARN: implicit conversion from unsigned `uint8` to signed `int16`
+        int16 w = int16(a);
+    }
+}
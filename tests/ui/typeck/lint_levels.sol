@@ -0,0 +1,8 @@
+//@compile-flags: -Zwarn-unused-errors -Zwarn-unused-events -Zlint=unused-errors=deny,unused-events=allow -Zlint=unused-functions=warn
+
+contract C {
+    error E(); //~ ERROR: error `E` is never used
+    event Ev();
+
+    function f() private {} //~ WARN: private function `f` is never used
+}
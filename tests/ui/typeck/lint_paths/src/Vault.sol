@@ -0,0 +1,7 @@
+//@compile-flags: -Zwarn-unused-payable -Zlint-paths=**/lint_paths/test/**:unused-payable=allow
+
+import "../test/VaultTest.sol";
+
+contract Vault {
+    function deposit() public payable {} //~ WARN: payable function `deposit` does not use `msg.value`
+}
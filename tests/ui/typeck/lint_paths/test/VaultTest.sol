@@ -0,0 +1,9 @@
+// `unused-payable` is allowed in this directory by `../src/Vault.sol`, so `fund` is not reported.
+
+import "../src/Vault.sol";
+
+contract VaultTest {
+    Vault vault;
+
+    function fund() public payable {}
+}
@@ -0,0 +1,28 @@
+//@compile-flags: -Zwarn-manual-dispatch
+
+contract C {
+    bytes data;
+
+    fallback() external {
+        if (msg.sig == this.f.selector) { //~ WARN: `msg.sig` compared to dispatch calls manually
+            uint256 x = abi.decode(msg.data[4:], (uint256)); //~ WARN: `msg.data` sliced to decode the calldata manually
+            f(x);
+        } else if (bytes4(0x12345678) != msg.sig) { //~ WARN: `msg.sig` compared to dispatch calls manually
+            data = msg.data;
+        }
+    }
+
+    function f(uint256) public {}
+
+    function first() public view returns (bytes1) {
+        return msg.data[0]; //~ WARN: `msg.data` indexed to decode the calldata manually
+    }
+
+    function selector() public view returns (bytes4) {
+        return msg.sig;
+    }
+
+    function length() public view returns (uint256) {
+        return msg.data.length;
+    }
+}
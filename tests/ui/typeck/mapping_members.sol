@@ -0,0 +1,26 @@
+contract C {
+    struct S {
+        uint256 a;
+    }
+
+    mapping(address => uint256) balances;
+    mapping(uint256 => mapping(uint256 => bool)) nested;
+    mapping(uint256 => S) structs;
+
+    function sum() public view returns (uint256 total) {
+        for (uint256 i = 0; i < balances.length; i++) { //~ ERROR: member `length` not found in `mapping(address => uint256)`
+            total += i;
+        }
+    }
+
+    function members() public view {
+        balances.keys; //~ ERROR: member `keys` not found in `mapping(address => uint256)`
+        nested[0].length; //~ ERROR: member `length` not found in `mapping(uint256 => bool)`
+    }
+
+    function ok(address a) public view returns (uint256) {
+        nested[0][1];
+        structs[2].a;
+        return balances[a];
+    }
+}
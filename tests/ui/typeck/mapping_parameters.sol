@@ -0,0 +1,25 @@
+library L {
+    function get(mapping(uint => uint) storage m, uint k) internal view returns (uint) {
+        return m[k];
+    }
+
+    function id(mapping(uint => uint) storage m) internal pure returns (mapping(uint => uint) storage) {
+        return m;
+    }
+}
+
+contract C {
+    mapping(uint => uint) map;
+
+    function i(mapping(uint => uint) storage m) internal view returns (uint) {
+        return m[0];
+    }
+
+    function p() private view returns (mapping(uint => uint) storage) {
+        return map;
+    }
+
+    function m1(mapping(uint => uint) memory m) internal {} //~ ERROR: data location must be `storage` for mapping parameters, but `memory` was given
+    function m2(mapping(uint => uint) m) internal {} //~ ERROR: data location must be `storage` for mapping parameters, but none was given
+    function m3() internal returns (mapping(uint => uint) calldata) {} //~ ERROR: data location must be `storage` for mapping return values, but `calldata` was given
+}
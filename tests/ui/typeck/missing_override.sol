@@ -0,0 +1,39 @@
+contract A {
+    function f() public virtual {}
+}
+
+contract B is A {
+    function f() public {} //~ ERROR: overriding function is missing `override` specifier
+}
+
+contract C {
+    function f() public virtual {}
+}
+
+contract D is A, C {} //~ ERROR: derived contract must override function `f()`
+
+contract E is A, C {
+    function f() public override(A, C) {}
+}
+
+interface I {
+    function g() external;
+}
+
+contract F is I {
+    function g() external {}
+}
+
+contract G {
+    function h() internal virtual {}
+}
+
+contract H is G {
+    function h() internal {} //~ ERROR: overriding function is missing `override` specifier
+}
+
+contract J {
+    function h() internal virtual {}
+}
+
+contract K is G, J {} //~ ERROR: derived contract must override function `h()`
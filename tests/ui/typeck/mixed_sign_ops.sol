@@ -0,0 +1,30 @@
+contract C {
+    function f(int x, uint y, int8 a, uint8 b, int16 c, uint16 d) public pure {
+        x < y; //~ ERROR: operator `<` not compatible with types `int256` and `uint256`
+        y >= x; //~ ERROR: operator `>=` not compatible with types `uint256` and `int256`
+        x == y; //~ ERROR: operator `==` not compatible with types `int256` and `uint256`
+        x + y; //~ ERROR: operator `+` not compatible with types `int256` and `uint256`
+        a * b; //~ ERROR: operator `*` not compatible with types `int8` and `uint8`
+        d - c; //~ ERROR: operator `-` not compatible with types `uint16` and `int16`
+
+        // Same sign.
+        x < x;
+        y < y;
+        a < c;
+        b < d;
+        x + a;
+        y + b;
+
+        // A common type exists.
+        b < c;
+        c + b;
+
+        // Explicit conversions.
+        x < int(y);
+        uint(x) < y;
+
+        // Shifts and exponentiation.
+        x << b;
+        y ** b;
+    }
+}
@@ -0,0 +1,27 @@
+//@compile-flags: -Zwarn-msg-data-in-internal
+
+contract C {
+    bytes data;
+
+    fallback() external {
+        data = msg.data;
+        forward();
+    }
+
+    function dispatch() public {
+        data = msg.data;
+        bytes4 sig = msg.sig;
+    }
+
+    function forward() internal {
+        data = msg.data; //~ WARN: `msg.data` read in internal function `forward`
+    }
+
+    function selector() private view returns (bytes4) {
+        return msg.sig; //~ WARN: `msg.sig` read in private function `selector`
+    }
+
+    function sender() internal view returns (address) {
+        return msg.sender;
+    }
+}
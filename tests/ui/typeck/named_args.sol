@@ -0,0 +1,47 @@
+struct S {
+    uint a;
+    bool b;
+}
+
+error E(uint code, string reason);
+
+contract C {
+    function f(uint a, uint b) public pure returns (uint) {
+        return a + b;
+    }
+
+    function g(uint, uint b) public pure {}
+
+    function h() public pure {
+        f({a: 1, b: 2});
+        f({b: 2, a: 1});
+        f({a: 1, a: 2, b: 3}); //~ ERROR: duplicate argument `a`
+        f({a: 1, c: 2}); //~ ERROR: function `f` has no parameter named `c`
+        //~^ ERROR: missing argument for parameter `b`
+        f({a: 1}); //~ ERROR: missing argument for parameter `b`
+        f({c: 1}); //~ ERROR: function `f` has no parameter named `c`
+        //~^ ERROR: missing arguments for parameters `a`, `b`
+        g({b: 2}); //~ ERROR: missing argument for parameter <unnamed>
+
+        S memory s = S({a: 1, b: true});
+        s = S({b: true, a: 1, b: false}); //~ ERROR: duplicate argument `b`
+        s = S({a: 1, x: true}); //~ ERROR: struct `S` has no field named `x`
+        //~^ ERROR: missing argument for field `b`
+    }
+
+    function i() public pure {
+        revert E({code: 1, reason: "a"});
+    }
+
+    function j() public pure {
+        revert E({code: 1, message: "a"}); //~ ERROR: error `E` has no parameter named `message`
+        //~^ ERROR: missing argument for parameter `reason`
+    }
+
+    event Ev(uint x);
+
+    function k() public {
+        emit Ev({x: 1});
+        emit Ev({x: 1, x: 2}); //~ ERROR: duplicate argument `x`
+    }
+}
@@ -0,0 +1,39 @@
+contract C {
+    function f(uint256 x) internal pure returns (uint256) {
+        return x;
+    }
+    function f(string memory s) internal pure returns (string memory) {
+        return s;
+    }
+
+    function g(uint8) internal pure {}
+    function g(uint16) internal pure {}
+
+    function h(address) internal pure {}
+    function h(bool, uint256) internal pure {}
+
+    function unique(uint8 small) public pure {
+        f(1);
+        f("hello");
+        g(uint16(small));
+        h(true, 1);
+        h(address(0));
+    }
+
+    function ambiguous(uint8 small) public pure {
+        g(1); //~ ERROR: multiple overloads of function `g` match the arguments
+        g(small); //~ ERROR: multiple overloads of function `g` match the arguments
+    }
+
+    function noMatch(uint256 big) public pure {
+        g(big); //~ ERROR: no overload of function `g` matches the arguments
+        h(true); //~ ERROR: no overload of function `h` matches the arguments
+        h(); //~ ERROR: no overload of function `h` matches the arguments
+        f(true); //~ ERROR: no overload of function `f` matches the arguments
+    }
+
+    // The return type of the selected overload is known.
+    function returnsString() public pure returns (string memory) {
+        return f(1); //~ ERROR: return value of type `uint256` is not implicitly convertible
+    }
+}
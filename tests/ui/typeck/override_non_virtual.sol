@@ -0,0 +1,25 @@
+contract A {
+    function f() public {}
+    function g() public virtual {}
+    function h(uint) external view virtual {}
+    function h(bool) public {}
+}
+
+contract B is A {
+    function f() public override {} //~ ERROR: trying to override non-virtual function
+    function g() public override {}
+    function h(uint) external view override {}
+    function h(bool) public override {} //~ ERROR: trying to override non-virtual function
+}
+
+interface I {
+    function i() external;
+}
+
+contract C is I {
+    function i() external override {}
+}
+
+contract D is B {
+    function g() public override {} //~ ERROR: trying to override non-virtual function
+}
@@ -0,0 +1,42 @@
+contract A {
+    function f1() external virtual {}
+    function f2() public virtual {}
+    function f3() public virtual {}
+    function f4() public view virtual {}
+    function f5() public payable virtual {}
+    function f6() public virtual {}
+    function f7() public virtual returns (uint) {}
+    function f8(string memory s) public virtual {}
+    function f9(string memory s) external virtual {}
+    function f10() public virtual returns (string memory) {}
+}
+
+contract B is A {
+    function f1() public override {}
+    function f2() external override {} //~ ERROR: overriding function visibility differs
+    function f3() public view override {}
+    function f4() public override {} //~ ERROR: overriding function changes state mutability from `view` to `nonpayable`
+    function f5() public view override {} //~ ERROR: overriding function changes state mutability from `payable` to `view`
+    function f6() public pure override {}
+    function f7() public override returns (int) {} //~ ERROR: overriding function return types differ
+    function f8(string calldata s) public override {} //~ ERROR: data location of overriding function parameter differs
+    function f9(string calldata s) external override {}
+    function f10() public override returns (string calldata) {} //~ ERROR: data location of overriding function return differs
+}
+
+contract C is B {
+    function f4() public pure override {} //~ ERROR: trying to override non-virtual function
+    function f3() public override {} //~ ERROR: overriding function changes state mutability from `view` to `nonpayable`
+    //~^ ERROR: trying to override non-virtual function
+}
+
+contract D {
+    function g() private {}
+    function h() private view {}
+}
+
+// Private functions are not inherited, so these do not override them.
+contract E is D {
+    function g() public {}
+    function h() internal {}
+}
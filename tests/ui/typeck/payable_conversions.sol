@@ -0,0 +1,18 @@
+contract C {
+    receive() external payable {}
+
+    function f(address a, uint160 u160, uint256 u256, bytes20 b20, bytes32 b32) public view {
+        payable(0);
+        payable(address(0));
+        payable(a);
+        payable(this);
+        payable(msg.sender);
+        payable(address(u160));
+
+        payable(1); //~ ERROR: explicit type conversion not allowed from `int_const` to `address payable`
+        payable(u256); //~ ERROR: explicit type conversion not allowed from `uint256` to `address payable`
+        payable(u160); //~ ERROR: explicit type conversion not allowed from `uint160` to `address payable`
+        payable(b20); //~ ERROR: explicit type conversion not allowed from `bytes20` to `address payable`
+        payable(b32); //~ ERROR: explicit type conversion not allowed from `bytes32` to `address payable`
+    }
+}
@@ -0,0 +1,15 @@
+//@compile-flags: -Zwarn-redundant-conversions
+
+contract C {
+    function f(uint256 a, uint256 b, address c) public view returns (uint256) {
+        uint256 x = uint256(a); //~ WARN: redundant conversion to `uint256`
+        uint256 y = uint256(a + b) * 2; //~ WARN: redundant conversion to `uint256`
+        address d = address(c); //~ WARN: redundant conversion to `address`
+
+        uint8 z = uint8(a);
+        uint256 w = uint256(z);
+        uint256 v = uint256(1);
+        address e = address(this);
+        return x + y + z + w + v + uint160(d) + uint160(e);
+    }
+}
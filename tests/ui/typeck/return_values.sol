@@ -0,0 +1,94 @@
+contract C {
+    struct S {
+        uint x;
+    }
+
+    S s;
+    string str;
+
+    function correct() public pure returns (uint, bool) {
+        return (5, true);
+    }
+
+    function correctNamed() public pure returns (uint a, bool b) {
+        a = 1;
+        b = false;
+        return;
+    }
+
+    function correctBare() public pure returns (uint) {
+        return;
+    }
+
+    function correctCall() public pure returns (uint, bool) {
+        return correct();
+    }
+
+    function correctRefs() public view returns (S memory, string memory) {
+        return (s, str);
+    }
+
+    function correctConversions(address payable p) public pure returns (address, uint256, int16, bytes4, string memory) {
+        return (p, uint8(1), int8(-1), "abcd", "a");
+    }
+
+    function tooFew() public pure returns (uint, bool) {
+        return 5; //~ ERROR: expected 2 return values, found 1
+    }
+
+    function tooFewCall() public pure returns (uint, bool, bytes32) {
+        return correct(); //~ ERROR: expected 3 return values, found 2
+    }
+
+    function tooMany() public pure returns (uint) {
+        return (1, 2); //~ ERROR: expected 1 return value, found 2
+    }
+
+    function noReturns() public pure {
+        return 1; //~ ERROR: expected 0 return values, found 1
+    }
+
+    function mismatch() public pure returns (uint, bool) {
+        return (
+            true, //~ ERROR: return value of type `bool` is not implicitly convertible to expected type `uint256`
+            1 //~ ERROR: return value of type `int_const` is not implicitly convertible to expected type `bool`
+        );
+    }
+
+    function mismatchAddress(address a) public pure returns (address payable) {
+        return a; //~ ERROR: return value of type `address` is not implicitly convertible to expected type `address payable`
+    }
+
+    function mismatchRef() public view returns (bytes memory) {
+        return str; //~ ERROR: return value of type `string storage` is not implicitly convertible to expected type `bytes memory`
+    }
+
+    function mismatchInt(uint256 x) public pure returns (uint8) {
+        return x; //~ ERROR: return value of type `uint256` is not implicitly convertible to expected type `uint8`
+    }
+
+    function mismatchLiteral() public pure returns (uint8) {
+        return 256; //~ ERROR: return value of type `int_const` is not implicitly convertible to expected type `uint8`
+    }
+
+    function tooFewOfThree(uint a, uint b) public pure returns (uint, uint, uint) {
+        return (a, b); //~ ERROR: expected 3 return values, found 2
+    }
+
+    function tooManyOfOne(uint a, uint b) public pure returns (uint) {
+        return (a, b, a); //~ ERROR: expected 1 return value, found 3
+    }
+
+    function tooFewEmpty() public pure returns (uint) {
+        return (); //~ ERROR: expected 1 return value, found 0
+    }
+
+    function tooFewNamed() public pure returns (uint a, bool b) {
+        a = 1;
+        return a; //~ ERROR: expected 2 return values, found 1
+    }
+
+    function mismatchEmptyComponent() public pure returns (uint, bool) {
+        return (, 1); //~ ERROR: return value of type `int_const` is not implicitly convertible to expected type `bool`
+    }
+}
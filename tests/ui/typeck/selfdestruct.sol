@@ -0,0 +1,13 @@
+//@compile-flags: --solc-compat 0.8.18
+
+library L {
+    function f(address payable a) internal {
+        selfdestruct(a); //~ ERROR: `selfdestruct` cannot be used in libraries
+    }
+}
+
+contract C {
+    function f(address payable a) public {
+        selfdestruct(a); //~ WARN: `selfdestruct` has been deprecated
+    }
+}
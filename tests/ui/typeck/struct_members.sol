@@ -0,0 +1,28 @@
+struct Inner {
+    uint256 value;
+    address owner;
+}
+
+struct Outer {
+    Inner inner;
+    uint256 count;
+}
+
+contract C {
+    Outer outer;
+    mapping(uint256 => Outer) outers;
+
+    function f() public view returns (uint256, address) {
+        Outer memory o = outer;
+        uint256 a = o.inner.value + outers[0].inner.value;
+        uint256 b = outer.inner.owner.balance;
+        return (a + b, o.inner.owner);
+    }
+
+    function g() public view {
+        outer.inner.valeu; //~ ERROR: field `valeu` not found in `struct Inner`
+        outer.countt; //~ ERROR: field `countt` not found in `struct Outer`
+        outer.count.value; //~ ERROR: member `value` not found in `uint256`
+        outer.inner.owner.balanse; //~ ERROR: member `balanse` not found in `address`
+    }
+}
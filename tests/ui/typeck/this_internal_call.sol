@@ -0,0 +1,31 @@
+contract A {
+    function a() internal {}
+    function b() private {}
+    function c() public {}
+}
+
+contract C is A {
+    uint public x;
+
+    function ext() external {}
+    function pub() public {}
+    function int_() internal {}
+    function priv() private {}
+    function over(uint) internal {}
+    function over(bool) external {}
+
+    function f() public {
+        this.ext();
+        this.pub();
+        this.c();
+        this.x();
+        this.over(true);
+        bytes4 s = this.ext.selector;
+
+        this.int_(); //~ ERROR: member `int_` not found in `contract C`
+        this.priv(); //~ ERROR: member `priv` not found in `contract C`
+        this.a(); //~ ERROR: member `a` not found in `contract C`
+        this.b(); //~ ERROR: member `b` not found in `contract C`
+        s = this.int_.selector; //~ ERROR: member `int_` not found in `contract C`
+    }
+}
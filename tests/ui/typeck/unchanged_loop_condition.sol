@@ -0,0 +1,68 @@
+//@compile-flags: -Zwarn-unchanged-loop-condition
+
+contract C {
+    uint256 total;
+
+    function infinite(uint256 n) public {
+        uint256 i = 0;
+        while (i < n) { //~ WARN: the condition of this `while` loop is never updated
+            total += 1;
+        }
+    }
+
+    function infiniteFor(uint256 n) public {
+        for (uint256 i = 0; i < n; ) { //~ WARN: the condition of this `for` loop is never updated
+            total += i;
+        }
+    }
+
+    function infiniteDoWhile(bool done) public {
+        do {
+            total += 1;
+            for (uint256 j = 0; j < 10; j++) {
+                if (j == 5) break;
+            }
+        } while (!done); //~ WARN: the condition of this `do while` loop is never updated
+    }
+
+    function counting(uint256 n) public {
+        for (uint256 i = 0; i < n; i++) {
+            total += i;
+        }
+        uint256 j = n;
+        while (j > 0) {
+            j -= 1;
+        }
+        (uint256 a, uint256 b) = (0, n);
+        do {
+            (a, b) = (a + 1, b);
+        } while (a < b);
+    }
+
+    function exits(uint256 n) public returns (uint256) {
+        uint256 i = 0;
+        while (i < n) {
+            if (total > 10) break;
+            total += 1;
+        }
+        while (i < n) {
+            return total;
+        }
+        while (i < n) {
+            revert("unreachable");
+        }
+        return 0;
+    }
+
+    function notLocal() public {
+        while (total < 10) {
+            bump();
+        }
+        while (address(this).balance > 0) {}
+        while (true) {}
+    }
+
+    function bump() internal {
+        total += 1;
+    }
+}
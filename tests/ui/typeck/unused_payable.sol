@@ -0,0 +1,45 @@
+//@compile-flags: -Zwarn-unused-payable
+
+contract C {
+    uint total;
+
+    constructor() payable {}
+
+    receive() external payable {}
+
+    function unused() public payable {} //~ WARN: payable function `unused` does not use `msg.value`
+
+    function ignoresValue(uint x) public payable { //~ WARN: payable function `ignoresValue` does not use `msg.value`
+        total += x;
+    }
+
+    function reads() public payable {
+        total += msg.value;
+    }
+
+    function forwards(address payable to) public payable {
+        to.transfer(address(this).balance);
+    }
+
+    function forwardsCall(address to) public payable {
+        (bool ok, ) = to.call{value: 1}("");
+        require(ok);
+    }
+
+    function readsIndirectly() public payable {
+        record();
+    }
+
+    function record() internal {
+        total += msg.value;
+    }
+
+    modifier onlyWithValue() {
+        require(msg.value > 0);
+        _;
+    }
+
+    function readsInModifier() public payable onlyWithValue {}
+
+    function notPayable() public {}
+}
@@ -0,0 +1,29 @@
+//@compile-flags: -Zwarn-unused-imports -Zwarn-unused-functions -Zwarn-unused-variables
+//@compile-flags: -Zwarn-unused-errors -Zwarn-unused-events -Zunused-report
+
+import {Used, Unused} from "./auxiliary/unused_symbols.sol"; //~ WARN: import `Unused` is never used
+import "./auxiliary/unused_symbols.sol" as lib; //~ WARN: import `lib` is never used
+
+error UsedError();
+error UnusedError(); //~ WARN: error `UnusedError` is never used
+
+contract C is Used {
+    event UsedEvent(uint256 amount);
+    event UnusedEvent(); //~ WARN: event `UnusedEvent` is never emitted
+
+    function f(uint256 used, uint256 unused) public returns (uint256 ret) { //~ WARN: parameter `unused` is never used
+        uint256 x; //~ WARN: variable `x` is never used
+        uint256 y;
+        assembly {
+            y := used
+        }
+        emit UsedEvent(y);
+        ret = g();
+    }
+
+    function g() private pure returns (uint256) {
+        revert UsedError();
+    }
+
+    function h() private {} //~ WARN: private function `h` is never used
+}
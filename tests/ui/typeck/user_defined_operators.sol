@@ -0,0 +1,35 @@
+type Fixed is int256;
+type Id is uint256;
+
+using {add as +, eq as ==} for Fixed global;
+
+function add(Fixed a, Fixed b) pure returns (Fixed) {
+    return Fixed.wrap(Fixed.unwrap(a) + Fixed.unwrap(b));
+}
+
+function eq(Fixed a, Fixed b) pure returns (bool) {
+    return Fixed.unwrap(a) == Fixed.unwrap(b);
+}
+
+contract C {
+    function sum(Fixed a, Fixed b) public pure returns (Fixed) {
+        Fixed c = a + b;
+        return c + a + b;
+    }
+
+    function equal(Fixed a, Fixed b) public pure returns (bool) {
+        return a == b && a + b == a;
+    }
+
+    function unbound(Fixed a, Fixed b) public pure returns (Fixed) {
+        return a - b; //~ ERROR: operator `-` not compatible with types `Fixed` and `Fixed`
+    }
+
+    function noBindings(Id a, Id b) public pure returns (Id) {
+        return a + b; //~ ERROR: operator `+` not compatible with types `Id` and `Id`
+    }
+
+    function mismatch(Fixed a, Id b) public pure {
+        a + b; //~ ERROR: operator `+` not compatible with types `Fixed` and `Id`
+    }
+}